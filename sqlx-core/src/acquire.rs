@@ -97,6 +97,24 @@ impl<'a, DB: Database> Acquire<'a> for &'_ Pool<DB> {
     }
 }
 
+/// A nested transaction begun from a `&mut Transaction` is a savepoint, which borrows the outer
+/// transaction until it is committed or rolled back.
+impl<'c, 't, DB: Database> Acquire<'t> for &'t mut Transaction<'c, DB> {
+    type Database = DB;
+
+    type Connection = &'t mut <DB as Database>::Connection;
+
+    #[inline]
+    fn acquire(self) -> BoxFuture<'t, Result<Self::Connection, Error>> {
+        Box::pin(futures_util::future::ok(&mut **self))
+    }
+
+    #[inline]
+    fn begin(self) -> BoxFuture<'t, Result<Transaction<'t, DB>, Error>> {
+        Transaction::begin(MaybePoolConnection::Connection(&mut **self))
+    }
+}
+
 #[allow(unused_macros)]
 macro_rules! impl_acquire {
     ($DB:ident, $C:ident) => {
@@ -147,31 +165,5 @@ macro_rules! impl_acquire {
                 crate::transaction::Transaction::begin(&mut **self)
             }
         }
-
-        impl<'c, 't> crate::acquire::Acquire<'t>
-            for &'t mut crate::transaction::Transaction<'c, $DB>
-        {
-            type Database = $DB;
-
-            type Connection = &'t mut <$DB as crate::database::Database>::Connection;
-
-            #[inline]
-            fn acquire(
-                self,
-            ) -> futures_core::future::BoxFuture<'t, Result<Self::Connection, crate::error::Error>>
-            {
-                Box::pin(futures_util::future::ok(&mut **self))
-            }
-
-            #[inline]
-            fn begin(
-                self,
-            ) -> futures_core::future::BoxFuture<
-                't,
-                Result<crate::transaction::Transaction<'t, $DB>, crate::error::Error>,
-            > {
-                crate::transaction::Transaction::begin(&mut **self)
-            }
-        }
     };
 }
@@ -52,6 +52,45 @@ impl<'c> Executor<'c> for &'c mut AnyConnection {
         }
     }
 
+    fn fetch_many_with_size<'e, 'q: 'e, E: 'q>(
+        self,
+        mut query: E,
+        size: usize,
+    ) -> BoxStream<'e, Result<Either<AnyQueryResult, AnyRow>, Error>>
+    where
+        'c: 'e,
+        E: Execute<'q, Self::Database>,
+    {
+        let arguments = query.take_arguments();
+        let query = query.sql();
+
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn
+                .fetch_many_with_size((query, arguments.map(Into::into)), size)
+                .map_ok(|v| v.map_right(Into::into).map_left(Into::into))
+                .boxed(),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn
+                .fetch_many_with_size((query, arguments.map(Into::into)), size)
+                .map_ok(|v| v.map_right(Into::into).map_left(Into::into))
+                .boxed(),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn
+                .fetch_many_with_size((query, arguments.map(Into::into)), size)
+                .map_ok(|v| v.map_right(Into::into).map_left(Into::into))
+                .boxed(),
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => conn
+                .fetch_many_with_size((query, arguments.map(Into::into)), size)
+                .map_ok(|v| v.map_right(Into::into).map_left(Into::into))
+                .boxed(),
+        }
+    }
+
     fn fetch_optional<'e, 'q: 'e, E: 'q>(
         self,
         mut query: E,
@@ -15,7 +15,7 @@ use crate::mssql;
 
 #[cfg(feature = "mysql")]
 use crate::mysql;
-use crate::transaction::Transaction;
+use crate::transaction::{Transaction, TransactionOptions};
 
 mod establish;
 mod executor;
@@ -77,6 +77,54 @@ impl AnyConnection {
     pub fn private_get_mut(&mut self) -> &mut AnyConnectionKind {
         &mut self.0
     }
+
+    /// Returns the underlying [`PgConnection`][postgres::PgConnection], or `None` if this
+    /// connection is backed by a different driver.
+    #[cfg(feature = "postgres")]
+    pub fn try_pg(&mut self) -> Option<&mut postgres::PgConnection> {
+        match &mut self.0 {
+            AnyConnectionKind::Postgres(conn) => Some(conn),
+
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying [`MySqlConnection`][mysql::MySqlConnection], or `None` if this
+    /// connection is backed by a different driver.
+    #[cfg(feature = "mysql")]
+    pub fn try_mysql(&mut self) -> Option<&mut mysql::MySqlConnection> {
+        match &mut self.0 {
+            AnyConnectionKind::MySql(conn) => Some(conn),
+
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying [`SqliteConnection`][sqlite::SqliteConnection], or `None` if this
+    /// connection is backed by a different driver.
+    #[cfg(feature = "sqlite")]
+    pub fn try_sqlite(&mut self) -> Option<&mut sqlite::SqliteConnection> {
+        match &mut self.0 {
+            AnyConnectionKind::Sqlite(conn) => Some(conn),
+
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying [`MssqlConnection`][mssql::MssqlConnection], or `None` if this
+    /// connection is backed by a different driver.
+    #[cfg(feature = "mssql")]
+    pub fn try_mssql(&mut self) -> Option<&mut mssql::MssqlConnection> {
+        match &mut self.0 {
+            AnyConnectionKind::Mssql(conn) => Some(conn),
+
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
 }
 
 macro_rules! delegate_to {
@@ -163,6 +211,16 @@ impl Connection for AnyConnection {
         Transaction::begin(self)
     }
 
+    fn begin_with_options(
+        &mut self,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<Transaction<'_, Self::Database>, Error>>
+    where
+        Self: Sized,
+    {
+        Transaction::begin_with_options(self, options)
+    }
+
     fn cached_statements_size(&self) -> usize {
         match &self.0 {
             #[cfg(feature = "postgres")]
@@ -197,6 +255,10 @@ impl Connection for AnyConnection {
         }
     }
 
+    fn shrink_buffers(&mut self) {
+        delegate_to_mut!(self.shrink_buffers())
+    }
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         delegate_to_mut!(self.flush())
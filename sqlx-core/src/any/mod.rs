@@ -18,6 +18,7 @@ mod database;
 mod error;
 mod kind;
 mod options;
+mod placeholders;
 mod query_result;
 pub(crate) mod row;
 mod statement;
@@ -40,6 +41,10 @@ pub use decode::AnyDecode;
 pub use encode::AnyEncode;
 pub use kind::AnyKind;
 pub use options::AnyConnectOptions;
+pub use placeholders::translate_placeholders;
+// Used internally in `any_query!`
+#[doc(hidden)]
+pub use placeholders::AnyQueryCache;
 pub use query_result::AnyQueryResult;
 pub use row::AnyRow;
 pub use statement::AnyStatement;
@@ -47,8 +52,14 @@ pub use transaction::AnyTransactionManager;
 pub use type_info::AnyTypeInfo;
 pub use value::{AnyValue, AnyValueRef};
 
+/// An alias for [`Pool`][crate::pool::Pool], specialized for [`Any`].
 pub type AnyPool = crate::pool::Pool<Any>;
 
+/// An alias for [`PoolOptions`][crate::pool::PoolOptions], specialized for [`Any`].
+///
+/// As this is the same type as the options of the concrete pools, all of the same settings
+/// (`min_connections`, `idle_timeout`, `max_lifetime`, `test_before_acquire`, etc.) are
+/// available and apply to whichever database the connection URL selects.
 pub type AnyPoolOptions = crate::pool::PoolOptions<Any>;
 
 /// An alias for [`Executor<'_, Database = Any>`][Executor].
@@ -0,0 +1,254 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use once_cell::sync::OnceCell;
+
+use crate::any::AnyKind;
+
+/// Rewrite the `?` placeholders in `sql` into the syntax expected by the given database.
+///
+/// PostgreSQL expects `$1, $2, ...` and MSSQL expects `@p1, @p2, ...`, while MySQL and SQLite
+/// accept `?` as-is. A `?` inside a string literal, a quoted identifier or a comment is left
+/// untouched, including the dollar-quoted (`$$ ... $$`) and escaped (`E'...'`) strings of
+/// PostgreSQL.
+///
+/// Note that this also rewrites the `?` family of JSONB operators in PostgreSQL, which have to be
+/// replaced by their function equivalents (e.g. `jsonb_exists`) in queries written this way.
+pub fn translate_placeholders(sql: &str, kind: AnyKind) -> Cow<'_, str> {
+    let syntax = match placeholder_syntax(kind) {
+        Some(syntax) if sql.contains('?') => syntax,
+        _ => return Cow::Borrowed(sql),
+    };
+
+    let mut out = String::with_capacity(sql.len() + 8);
+    let mut index = 0;
+    let mut pos = 0;
+
+    while let Some(offset) = sql[pos..].find(&['?', '\'', '"', '`', '[', '-', '/', '$'][..]) {
+        let start = pos + offset;
+        let rest = &sql[start..];
+
+        out.push_str(&sql[pos..start]);
+
+        if rest.starts_with('?') {
+            index += 1;
+            let _ = write!(out, "{}{}", syntax.prefix, index);
+
+            pos = start + 1;
+            continue;
+        }
+
+        // the length of the section starting here which is copied as-is; every character
+        // matched above is ASCII, so this always ends on a character boundary
+        let len = match rest.as_bytes()[0] {
+            b'\'' if syntax.postgres_strings && is_escape_string(&sql[..start]) => {
+                escape_string_len(rest)
+            }
+
+            // string literals and quoted identifiers, where a doubled quote is an escape and
+            // simply reads as two adjacent quoted sections
+            b'\'' => section_len(rest, 1, "'"),
+            b'"' => section_len(rest, 1, "\""),
+            b'`' => section_len(rest, 1, "`"),
+
+            b'[' if syntax.brackets => section_len(rest, 1, "]"),
+
+            b'$' if syntax.postgres_strings => match dollar_quote_tag(&sql[..start], rest) {
+                Some(tag) => section_len(rest, tag.len(), tag),
+                None => 1,
+            },
+
+            b'-' if rest.starts_with("--") => section_len(rest, 2, "\n"),
+            b'/' if rest.starts_with("/*") => section_len(rest, 2, "*/"),
+
+            _ => 1,
+        };
+
+        out.push_str(&rest[..len]);
+        pos = start + len;
+    }
+
+    out.push_str(&sql[pos..]);
+
+    Cow::Owned(out)
+}
+
+struct Syntax {
+    prefix: &'static str,
+
+    // identifiers can be quoted in brackets, which are array subscripts in PostgreSQL
+    brackets: bool,
+
+    // dollar-quoted strings, and escaped strings where a backslash escapes the next character
+    postgres_strings: bool,
+}
+
+fn placeholder_syntax(kind: AnyKind) -> Option<Syntax> {
+    match kind {
+        #[cfg(feature = "postgres")]
+        AnyKind::Postgres => Some(Syntax {
+            prefix: "$",
+            brackets: false,
+            postgres_strings: true,
+        }),
+
+        #[cfg(feature = "mssql")]
+        AnyKind::Mssql => Some(Syntax {
+            prefix: "@p",
+            brackets: true,
+            postgres_strings: false,
+        }),
+
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
+
+// the length of the section at the start of `rest` which ends with `end`, skipping the first
+// `skip` bytes which open it; an unterminated section runs to the end of the query
+fn section_len(rest: &str, skip: usize, end: &str) -> usize {
+    rest[skip..]
+        .find(end)
+        .map_or(rest.len(), |i| skip + i + end.len())
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+// whether the quote which follows `before` opens an `E'...'` string, rather than e.g. following
+// an identifier ending in `e`
+fn is_escape_string(before: &str) -> bool {
+    let mut chars = before.chars().rev();
+
+    matches!(chars.next(), Some('E' | 'e'))
+        && !matches!(chars.next(), Some(c) if is_identifier_char(c))
+}
+
+fn escape_string_len(rest: &str) -> usize {
+    let mut bytes = rest.bytes().enumerate().skip(1);
+
+    while let Some((i, b)) = bytes.next() {
+        match b {
+            b'\\' => {
+                bytes.next();
+            }
+
+            b'\'' => return i + 1,
+
+            _ => {}
+        }
+    }
+
+    rest.len()
+}
+
+// the `$tag$` delimiter opening a dollar-quoted string at the start of `rest`, if any; a `$`
+// within an identifier or followed by a digit (a positional parameter) does not open one
+fn dollar_quote_tag<'a>(before: &str, rest: &'a str) -> Option<&'a str> {
+    if matches!(before.chars().next_back(), Some(c) if is_identifier_char(c)) {
+        return None;
+    }
+
+    let after = &rest[1..];
+    let tag_len = after.find(|c: char| !(c.is_alphanumeric() || c == '_'))?;
+
+    if after.starts_with(|c: char| c.is_ascii_digit()) || !after[tag_len..].starts_with('$') {
+        return None;
+    }
+
+    Some(&rest[..tag_len + 2])
+}
+
+// Used internally by `any_query!` to translate a query once per call site and database
+#[doc(hidden)]
+pub struct AnyQueryCache {
+    sql: &'static str,
+    postgres: OnceCell<String>,
+    mssql: OnceCell<String>,
+}
+
+impl AnyQueryCache {
+    pub const fn new(sql: &'static str) -> Self {
+        Self {
+            sql,
+            postgres: OnceCell::new(),
+            mssql: OnceCell::new(),
+        }
+    }
+
+    pub fn sql(&'static self, kind: AnyKind) -> &'static str {
+        let cell = match kind {
+            #[cfg(feature = "postgres")]
+            AnyKind::Postgres => &self.postgres,
+
+            #[cfg(feature = "mssql")]
+            AnyKind::Mssql => &self.mssql,
+
+            #[allow(unreachable_patterns)]
+            _ => return self.sql,
+        };
+
+        cell.get_or_init(|| translate_placeholders(self.sql, kind).into_owned())
+    }
+}
+
+#[cfg(all(test, feature = "postgres"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_numbers_placeholders_for_postgres() {
+        assert_eq!(
+            translate_placeholders("SELECT * FROM t WHERE a = ? AND b = ?", AnyKind::Postgres),
+            "SELECT * FROM t WHERE a = $1 AND b = $2"
+        );
+    }
+
+    #[test]
+    fn it_skips_quoted_sections_and_comments() {
+        assert_eq!(
+            translate_placeholders(
+                "SELECT '?', 'it''s ?', \"?\" -- ?\n, ? /* ? */ FROM t WHERE a = ?",
+                AnyKind::Postgres
+            ),
+            "SELECT '?', 'it''s ?', \"?\" -- ?\n, $1 /* ? */ FROM t WHERE a = $2"
+        );
+    }
+
+    #[test]
+    fn it_skips_postgres_strings() {
+        assert_eq!(
+            translate_placeholders(
+                "SELECT $$ ? $$, $body$ '?' $$ ? $body$, ?, E'it\\'s ?', e'\\\\', ?",
+                AnyKind::Postgres
+            ),
+            "SELECT $$ ? $$, $body$ '?' $$ ? $body$, $1, E'it\\'s ?', e'\\\\', $2"
+        );
+
+        // neither the dollar sign of an identifier nor a quote after an identifier opens a string
+        assert_eq!(
+            translate_placeholders(
+                "SELECT a$b$ FROM t WHERE name = 'e' AND b = ?",
+                AnyKind::Postgres
+            ),
+            "SELECT a$b$ FROM t WHERE name = 'e' AND b = $1"
+        );
+    }
+
+    #[test]
+    fn it_does_not_treat_brackets_as_quotes_for_postgres() {
+        assert_eq!(
+            translate_placeholders("SELECT (ARRAY[?, ?])[?]", AnyKind::Postgres),
+            "SELECT (ARRAY[$1, $2])[$3]"
+        );
+    }
+
+    #[test]
+    fn it_leaves_queries_without_placeholders_borrowed() {
+        assert!(matches!(
+            translate_placeholders("SELECT 1", AnyKind::Postgres),
+            Cow::Borrowed("SELECT 1")
+        ));
+    }
+}
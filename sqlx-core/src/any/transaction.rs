@@ -4,7 +4,7 @@ use crate::any::connection::AnyConnectionKind;
 use crate::any::{Any, AnyConnection};
 use crate::database::Database;
 use crate::error::Error;
-use crate::transaction::TransactionManager;
+use crate::transaction::{TransactionManager, TransactionOptions};
 
 pub struct AnyTransactionManager;
 
@@ -35,6 +35,41 @@ impl TransactionManager for AnyTransactionManager {
         }
     }
 
+    fn begin_with_options(
+        conn: &mut AnyConnection,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        match &mut conn.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => {
+                <crate::postgres::Postgres as Database>::TransactionManager::begin_with_options(
+                    conn, options,
+                )
+            }
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => {
+                <crate::mysql::MySql as Database>::TransactionManager::begin_with_options(
+                    conn, options,
+                )
+            }
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => {
+                <crate::sqlite::Sqlite as Database>::TransactionManager::begin_with_options(
+                    conn, options,
+                )
+            }
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => {
+                <crate::mssql::Mssql as Database>::TransactionManager::begin_with_options(
+                    conn, options,
+                )
+            }
+        }
+    }
+
     fn commit(conn: &mut AnyConnection) -> BoxFuture<'_, Result<(), Error>> {
         match &mut conn.0 {
             #[cfg(feature = "postgres")]
@@ -59,6 +94,30 @@ impl TransactionManager for AnyTransactionManager {
         }
     }
 
+    fn commit_with_savepoint_release(conn: &mut AnyConnection) -> BoxFuture<'_, Result<(), Error>> {
+        match &mut conn.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => {
+                crate::postgres::PgTransactionManager::commit_with_savepoint_release(conn)
+            }
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => {
+                crate::mysql::MySqlTransactionManager::commit_with_savepoint_release(conn)
+            }
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => {
+                crate::sqlite::SqliteTransactionManager::commit_with_savepoint_release(conn)
+            }
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => {
+                crate::mssql::MssqlTransactionManager::commit_with_savepoint_release(conn)
+            }
+        }
+    }
+
     fn rollback(conn: &mut AnyConnection) -> BoxFuture<'_, Result<(), Error>> {
         match &mut conn.0 {
             #[cfg(feature = "postgres")]
@@ -106,4 +165,97 @@ impl TransactionManager for AnyTransactionManager {
             }
         }
     }
+
+    fn savepoint<'c>(
+        conn: &'c mut AnyConnection,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        match &mut conn.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => {
+                <crate::postgres::Postgres as Database>::TransactionManager::savepoint(conn, name)
+            }
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => {
+                <crate::mysql::MySql as Database>::TransactionManager::savepoint(conn, name)
+            }
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => {
+                <crate::sqlite::Sqlite as Database>::TransactionManager::savepoint(conn, name)
+            }
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => {
+                <crate::mssql::Mssql as Database>::TransactionManager::savepoint(conn, name)
+            }
+        }
+    }
+
+    fn rollback_to_savepoint<'c>(
+        conn: &'c mut AnyConnection,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        match &mut conn.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => {
+                <crate::postgres::Postgres as Database>::TransactionManager::rollback_to_savepoint(
+                    conn, name,
+                )
+            }
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => {
+                <crate::mysql::MySql as Database>::TransactionManager::rollback_to_savepoint(
+                    conn, name,
+                )
+            }
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => {
+                <crate::sqlite::Sqlite as Database>::TransactionManager::rollback_to_savepoint(
+                    conn, name,
+                )
+            }
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => {
+                <crate::mssql::Mssql as Database>::TransactionManager::rollback_to_savepoint(
+                    conn, name,
+                )
+            }
+        }
+    }
+
+    fn release_savepoint<'c>(
+        conn: &'c mut AnyConnection,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        match &mut conn.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => {
+                <crate::postgres::Postgres as Database>::TransactionManager::release_savepoint(
+                    conn, name,
+                )
+            }
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => {
+                <crate::mysql::MySql as Database>::TransactionManager::release_savepoint(conn, name)
+            }
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => {
+                <crate::sqlite::Sqlite as Database>::TransactionManager::release_savepoint(
+                    conn, name,
+                )
+            }
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => {
+                <crate::mssql::Mssql as Database>::TransactionManager::release_savepoint(conn, name)
+            }
+        }
+    }
 }
@@ -2,6 +2,7 @@
 
 use crate::database::{Database, HasArguments};
 use crate::encode::Encode;
+use crate::error::Error;
 use crate::types::Type;
 use std::fmt::{self, Write};
 
@@ -23,6 +24,22 @@ pub trait Arguments<'q>: Send + Sized + Default {
     }
 }
 
+// checks the number of bound values against the number of parameters of the prepared statement
+#[allow(dead_code)]
+pub(crate) fn check_parameter_count(parameters: usize, bound: usize) -> Result<(), Error> {
+    if parameters != bound {
+        return Err(err_protocol!(
+            "the query has {} parameter{} but {} {} bound",
+            parameters,
+            if parameters == 1 { "" } else { "s" },
+            bound,
+            if bound == 1 { "was" } else { "were" }
+        ));
+    }
+
+    Ok(())
+}
+
 pub trait IntoArguments<'q, DB: HasArguments<'q>>: Sized + Send {
     fn into_arguments(self) -> <DB as HasArguments<'q>>::Arguments;
 }
@@ -55,3 +72,16 @@ impl<'q, DB: HasArguments<'q>> IntoArguments<'q, DB> for ImmutableArguments<'q,
 
 // TODO: Impl `IntoArguments` for &[&dyn Encode]
 // TODO: Impl `IntoArguments` for (impl Encode, ...) x16
+
+#[test]
+fn test_check_parameter_count() {
+    assert!(check_parameter_count(2, 2).is_ok());
+
+    let message = |parameters, bound| match check_parameter_count(parameters, bound) {
+        Err(Error::Protocol(message)) => message,
+        res => panic!("expected a protocol error, got {:?}", res),
+    };
+
+    assert_eq!(message(2, 1), "the query has 2 parameters but 1 was bound");
+    assert_eq!(message(1, 0), "the query has 1 parameter but 0 were bound");
+}
@@ -1,10 +1,11 @@
 use crate::database::{Database, HasStatementCache};
 use crate::error::Error;
-use crate::transaction::Transaction;
+use crate::transaction::{transaction_options_unsupported, Transaction, TransactionOptions};
 use futures_core::future::BoxFuture;
 use log::LevelFilter;
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
 use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Represents a single database connection.
@@ -36,6 +37,47 @@ pub trait Connection: Send {
     where
         Self: Sized;
 
+    /// Begin a new transaction with the given isolation level and access mode.
+    ///
+    /// Unlike [`begin`][Self::begin], this returns an error if a transaction is already active,
+    /// as the options cannot be applied to a savepoint.
+    ///
+    /// By default, only the default options are supported, with which this is the same as
+    /// [`begin`][Self::begin].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # #[cfg(feature = "postgres")]
+    /// # async fn example(
+    /// #     conn: &mut sqlx_core::postgres::PgConnection,
+    /// # ) -> Result<(), sqlx_core::error::Error> {
+    /// # use sqlx_core::connection::Connection;
+    /// # use sqlx_core::transaction::{IsolationLevel, TransactionOptions};
+    /// let options = TransactionOptions::new()
+    ///     .isolation_level(IsolationLevel::RepeatableRead)
+    ///     .read_only(true);
+    ///
+    /// let mut tx = conn.begin_with_options(options).await?;
+    /// // every query in `tx` sees the same snapshot of the database
+    /// tx.commit().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn begin_with_options(
+        &mut self,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<Transaction<'_, Self::Database>, Error>>
+    where
+        Self: Sized,
+    {
+        if options != TransactionOptions::default() {
+            return Box::pin(async move { Err(transaction_options_unsupported()) });
+        }
+
+        self.begin()
+    }
+
     /// Execute the function inside a transaction.
     ///
     /// If the function returns an error, the transaction will be rolled back. If it does not
@@ -102,6 +144,19 @@ pub trait Connection: Send {
         Box::pin(async move { Ok(()) })
     }
 
+    /// Shrinks the buffers of the connection back to their initial capacity, if they grew to
+    /// read or write a large amount of data.
+    ///
+    /// Buffers grow as needed but are not shrunk automatically, so that a connection that
+    /// repeatedly handles large amounts of data does not have to grow them every time. Calling
+    /// this, for example from [`PoolOptions::after_release`], limits the memory held by
+    /// connections that only rarely do.
+    ///
+    /// This does nothing for connections without buffers of their own.
+    ///
+    /// [`PoolOptions::after_release`]: crate::pool::PoolOptions::after_release
+    fn shrink_buffers(&mut self) {}
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>>;
 
@@ -131,11 +186,41 @@ pub trait Connection: Send {
     }
 }
 
-#[derive(Clone, Debug)]
-pub(crate) struct LogSettings {
+/// Settings for the logging of executed statements.
+///
+/// These are set with [`ConnectOptions::log_statements`] and
+/// [`ConnectOptions::log_slow_statements`] when connecting, and can be changed on a
+/// live connection with `set_log_settings()` on the concrete connection type
+/// (e.g. `PgConnection::set_log_settings()`).
+#[derive(Clone)]
+pub struct LogSettings {
     pub(crate) statements_level: LevelFilter,
     pub(crate) slow_statements_level: LevelFilter,
     pub(crate) slow_statements_duration: Duration,
+    pub(crate) log_parameters: bool,
+    pub(crate) redact_parameter: Option<Arc<dyn Fn(usize) -> bool + Send + Sync>>,
+    #[cfg(feature = "tracing")]
+    pub(crate) trace_params: bool,
+}
+
+impl Debug for LogSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = f.debug_struct("LogSettings");
+
+        s.field("statements_level", &self.statements_level)
+            .field("slow_statements_level", &self.slow_statements_level)
+            .field("slow_statements_duration", &self.slow_statements_duration)
+            .field("log_parameters", &self.log_parameters)
+            .field(
+                "redact_parameter",
+                &self.redact_parameter.as_ref().map(|_| "<fn>"),
+            );
+
+        #[cfg(feature = "tracing")]
+        s.field("trace_params", &self.trace_params);
+
+        s.finish()
+    }
 }
 
 impl Default for LogSettings {
@@ -144,18 +229,65 @@ impl Default for LogSettings {
             statements_level: LevelFilter::Info,
             slow_statements_level: LevelFilter::Warn,
             slow_statements_duration: Duration::from_secs(1),
+            log_parameters: false,
+            redact_parameter: None,
+            #[cfg(feature = "tracing")]
+            trace_params: false,
         }
     }
 }
 
 impl LogSettings {
-    pub(crate) fn log_statements(&mut self, level: LevelFilter) {
+    /// Log executed statements with the specified `level`.
+    pub fn log_statements(&mut self, level: LevelFilter) {
         self.statements_level = level;
     }
-    pub(crate) fn log_slow_statements(&mut self, level: LevelFilter, duration: Duration) {
+
+    /// Log executed statements with a duration above the specified `duration`
+    /// at the specified `level`.
+    pub fn log_slow_statements(&mut self, level: LevelFilter, duration: Duration) {
         self.slow_statements_level = level;
         self.slow_statements_duration = duration;
     }
+
+    /// Include the values of bound parameters in the log of each executed statement.
+    ///
+    /// This is disabled by default as the values may contain sensitive data; see also
+    /// [`redact_parameters`][Self::redact_parameters]. Text is logged as is, up to a length,
+    /// while binary values are summarized by their length and first few bytes in hex.
+    ///
+    /// Not supported by MSSQL, whose parameters are never logged.
+    pub fn log_parameters(&mut self, enabled: bool) {
+        self.log_parameters = enabled;
+    }
+
+    /// Mask the bound parameters for whose index `redact` returns `true` in the log of
+    /// each executed statement, which then show as `<redacted>`.
+    ///
+    /// The index of a parameter is its zero-based position among the bound arguments, so
+    /// that `$1` in Postgres is at index `0`.
+    ///
+    /// ```rust
+    /// # use sqlx_core::connection::LogSettings;
+    /// let mut settings = LogSettings::default();
+    /// settings.log_parameters(true);
+    ///
+    /// // the second parameter of every statement is a password
+    /// settings.redact_parameters(|index| index == 1);
+    /// ```
+    pub fn redact_parameters(&mut self, redact: impl Fn(usize) -> bool + Send + Sync + 'static) {
+        self.redact_parameter = Some(Arc::new(redact));
+    }
+
+    /// Record the values of bound parameters on the `sqlx.query` tracing span.
+    ///
+    /// This is disabled by default, in which case the span shows `[redacted]` instead. The
+    /// arguments are recorded as encoded for the database, so if any parameters are masked by
+    /// [`redact_parameters`][Self::redact_parameters], the span also shows `[redacted]`.
+    #[cfg(feature = "tracing")]
+    pub fn trace_params(&mut self, enabled: bool) {
+        self.trace_params = enabled;
+    }
 }
 
 pub trait ConnectOptions: 'static + Send + Sync + FromStr<Err = Error> + Debug + Clone {
@@ -173,6 +305,38 @@ pub trait ConnectOptions: 'static + Send + Sync + FromStr<Err = Error> + Debug +
     /// at the specified `level`.
     fn log_slow_statements(&mut self, level: LevelFilter, duration: Duration) -> &mut Self;
 
+    /// Include the values of bound parameters in the log of each executed statement.
+    ///
+    /// See [`LogSettings::log_parameters`]. By default this does nothing, for databases whose
+    /// parameters can not be logged, such as MSSQL.
+    fn log_parameters(&mut self, enabled: bool) -> &mut Self {
+        let _ = enabled;
+        self
+    }
+
+    /// Mask the bound parameters for whose index `redact` returns `true` in the log of
+    /// each executed statement.
+    ///
+    /// See [`LogSettings::redact_parameters`]. By default this does nothing, like
+    /// [`log_parameters`][Self::log_parameters].
+    fn redact_parameters(
+        &mut self,
+        redact: impl Fn(usize) -> bool + Send + Sync + 'static,
+    ) -> &mut Self {
+        let _ = redact;
+        self
+    }
+
+    /// Include the values of bound parameters in the `sqlx.query` tracing span of each statement.
+    ///
+    /// See [`LogSettings::trace_params`]. By default this does nothing, like
+    /// [`log_parameters`][Self::log_parameters].
+    #[cfg(feature = "tracing")]
+    fn trace_params(&mut self, enabled: bool) -> &mut Self {
+        let _ = enabled;
+        self
+    }
+
     /// Entirely disables statement logging (both slow and regular).
     fn disable_statement_logging(&mut self) -> &mut Self {
         self.log_statements(LevelFilter::Off)
@@ -82,9 +82,16 @@ pub enum Error {
     #[error("error occurred while decoding: {0}")]
     Decode(#[source] BoxDynError),
 
+    /// Error occurred while encoding a value.
+    #[error("error occurred while encoding a value: {0}")]
+    Encode(#[source] BoxDynError),
+
     /// A [`Pool::acquire`] timed out due to connections not becoming available or
     /// because another task encountered too many errors while trying to open a new connection.
     ///
+    /// If the timeout elapsed while the call was itself retrying to open a new connection, the
+    /// error of the last attempt is returned instead.
+    ///
     /// [`Pool::acquire`]: crate::pool::Pool::acquire
     #[error("pool timed out while waiting for an open connection")]
     PoolTimedOut,
@@ -100,6 +107,12 @@ pub enum Error {
     #[error("attempted to communicate with a crashed background worker")]
     WorkerCrashed,
 
+    /// A named savepoint was used incorrectly, for example released after it was rolled back.
+    ///
+    /// See [`Transaction::savepoint`](crate::transaction::Transaction::savepoint).
+    #[error("invalid use of savepoint: {0}")]
+    Savepoint(String),
+
     #[cfg(feature = "migrate")]
     #[error("{0}")]
     Migrate(#[source] Box<crate::migrate::MigrateError>),
@@ -122,6 +135,25 @@ impl Error {
         }
     }
 
+    /// Returns `true` if this error means the connection to the database was lost or could
+    /// not be established, as opposed to an error returned by the database itself.
+    pub(crate) fn is_transport(&self) -> bool {
+        use io::ErrorKind::*;
+
+        match self {
+            Error::Io(e) => matches!(
+                e.kind(),
+                ConnectionRefused
+                    | ConnectionReset
+                    | ConnectionAborted
+                    | NotConnected
+                    | BrokenPipe
+                    | UnexpectedEof
+            ),
+            _ => false,
+        }
+    }
+
     #[allow(dead_code)]
     #[inline]
     pub(crate) fn protocol(err: impl Display) -> Self {
@@ -178,6 +210,49 @@ pub trait DatabaseError: 'static + Send + Sync + StdError {
     fn constraint(&self) -> Option<&str> {
         None
     }
+
+    /// The kind of this error, for the errors that mean the same on each database.
+    ///
+    /// This is [`ErrorKind::Other`] for any error without a portable meaning, which can
+    /// still be told apart by its [`code`](DatabaseError::code).
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Other
+    }
+}
+
+/// The kind of a [`DatabaseError`], for the errors that mean the same on each database.
+///
+/// Each driver maps its own error codes to these, e.g. both the SQLSTATE `23505` of PostgreSQL
+/// and the error number `1062` of MySQL are a [`UniqueViolation`](ErrorKind::UniqueViolation).
+///
+/// ```rust,ignore
+/// match sqlx::query("INSERT INTO users (name) VALUES ($1)").bind(name).execute(&pool).await {
+///     Err(sqlx::Error::Database(e)) if e.kind() == ErrorKind::UniqueViolation => {
+///         // the name is taken
+///     }
+///     // ...
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A unique constraint or primary key was violated.
+    UniqueViolation,
+
+    /// A foreign key constraint was violated.
+    ForeignKeyViolation,
+
+    /// A `NOT NULL` constraint was violated.
+    NotNullViolation,
+
+    /// A check constraint was violated.
+    CheckViolation,
+
+    /// The transaction was aborted to resolve a deadlock with another one.
+    Deadlock,
+
+    /// An error without a portable meaning.
+    Other,
 }
 
 impl dyn DatabaseError {
@@ -91,6 +91,36 @@ pub trait Executor<'c>: Send + Debug + Sized {
         'c: 'e,
         E: Execute<'q, Self::Database>;
 
+    /// Execute multiple queries and return the generated results as a stream
+    /// from each query, in a stream, asking the database for at most `size` rows at a time.
+    ///
+    /// The next batch of rows is only requested once every row of the previous batch has been
+    /// consumed from the stream, which bounds the memory used to scan a large result set.
+    /// A `size` of `0` fetches every row at once, like [`fetch_many`][Self::fetch_many].
+    ///
+    /// `size` is only a hint: batching is currently only implemented for prepared queries on
+    /// PostgreSQL, and it is ignored by the other drivers, including MySQL and MSSQL, which
+    /// fetch every row at once like [`fetch_many`][Self::fetch_many]. They still only read
+    /// rows from the connection as the stream is polled.
+    fn fetch_many_with_size<'e, 'q: 'e, E: 'q>(
+        self,
+        query: E,
+        size: usize,
+    ) -> BoxStream<
+        'e,
+        Result<
+            Either<<Self::Database as Database>::QueryResult, <Self::Database as Database>::Row>,
+            Error,
+        >,
+    >
+    where
+        'c: 'e,
+        E: Execute<'q, Self::Database>,
+    {
+        let _ = size;
+        self.fetch_many(query)
+    }
+
     /// Execute the query and return all the generated results, collected into a [`Vec`].
     fn fetch_all<'e, 'q: 'e, E: 'q>(
         self,
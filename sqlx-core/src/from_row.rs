@@ -1,3 +1,4 @@
+use crate::column::Column;
 use crate::error::Error;
 use crate::row::Row;
 
@@ -123,6 +124,36 @@ use crate::row::Row;
 ///
 /// This field is compatible with the `default` attribute.
 ///
+/// #### `prefix`
+///
+/// When the columns of a flattened field share names with other columns, as is common in joins,
+/// the `prefix` attribute reads them under prefixed names instead:
+///
+/// ```rust,ignore
+/// #[derive(sqlx::FromRow)]
+/// struct Customer {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// #[derive(sqlx::FromRow)]
+/// struct Order {
+///     id: i32,
+///     #[sqlx(flatten, prefix = "customer_")]
+///     customer: Customer,
+/// }
+/// ```
+///
+/// Given a query such as:
+///
+/// ```sql
+/// SELECT orders.id, customers.id AS customer_id, customers.name AS customer_name
+/// FROM orders JOIN customers ON customers.id = orders.customer_id;
+/// ```
+///
+/// Prefixes of nested flattened fields are appended to one another. The flattened type must
+/// derive `FromRow` for the prefix to have an effect.
+///
 /// ## Manual implementation
 ///
 /// You can also implement the [`FromRow`] trait by hand. This can be useful if you
@@ -177,10 +208,39 @@ use crate::row::Row;
 ///
 pub trait FromRow<'r, R: Row>: Sized {
     fn from_row(row: &'r R) -> Result<Self, Error>;
+
+    /// Like [`from_row`][Self::from_row], but reads the columns of the fields with their names
+    /// prefixed by `prefix`, for `#[sqlx(flatten, prefix = "..")]`.
+    ///
+    /// The default implementation ignores the prefix, so it only has an effect on types that
+    /// derive `FromRow`.
+    #[doc(hidden)]
+    fn from_row_with_prefix(row: &'r R, prefix: &str) -> Result<Self, Error> {
+        let _ = prefix;
+        Self::from_row(row)
+    }
+}
+
+/// Find the name of the column of `row` called `name` with `prefix`, borrowed from the row.
+#[doc(hidden)]
+pub fn prefixed_column_name<'r, R: Row>(
+    row: &'r R,
+    prefix: &str,
+    name: &'r str,
+) -> Result<&'r str, Error> {
+    if prefix.is_empty() {
+        return Ok(name);
+    }
+
+    row.columns()
+        .iter()
+        .map(Column::name)
+        .find(|column| column.strip_prefix(prefix) == Some(name))
+        .ok_or_else(|| Error::ColumnNotFound(format!("{}{}", prefix, name)))
 }
 
 // implement FromRow for tuples of types that implement Decode
-// up to tuples of 9 values
+// up to tuples of 32 values
 
 macro_rules! impl_from_row_for_tuple {
     ($( ($idx:tt) -> $T:ident );+;) => {
@@ -198,172 +258,19 @@ macro_rules! impl_from_row_for_tuple {
     };
 }
 
-impl_from_row_for_tuple!(
-    (0) -> T1;
-);
-
-impl_from_row_for_tuple!(
-    (0) -> T1;
-    (1) -> T2;
-);
-
-impl_from_row_for_tuple!(
-    (0) -> T1;
-    (1) -> T2;
-    (2) -> T3;
-);
-
-impl_from_row_for_tuple!(
-    (0) -> T1;
-    (1) -> T2;
-    (2) -> T3;
-    (3) -> T4;
-);
-
-impl_from_row_for_tuple!(
-    (0) -> T1;
-    (1) -> T2;
-    (2) -> T3;
-    (3) -> T4;
-    (4) -> T5;
-);
-
-impl_from_row_for_tuple!(
-    (0) -> T1;
-    (1) -> T2;
-    (2) -> T3;
-    (3) -> T4;
-    (4) -> T5;
-    (5) -> T6;
-);
-
-impl_from_row_for_tuple!(
-    (0) -> T1;
-    (1) -> T2;
-    (2) -> T3;
-    (3) -> T4;
-    (4) -> T5;
-    (5) -> T6;
-    (6) -> T7;
-);
-
-impl_from_row_for_tuple!(
-    (0) -> T1;
-    (1) -> T2;
-    (2) -> T3;
-    (3) -> T4;
-    (4) -> T5;
-    (5) -> T6;
-    (6) -> T7;
-    (7) -> T8;
-);
-
-impl_from_row_for_tuple!(
-    (0) -> T1;
-    (1) -> T2;
-    (2) -> T3;
-    (3) -> T4;
-    (4) -> T5;
-    (5) -> T6;
-    (6) -> T7;
-    (7) -> T8;
-    (8) -> T9;
-);
-
-impl_from_row_for_tuple!(
-    (0) -> T1;
-    (1) -> T2;
-    (2) -> T3;
-    (3) -> T4;
-    (4) -> T5;
-    (5) -> T6;
-    (6) -> T7;
-    (7) -> T8;
-    (8) -> T9;
-    (9) -> T10;
-);
-
-impl_from_row_for_tuple!(
-    (0) -> T1;
-    (1) -> T2;
-    (2) -> T3;
-    (3) -> T4;
-    (4) -> T5;
-    (5) -> T6;
-    (6) -> T7;
-    (7) -> T8;
-    (8) -> T9;
-    (9) -> T10;
-    (10) -> T11;
-);
-
-impl_from_row_for_tuple!(
-    (0) -> T1;
-    (1) -> T2;
-    (2) -> T3;
-    (3) -> T4;
-    (4) -> T5;
-    (5) -> T6;
-    (6) -> T7;
-    (7) -> T8;
-    (8) -> T9;
-    (9) -> T10;
-    (10) -> T11;
-    (11) -> T12;
-);
-
-impl_from_row_for_tuple!(
-    (0) -> T1;
-    (1) -> T2;
-    (2) -> T3;
-    (3) -> T4;
-    (4) -> T5;
-    (5) -> T6;
-    (6) -> T7;
-    (7) -> T8;
-    (8) -> T9;
-    (9) -> T10;
-    (10) -> T11;
-    (11) -> T12;
-    (12) -> T13;
-);
-
-impl_from_row_for_tuple!(
-    (0) -> T1;
-    (1) -> T2;
-    (2) -> T3;
-    (3) -> T4;
-    (4) -> T5;
-    (5) -> T6;
-    (6) -> T7;
-    (7) -> T8;
-    (8) -> T9;
-    (9) -> T10;
-    (10) -> T11;
-    (11) -> T12;
-    (12) -> T13;
-    (13) -> T14;
-);
-
-impl_from_row_for_tuple!(
-    (0) -> T1;
-    (1) -> T2;
-    (2) -> T3;
-    (3) -> T4;
-    (4) -> T5;
-    (5) -> T6;
-    (6) -> T7;
-    (7) -> T8;
-    (8) -> T9;
-    (9) -> T10;
-    (10) -> T11;
-    (11) -> T12;
-    (12) -> T13;
-    (13) -> T14;
-    (14) -> T15;
-);
+// implement it for every prefix of the given elements, accumulating them one at a time
+macro_rules! impl_from_row_for_tuples {
+    (@ [$($prefix:tt)*]) => {};
+    (@ [$($prefix:tt)*] ($idx:tt) -> $T:ident; $($rest:tt)*) => {
+        impl_from_row_for_tuple!($($prefix)* ($idx) -> $T;);
+        impl_from_row_for_tuples!(@ [$($prefix)* ($idx) -> $T;] $($rest)*);
+    };
+    ($($elements:tt)+) => {
+        impl_from_row_for_tuples!(@ [] $($elements)+);
+    };
+}
 
-impl_from_row_for_tuple!(
+impl_from_row_for_tuples!(
     (0) -> T1;
     (1) -> T2;
     (2) -> T3;
@@ -380,4 +287,20 @@ impl_from_row_for_tuple!(
     (13) -> T14;
     (14) -> T15;
     (15) -> T16;
+    (16) -> T17;
+    (17) -> T18;
+    (18) -> T19;
+    (19) -> T20;
+    (20) -> T21;
+    (21) -> T22;
+    (22) -> T23;
+    (23) -> T24;
+    (24) -> T25;
+    (25) -> T26;
+    (26) -> T27;
+    (27) -> T28;
+    (28) -> T29;
+    (29) -> T30;
+    (30) -> T31;
+    (31) -> T32;
 );
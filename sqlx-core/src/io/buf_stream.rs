@@ -1,9 +1,13 @@
 #![allow(dead_code)]
 
+use std::cmp;
 use std::io;
 use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::task::{Context, Poll};
 
 use bytes::BytesMut;
+use futures_util::ready;
 use sqlx_rt::{AsyncRead, AsyncReadExt, AsyncWrite};
 
 use crate::error::Error;
@@ -11,6 +15,10 @@ use crate::io::write_and_flush::WriteAndFlush;
 use crate::io::{decode::Decode, encode::Encode};
 use std::io::Cursor;
 
+// the capacities the buffers start out with, and are shrunk back down to
+const WBUF_CAPACITY: usize = 512;
+const RBUF_CAPACITY: usize = 4096;
+
 pub struct BufStream<S>
 where
     S: AsyncRead + AsyncWrite + Unpin,
@@ -32,8 +40,8 @@ where
     pub fn new(stream: S) -> Self {
         Self {
             stream,
-            wbuf: Vec::with_capacity(512),
-            rbuf: BytesMut::with_capacity(4096),
+            wbuf: Vec::with_capacity(WBUF_CAPACITY),
+            rbuf: BytesMut::with_capacity(RBUF_CAPACITY),
         }
     }
 
@@ -58,6 +66,34 @@ where
         }
     }
 
+    // poll-based counterpart of `flush`, for use in `Sink` implementations;
+    // only the bytes which were actually written are removed from the buffer
+    pub fn poll_write_and_flush(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        while !self.wbuf.is_empty() {
+            let written = ready!(Pin::new(&mut self.stream).poll_write(cx, &self.wbuf))?;
+
+            if written == 0 {
+                return Poll::Ready(Err(io::Error::from(io::ErrorKind::WriteZero).into()));
+            }
+
+            self.wbuf.drain(..written);
+        }
+
+        Pin::new(&mut self.stream).poll_flush(cx).map_err(Error::Io)
+    }
+
+    /// Shrinks the buffers back to their initial capacity if they have grown beyond it,
+    /// keeping any data that has yet to be read or written.
+    pub fn shrink_buffers(&mut self) {
+        shrink_write_buffer(&mut self.wbuf);
+        shrink_read_buffer(&mut self.rbuf);
+    }
+
+    /// The combined capacity of the read and write buffers.
+    pub fn buffer_capacity(&self) -> usize {
+        self.wbuf.capacity() + self.rbuf.capacity()
+    }
+
     pub async fn read<'de, T>(&mut self, cnt: usize) -> Result<T, Error>
     where
         T: Decode<'de, ()>,
@@ -104,6 +140,20 @@ where
     }
 }
 
+fn shrink_write_buffer(wbuf: &mut Vec<u8>) {
+    wbuf.shrink_to(cmp::max(WBUF_CAPACITY, wbuf.len()));
+}
+
+fn shrink_read_buffer(rbuf: &mut BytesMut) {
+    // a `BytesMut` cannot be shrunk in place, and its capacity does not tell how large its
+    // allocation is: once the values split off from the front of it are dropped, the space
+    // they took is reclaimed when it is next reserved into, so it is always replaced
+    let mut shrunk = BytesMut::with_capacity(cmp::max(RBUF_CAPACITY, rbuf.len()));
+    shrunk.extend_from_slice(rbuf);
+
+    *rbuf = shrunk;
+}
+
 // Holds a buffer which has been temporarily extended, so that
 // we can read into it. Automatically shrinks the buffer back
 // down if the read is cancelled.
@@ -159,3 +209,87 @@ async fn read_raw_into<S: AsyncRead + Unpin>(
 
     Ok(())
 }
+
+#[test]
+fn it_shrinks_buffers_down_to_their_initial_capacity() {
+    let mut wbuf = Vec::with_capacity(1 << 20);
+    wbuf.extend_from_slice(b"unwritten");
+
+    shrink_write_buffer(&mut wbuf);
+    assert_eq!(wbuf, b"unwritten");
+    assert!(wbuf.capacity() >= WBUF_CAPACITY && wbuf.capacity() < 1 << 20);
+
+    let mut rbuf = BytesMut::with_capacity(1 << 20);
+    rbuf.extend_from_slice(b"unread");
+
+    shrink_read_buffer(&mut rbuf);
+    assert_eq!(&rbuf[..], b"unread");
+    assert_eq!(rbuf.capacity(), RBUF_CAPACITY);
+
+    // buffers are never shrunk below their initial capacity, nor their contents
+    let mut rbuf = BytesMut::with_capacity(RBUF_CAPACITY);
+    shrink_read_buffer(&mut rbuf);
+    assert_eq!(rbuf.capacity(), RBUF_CAPACITY);
+
+    let mut rbuf = BytesMut::from(&[0_u8; 10_000][..]);
+    shrink_read_buffer(&mut rbuf);
+    assert_eq!(rbuf.len(), 10_000);
+
+    // a buffer that was read from entirely would otherwise grow back into its allocation
+    let mut rbuf = BytesMut::with_capacity(1 << 20);
+    rbuf.resize(1 << 20, 0);
+    drop(rbuf.split_to(1 << 20));
+
+    shrink_read_buffer(&mut rbuf);
+    rbuf.reserve(1);
+    assert_eq!(rbuf.capacity(), RBUF_CAPACITY);
+}
+
+#[test]
+fn it_shrinks_buffers_grown_by_reads_and_writes() -> Result<(), Error> {
+    use crate::net::{Socket, TcpOptions};
+    use std::io::{Read, Write};
+
+    const LARGE: usize = 1 << 20;
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let port = listener.local_addr()?.port();
+
+    // the peer sends back everything it receives
+    let peer = std::thread::spawn(move || -> io::Result<()> {
+        let (mut stream, _) = listener.accept()?;
+        let mut buf = vec![0; LARGE];
+
+        stream.read_exact(&mut buf)?;
+        stream.write_all(&buf)
+    });
+
+    sqlx_rt::test_block_on(async move {
+        let socket = Socket::connect_tcp("127.0.0.1", port, TcpOptions::default()).await?;
+        let mut stream = BufStream::new(socket);
+
+        assert_eq!(stream.buffer_capacity(), WBUF_CAPACITY + RBUF_CAPACITY);
+
+        stream.wbuf.resize(LARGE, 0xab);
+        stream.flush().await?;
+
+        let echoed = stream.read_raw(LARGE).await?;
+        assert!(echoed.iter().all(|&byte| byte == 0xab));
+        drop(echoed);
+
+        assert!(stream.buffer_capacity() >= LARGE);
+
+        stream.shrink_buffers();
+        assert!(stream.buffer_capacity() < LARGE);
+
+        // the buffers grow again as needed
+        stream.wbuf.extend_from_slice(b"still usable");
+        assert_eq!(stream.wbuf, b"still usable");
+
+        Ok::<_, Error>(())
+    })?;
+
+    peer.join().unwrap()?;
+
+    Ok(())
+}
@@ -1,31 +1,213 @@
 use crate::connection::LogSettings;
 #[cfg(feature = "sqlite")]
 use std::collections::HashSet;
-#[cfg(feature = "sqlite")]
-use std::fmt::Debug;
+use std::fmt::{self, Debug, Display, Write};
 #[cfg(feature = "sqlite")]
 use std::hash::Hash;
 use std::time::Instant;
 
+// text parameters longer than this are truncated in the statement log, and binary parameters
+// are summarized by this many of their first bytes
+const MAX_LOGGED_TEXT_LEN: usize = 100;
+const MAX_LOGGED_BYTES: usize = 16;
+
+/// The value of a bound parameter, as shown in the statement log.
+pub(crate) enum LoggedParam<'a> {
+    Null,
+    Text(&'a str),
+    Bytes(&'a [u8]),
+    /// A value that is shown as formatted, e.g. a number.
+    Other(String),
+}
+
+impl Display for LoggedParam<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoggedParam::Null => f.write_str("NULL"),
+
+            LoggedParam::Text(text) if text.len() <= MAX_LOGGED_TEXT_LEN => {
+                write!(f, "{:?}", text)
+            }
+
+            LoggedParam::Text(text) => {
+                let mut end = MAX_LOGGED_TEXT_LEN;
+
+                while !text.is_char_boundary(end) {
+                    end -= 1;
+                }
+
+                write!(f, "{:?}… ({} bytes)", &text[..end], text.len())
+            }
+
+            LoggedParam::Bytes(bytes) => {
+                write!(f, "<{} bytes: ", bytes.len())?;
+
+                for byte in bytes.iter().take(MAX_LOGGED_BYTES) {
+                    write!(f, "{:02x}", byte)?;
+                }
+
+                if bytes.len() > MAX_LOGGED_BYTES {
+                    f.write_str("…")?;
+                }
+
+                f.write_str(">")
+            }
+
+            LoggedParam::Other(value) => f.write_str(value),
+        }
+    }
+}
+
+fn format_params<'a>(
+    values: impl IntoIterator<Item = LoggedParam<'a>>,
+    redact: Option<&(dyn Fn(usize) -> bool + Send + Sync)>,
+) -> String {
+    let mut params = String::from("[");
+
+    for (index, value) in values.into_iter().enumerate() {
+        if index > 0 {
+            params.push_str(", ");
+        }
+
+        if redact.map_or(false, |redact| redact(index)) {
+            params.push_str("<redacted>");
+        } else {
+            let _ = write!(params, "{}", value);
+        }
+    }
+
+    params.push(']');
+    params
+}
+
+/// A handle on the tracing span of a statement, to work through its results within it.
+#[derive(Clone)]
+pub(crate) struct QuerySpan(#[cfg(feature = "tracing")] tracing::Span);
+
+impl QuerySpan {
+    /// Polls `stream` within the span, so that events emitted while the results of the
+    /// statement are received are recorded in it.
+    #[cfg(all(
+        feature = "tracing",
+        any(feature = "postgres", feature = "mysql", feature = "mssql")
+    ))]
+    pub(crate) fn instrument<S>(self, stream: S) -> impl futures_core::Stream<Item = S::Item>
+    where
+        S: futures_core::Stream,
+    {
+        let mut stream = Box::pin(stream);
+
+        futures_util::stream::poll_fn(move |cx| {
+            let _entered = self.0.enter();
+            futures_core::Stream::poll_next(stream.as_mut(), cx)
+        })
+    }
+
+    #[cfg(all(
+        not(feature = "tracing"),
+        any(feature = "postgres", feature = "mysql", feature = "mssql")
+    ))]
+    pub(crate) fn instrument<S: futures_core::Stream>(self, stream: S) -> S {
+        stream
+    }
+
+    /// Enters the span until the returned guard is dropped.
+    #[cfg(all(feature = "tracing", feature = "sqlite"))]
+    pub(crate) fn entered(self) -> tracing::span::EnteredSpan {
+        self.0.entered()
+    }
+
+    #[cfg(all(not(feature = "tracing"), feature = "sqlite"))]
+    pub(crate) fn entered(self) -> Self {
+        self
+    }
+}
+
 pub(crate) struct QueryLogger<'q> {
     sql: &'q str,
     rows_returned: u64,
     rows_affected: u64,
+    params: Option<String>,
     start: Instant,
     settings: LogSettings,
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
 }
 
 impl<'q> QueryLogger<'q> {
-    pub(crate) fn new(sql: &'q str, settings: LogSettings) -> Self {
+    /// `db_system` is the name of the database as given by the OpenTelemetry conventions,
+    /// e.g. `postgresql`.
+    pub(crate) fn new(sql: &'q str, db_system: &'static str, settings: LogSettings) -> Self {
+        #[cfg(not(feature = "tracing"))]
+        let _ = db_system;
+
         Self {
             sql,
             rows_returned: 0,
             rows_affected: 0,
+            params: None,
             start: Instant::now(),
+            #[cfg(feature = "tracing")]
+            span: tracing::info_span!(
+                target: "sqlx::query",
+                "sqlx.query",
+                db.system = db_system,
+                db.statement = sql,
+                db.params = tracing::field::Empty,
+                db.rows_affected = tracing::field::Empty,
+                db.rows_returned = tracing::field::Empty,
+                elapsed_ms = tracing::field::Empty,
+            ),
             settings,
         }
     }
 
+    /// Records the bound parameters of the statement.
+    ///
+    /// The values are only included in the tracing span if enabled with
+    /// [`ConnectOptions::trace_params`][crate::connection::ConnectOptions::trace_params];
+    /// otherwise they are redacted. They are also redacted if any are masked by
+    /// [`LogSettings::redact_parameters`][crate::connection::LogSettings::redact_parameters],
+    /// as the arguments are recorded in their encoded form, which can't be masked by index.
+    pub(crate) fn record_params(&self, params: &dyn Debug) {
+        #[cfg(feature = "tracing")]
+        if self.settings.trace_params && self.settings.redact_parameter.is_none() {
+            self.span.record("db.params", tracing::field::debug(params));
+        } else {
+            self.span.record("db.params", "[redacted]");
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        let _ = params;
+    }
+
+    /// Records the values of the bound parameters of the statement, to include them in the
+    /// statement log if enabled with
+    /// [`LogSettings::log_parameters`][crate::connection::LogSettings::log_parameters].
+    pub(crate) fn record_param_values<'a>(
+        &mut self,
+        values: impl IntoIterator<Item = LoggedParam<'a>>,
+    ) {
+        if !self.settings.log_parameters {
+            return;
+        }
+
+        self.params = Some(format_params(
+            values,
+            self.settings.redact_parameter.as_deref(),
+        ));
+    }
+
+    #[cfg(feature = "tracing")]
+    pub(crate) fn span(&self) -> QuerySpan {
+        QuerySpan(self.span.clone())
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    pub(crate) fn span(&self) -> QuerySpan {
+        QuerySpan()
+    }
+
     pub(crate) fn increment_rows_returned(&mut self) {
         self.rows_returned += 1;
     }
@@ -36,8 +218,12 @@ impl<'q> QueryLogger<'q> {
 
     pub(crate) fn finish(&self) {
         let elapsed = self.start.elapsed();
+        let is_slow = elapsed >= self.settings.slow_statements_duration;
 
-        let lvl = if elapsed >= self.settings.slow_statements_duration {
+        #[cfg(feature = "tracing")]
+        self.finish_span(elapsed, is_slow);
+
+        let lvl = if is_slow {
             self.settings.slow_statements_level
         } else {
             self.settings.statements_level
@@ -63,11 +249,16 @@ impl<'q> QueryLogger<'q> {
                 String::new()
             };
 
+            let params = match &self.params {
+                Some(params) => format!(", parameters: {}", params),
+                None => String::new(),
+            };
+
             log::logger().log(
                 &log::Record::builder()
                     .args(format_args!(
-                        "{}; rows affected: {}, rows returned: {}, elapsed: {:.3?}{}",
-                        summary, self.rows_affected, self.rows_returned, elapsed, sql
+                        "{}; rows affected: {}, rows returned: {}, elapsed: {:.3?}{}{}",
+                        summary, self.rows_affected, self.rows_returned, elapsed, params, sql
                     ))
                     .level(lvl)
                     .module_path_static(Some("sqlx::query"))
@@ -76,6 +267,25 @@ impl<'q> QueryLogger<'q> {
             );
         }
     }
+
+    #[cfg(feature = "tracing")]
+    fn finish_span(&self, elapsed: std::time::Duration, is_slow: bool) {
+        let span = &self.span;
+
+        span.record("db.rows_affected", self.rows_affected);
+        span.record("db.rows_returned", self.rows_returned);
+        span.record("elapsed_ms", elapsed.as_secs_f64() * 1000.0);
+
+        if is_slow && self.settings.slow_statements_level != log::LevelFilter::Off {
+            tracing::warn!(
+                target: "sqlx::query",
+                parent: span,
+                elapsed_ms = elapsed.as_secs_f64() * 1000.0,
+                "slow statement: execution time exceeded {:.3?}",
+                self.settings.slow_statements_duration
+            );
+        }
+    }
 }
 
 impl<'q> Drop for QueryLogger<'q> {
@@ -178,3 +388,25 @@ fn parse_query_summary(sql: &str) -> String {
         .collect::<Vec<&str>>()
         .join(" ")
 }
+
+#[test]
+fn it_summarizes_large_parameters() {
+    assert_eq!(LoggedParam::Null.to_string(), "NULL");
+    assert_eq!(LoggedParam::Text("alice").to_string(), r#""alice""#);
+    assert_eq!(LoggedParam::Other("42".into()).to_string(), "42");
+
+    assert_eq!(
+        LoggedParam::Text(&"é".repeat(60)).to_string(),
+        format!("{:?}… (120 bytes)", "é".repeat(50))
+    );
+
+    assert_eq!(
+        LoggedParam::Bytes(&[0xde, 0xad, 0xbe, 0xef]).to_string(),
+        "<4 bytes: deadbeef>"
+    );
+
+    assert_eq!(
+        LoggedParam::Bytes(&[0xab; 1024]).to_string(),
+        format!("<1024 bytes: {}…>", "ab".repeat(16))
+    );
+}
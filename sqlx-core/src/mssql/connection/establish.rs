@@ -62,9 +62,11 @@ impl MssqlConnection {
             //       all messages are mostly informational (ENVCHANGE, INFO, LOGINACK)
 
             match stream.recv_message().await? {
-                Message::LoginAck(_) => {
+                Message::LoginAck(ack) => {
                     // indicates that the login was successful
-                    // no action is needed, we are just going to keep waiting till we hit <Done>
+                    // we are just going to keep waiting till we hit <Done>
+                    let version = ack.program_version;
+                    stream.server_version = (version.major, version.minor, version.build);
                 }
 
                 Message::Done(_) => {
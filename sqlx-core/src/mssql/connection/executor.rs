@@ -79,9 +79,15 @@ impl<'c> Executor<'c> for &'c mut MssqlConnection {
     {
         let sql = query.sql();
         let arguments = query.take_arguments();
-        let mut logger = QueryLogger::new(sql, self.log_settings.clone());
+        let mut logger = QueryLogger::new(sql, "mssql", self.log_settings.clone());
 
-        Box::pin(try_stream! {
+        if let Some(arguments) = &arguments {
+            logger.record_params(arguments);
+        }
+
+        let span = logger.span();
+
+        Box::pin(span.instrument(try_stream! {
             self.run(sql, arguments).await?;
 
             loop {
@@ -97,7 +103,31 @@ impl<'c> Executor<'c> for &'c mut MssqlConnection {
                         r#yield!(Either::Right(MssqlRow { row, column_names, columns }));
                     }
 
-                    Message::Done(done) | Message::DoneProc(done) => {
+                    Message::Done(done) => {
+                        if !done.status.contains(Status::DONE_MORE) {
+                            self.stream.handle_done(&done);
+                        }
+
+                        // every statement in a batch ends with a DONE token; statements that
+                        // do not report a count (e.g., DDL) still produce a result so that
+                        // callers see one result per statement
+                        let rows_affected = if done.status.contains(Status::DONE_COUNT) {
+                            done.affected_rows
+                        } else {
+                            0
+                        };
+
+                        logger.increase_rows_affected(rows_affected);
+                        r#yield!(Either::Left(MssqlQueryResult {
+                            rows_affected,
+                        }));
+
+                        if !done.status.contains(Status::DONE_MORE) {
+                            break;
+                        }
+                    }
+
+                    Message::DoneProc(done) => {
                         if !done.status.contains(Status::DONE_MORE) {
                             self.stream.handle_done(&done);
                         }
@@ -116,13 +146,16 @@ impl<'c> Executor<'c> for &'c mut MssqlConnection {
                     }
 
                     Message::DoneInProc(done) => {
-                        if done.status.contains(Status::DONE_COUNT) {
-                            let rows_affected = done.affected_rows;
-                            logger.increase_rows_affected(rows_affected);
-                            r#yield!(Either::Left(MssqlQueryResult {
-                                rows_affected,
-                            }));
-                        }
+                        let rows_affected = if done.status.contains(Status::DONE_COUNT) {
+                            done.affected_rows
+                        } else {
+                            0
+                        };
+
+                        logger.increase_rows_affected(rows_affected);
+                        r#yield!(Either::Left(MssqlQueryResult {
+                            rows_affected,
+                        }));
                     }
 
                     _ => {}
@@ -130,7 +163,7 @@ impl<'c> Executor<'c> for &'c mut MssqlConnection {
             }
 
             Ok(())
-        })
+        }))
     }
 
     fn fetch_optional<'e, 'q: 'e, E: 'q>(
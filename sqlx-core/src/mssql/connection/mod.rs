@@ -5,7 +5,8 @@ use crate::executor::Executor;
 use crate::mssql::connection::stream::MssqlStream;
 use crate::mssql::statement::MssqlStatementMetadata;
 use crate::mssql::{Mssql, MssqlConnectOptions};
-use crate::transaction::Transaction;
+use crate::net::TlsInfo;
+use crate::transaction::{Transaction, TransactionOptions};
 use futures_core::future::BoxFuture;
 use futures_util::{FutureExt, TryFutureExt};
 use std::fmt::{self, Debug, Formatter};
@@ -22,6 +23,40 @@ pub struct MssqlConnection {
     log_settings: LogSettings,
 }
 
+impl MssqlConnection {
+    /// The version of the server as `(major, minor, build)`, as reported when logging in.
+    ///
+    /// The major version is e.g. `15` for SQL Server 2019 and `16` for SQL Server 2022.
+    pub fn server_version(&self) -> (u8, u8, u16) {
+        self.stream.server_version
+    }
+
+    /// The parameters of the TLS session of this connection, or `None` if it is not encrypted.
+    ///
+    /// Encryption is not supported by the MSSQL driver yet, which tells the server so when
+    /// connecting, so this is currently always `None`.
+    pub fn tls_info(&self) -> Option<TlsInfo> {
+        self.stream.tls_info()
+    }
+
+    /// The settings used to log the statements executed on this connection.
+    pub fn log_settings(&self) -> &LogSettings {
+        &self.log_settings
+    }
+
+    /// Change how the statements executed on this connection are logged,
+    /// starting with the next statement.
+    ///
+    /// This overrides the settings from [`ConnectOptions::log_statements`] and
+    /// [`ConnectOptions::log_slow_statements`] for this connection only.
+    ///
+    /// [`ConnectOptions::log_statements`]: crate::connection::ConnectOptions::log_statements
+    /// [`ConnectOptions::log_slow_statements`]: crate::connection::ConnectOptions::log_slow_statements
+    pub fn set_log_settings(&mut self, settings: LogSettings) {
+        self.log_settings = settings;
+    }
+}
+
 impl Debug for MssqlConnection {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("MssqlConnection").finish()
@@ -71,6 +106,20 @@ impl Connection for MssqlConnection {
         Transaction::begin(self)
     }
 
+    fn begin_with_options(
+        &mut self,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<Transaction<'_, Self::Database>, Error>>
+    where
+        Self: Sized,
+    {
+        Transaction::begin_with_options(self, options)
+    }
+
+    fn shrink_buffers(&mut self) {
+        self.stream.shrink_buffers();
+    }
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         self.stream.wait_until_ready().boxed()
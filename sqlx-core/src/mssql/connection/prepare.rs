@@ -25,13 +25,21 @@ pub(crate) async fn prepare(
     static PARAMS_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"@p[[:alnum:]]+").unwrap());
 
     let mut params = String::new();
+    let mut names = Vec::new();
+
+    for m in PARAMS_RE.find_iter(sql) {
+        // a parameter may be used more than once, but can only be declared once
+        if names.contains(&m.as_str()) {
+            continue;
+        }
+
+        names.push(m.as_str());
 
-    for m in PARAMS_RE.captures_iter(sql) {
         if !params.is_empty() {
             params.push_str(",");
         }
 
-        params.push_str(&m[0]);
+        params.push_str(m.as_str());
 
         // NOTE: this means that a query! of `SELECT @p1` will have the macros believe
         //       it will return nvarchar(1); this is a greater issue with `query!` that we
@@ -134,6 +142,7 @@ pub(crate) async fn prepare(
     let metadata = Arc::new(MssqlStatementMetadata {
         columns: conn.stream.columns.as_ref().clone(),
         column_names: conn.stream.column_names.as_ref().clone(),
+        parameters: names.len(),
     });
 
     conn.cache_statement.insert(sql, metadata.clone());
@@ -34,6 +34,11 @@ pub(crate) struct MssqlStream {
     pub(crate) transaction_descriptor: u64,
     pub(crate) transaction_depth: usize,
 
+    // set when a transaction began with its own isolation level, which in MSSQL lasts for the
+    // rest of the session, to the level of the session before it, which is restored when the
+    // transaction ends
+    pub(crate) reset_isolation_level: Option<&'static str>,
+
     // current TabularResult from the server that we are iterating over
     response: Option<(PacketHeader, Bytes)>,
 
@@ -44,13 +49,18 @@ pub(crate) struct MssqlStream {
 
     // Maximum size of packets to send to the server
     pub(crate) max_packet_size: usize,
+
+    // version of the server from LOGINACK, as (major, minor, build)
+    pub(crate) server_version: (u8, u8, u16),
 }
 
 impl MssqlStream {
     pub(super) async fn connect(options: &MssqlConnectOptions) -> Result<Self, Error> {
-        let inner = BufStream::new(MaybeTlsStream::Raw(
-            TcpStream::connect((&*options.host, options.port)).await?,
-        ));
+        let socket = TcpStream::connect((&*options.host, options.port)).await?;
+
+        options.tcp.apply(&socket)?;
+
+        let inner = BufStream::new(MaybeTlsStream::Raw(socket));
 
         Ok(Self {
             inner,
@@ -60,10 +70,12 @@ impl MssqlStream {
             pending_done_count: 0,
             transaction_descriptor: 0,
             transaction_depth: 0,
+            reset_isolation_level: None,
             max_packet_size: options
                 .requested_packet_size
                 .try_into()
                 .unwrap_or(usize::MAX),
+            server_version: (0, 0, 0),
         })
     }
 
@@ -1,7 +1,7 @@
 use std::error::Error as StdError;
 use std::fmt::{self, Debug, Display, Formatter};
 
-use crate::error::DatabaseError;
+use crate::error::{DatabaseError, ErrorKind};
 use crate::mssql::protocol::error::Error;
 
 /// An error returned from the MSSQL database.
@@ -35,6 +35,27 @@ impl DatabaseError for MssqlDatabaseError {
         &self.0.message
     }
 
+    fn kind(&self) -> ErrorKind {
+        // https://learn.microsoft.com/en-us/sql/relational-databases/errors-events/database-engine-events-and-errors
+        let message = &self.0.message;
+
+        match self.0.number {
+            // a duplicate key in a unique constraint or a unique index
+            2627 | 2601 => ErrorKind::UniqueViolation,
+            // the same number is used for both kinds of constraint, which the message names;
+            // a foreign key is a REFERENCE constraint when the referenced row is deleted. The
+            // message is in the language of the session, so this can be `Other` for languages
+            // other than English
+            547 if message.contains("FOREIGN KEY") || message.contains("REFERENCE") => {
+                ErrorKind::ForeignKeyViolation
+            }
+            547 if message.contains("CHECK") => ErrorKind::CheckViolation,
+            515 => ErrorKind::NotNullViolation,
+            1205 => ErrorKind::Deadlock,
+            _ => ErrorKind::Other,
+        }
+    }
+
     #[doc(hidden)]
     fn as_error(&self) -> &(dyn StdError + Send + Sync + 'static) {
         self
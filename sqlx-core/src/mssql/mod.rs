@@ -31,6 +31,9 @@ pub use transaction::MssqlTransactionManager;
 pub use type_info::MssqlTypeInfo;
 pub use value::{MssqlValue, MssqlValueRef};
 
+#[doc(no_inline)]
+pub use crate::net::TlsInfo;
+
 /// An alias for [`Pool`][crate::pool::Pool], specialized for MSSQL.
 pub type MssqlPool = crate::pool::Pool<Mssql>;
 
@@ -24,4 +24,7 @@ impl ConnectOptions for MssqlConnectOptions {
         self.log_settings.log_slow_statements(level, duration);
         self
     }
+
+    // `log_parameters`, `redact_parameters` and `trace_params` are left to do nothing, as the
+    // parameters are only available in their encoded form
 }
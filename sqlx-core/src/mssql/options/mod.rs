@@ -1,12 +1,22 @@
+use std::time::Duration;
+
 use crate::connection::LogSettings;
+use crate::net::TcpOptions;
 
 mod connect;
 mod parse;
 
+/// Options and flags which can be used to configure a MSSQL connection.
+///
+/// Unlike the PostgreSQL and MySQL drivers, there is no `statement_timeout` option: SQL Server
+/// has no session setting that makes the server interrupt long-running statements. Use a
+/// client-side timeout around the query instead, and close the connection if it fires, since
+/// the interrupted request may still be running on the server.
 #[derive(Debug, Clone)]
 pub struct MssqlConnectOptions {
     pub(crate) host: String,
     pub(crate) port: u16,
+    pub(crate) tcp: TcpOptions,
     pub(crate) username: String,
     pub(crate) database: String,
     pub(crate) password: Option<String>,
@@ -26,6 +36,7 @@ impl MssqlConnectOptions {
         Self {
             port: 1433,
             host: String::from("localhost"),
+            tcp: TcpOptions::default(),
             database: String::from("master"),
             username: String::from("sa"),
             password: None,
@@ -44,6 +55,24 @@ impl MssqlConnectOptions {
         self
     }
 
+    /// Enables TCP keepalive on the connection, sending the first keepalive probe after the
+    /// connection has been idle for the given duration.
+    ///
+    /// This keeps long-lived idle connections from being silently dropped by NATs and
+    /// firewalls. By default set to `None`, which leaves keepalive disabled.
+    pub fn tcp_keepalive(mut self, time: Option<Duration>) -> Self {
+        self.tcp.keepalive = time;
+        self
+    }
+
+    /// Sets `TCP_NODELAY` on the connection, which disables Nagle's algorithm.
+    ///
+    /// By default set to `false`.
+    pub fn tcp_nodelay(mut self, nodelay: bool) -> Self {
+        self.tcp.nodelay = nodelay;
+        self
+    }
+
     pub fn username(mut self, username: &str) -> Self {
         self.username = username.to_owned();
         self
@@ -41,6 +41,16 @@ impl FromStr for MssqlConnectOptions {
             options = options.database(path);
         }
 
+        if let Some((key, _)) = url.query_pairs().next() {
+            return Err(Error::Configuration(
+                format!(
+                    "unknown query parameter `{}` while parsing connection URL",
+                    key
+                )
+                .into(),
+            ));
+        }
+
         Ok(options)
     }
 }
@@ -85,6 +85,10 @@ pub(crate) enum DataType {
     Image = 0x22,
     NText = 0x63,
     Variant = 0x62,
+
+    // table-valued parameter
+    // https://docs.microsoft.com/en-us/openspecs/windows_protocols/ms-tds/7a8e6c29-ba28-4e39-95a3-a5e8ee5b8b4b
+    Table = 0xf3,
 }
 
 // http://msdn.microsoft.com/en-us/library/dd358284.aspx
@@ -96,6 +100,8 @@ pub(crate) struct TypeInfo {
     pub(crate) scale: u8,
     pub(crate) precision: u8,
     pub(crate) collation: Option<Collation>,
+    // name of the user-defined table type of a table-valued parameter
+    pub(crate) type_name: Option<String>,
 }
 
 impl TypeInfo {
@@ -106,6 +112,7 @@ impl TypeInfo {
             scale: 0,
             precision: 0,
             collation: None,
+            type_name: None,
         }
     }
 
@@ -186,6 +193,7 @@ impl TypeInfo {
                     ty,
                     precision: 0,
                     collation: None,
+                    type_name: None,
                 }
             }
 
@@ -211,6 +219,7 @@ impl TypeInfo {
                     scale,
                     ty,
                     collation: None,
+                    type_name: None,
                 }
             }
 
@@ -226,6 +235,7 @@ impl TypeInfo {
                     collation: Some(collation),
                     scale: 0,
                     precision: 0,
+                    type_name: None,
                 }
             }
             _ => {
@@ -293,6 +303,11 @@ impl TypeInfo {
                     buf.push(0);
                 }
             }
+            DataType::Table => {
+                // the rest of TVP_TYPE_INFO (type name, column metadata and rows) is written
+                // by the value itself, see `MssqlTableValue`
+            }
+
             DataType::Xml
             | DataType::UserDefined
             | DataType::Text
@@ -384,6 +399,9 @@ impl TypeInfo {
                     Some(buf.split_to(size as usize))
                 }
             }
+
+            // table-valued parameters are never sent by the server
+            DataType::Table => None,
         }
     }
 
@@ -427,7 +445,8 @@ impl TypeInfo {
             | DataType::DateN
             | DataType::Float
             | DataType::SmallMoney
-            | DataType::BigInt => {
+            | DataType::BigInt
+            | DataType::Table => {
                 self.put_fixed_value(buf, value);
             }
 
@@ -481,7 +500,12 @@ impl TypeInfo {
         buf.push(0);
 
         let size = if let IsNull::Yes = value.encode(buf) {
-            0xFF
+            match self.ty {
+                // CHARBIN_NULL
+                DataType::Char | DataType::VarChar | DataType::Binary | DataType::VarBinary => 0xFF,
+                // GEN_NULL
+                _ => 0,
+            }
         } else {
             (buf.len() - offset - 1) as u8
         };
@@ -514,14 +538,24 @@ impl TypeInfo {
         buf.extend(&0_u32.to_le_bytes()); // chunk length
         let start_of_bytes = buf.len();
 
-        let size = if let IsNull::Yes = value.encode(buf) {
-            unreachable!("put_big_blob should never be called with NULL value");
-        } else {
-            u32::try_from(buf.len() - start_of_bytes).expect("blobs >4GB not supported")
-        };
+        if let IsNull::Yes = value.encode(buf) {
+            // PLP_NULL
+            buf.truncate(start_of_value);
+            buf.extend(&u64::MAX.to_le_bytes());
+            return;
+        }
+
+        let size = u32::try_from(buf.len() - start_of_bytes).expect("blobs >4GB not supported");
 
         buf[start_of_value..(start_of_value + 4)].copy_from_slice(&size.to_le_bytes());
-        buf[start_of_chunk..(start_of_chunk + 4)].copy_from_slice(&size.to_le_bytes());
+
+        if size == 0 {
+            // an empty value has no chunks, only the terminator
+            buf.truncate(start_of_chunk);
+        } else {
+            buf[start_of_chunk..(start_of_chunk + 4)].copy_from_slice(&size.to_le_bytes());
+        }
+
         buf.extend(&0_u32.to_le_bytes()); // end of chunks marker
     }
 
@@ -598,6 +632,7 @@ impl TypeInfo {
             DataType::Image => "IMAGE",
             DataType::NText => "NTEXT",
             DataType::Variant => "SQL_VARIANT",
+            DataType::Table => "TABLE",
         }
     }
 
@@ -736,6 +771,10 @@ impl TypeInfo {
             DataType::Image => s.push_str("image"),
             DataType::NText => s.push_str("ntext"),
             DataType::Variant => s.push_str("sql_variant"),
+            DataType::Table => {
+                s.push_str(self.type_name.as_deref().unwrap_or("table"));
+                s.push_str(" READONLY");
+            }
         }
     }
 }
@@ -785,6 +824,7 @@ impl DataType {
             0x22 => DataType::Image,
             0x63 => DataType::NText,
             0x62 => DataType::Variant,
+            0xf3 => DataType::Table,
 
             ty => {
                 return Err(err_protocol!("unknown data type 0x{:02x}", ty));
@@ -794,6 +834,16 @@ impl DataType {
 }
 
 impl Collation {
+    // the collation used for the string values we send
+    pub(crate) const DEFAULT: Collation = Collation {
+        locale: 0x0481,
+        flags: CollationFlags::IGNORE_CASE
+            .union(CollationFlags::IGNORE_WIDTH)
+            .union(CollationFlags::IGNORE_KANA),
+        sort: 52,
+        version: 0,
+    };
+
     pub(crate) fn get(buf: &mut Bytes) -> Collation {
         let locale_sort_version = buf.get_u32_le();
         let locale = locale_sort_version & 0xfffff;
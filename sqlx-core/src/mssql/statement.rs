@@ -18,6 +18,8 @@ pub struct MssqlStatement<'q> {
 pub(crate) struct MssqlStatementMetadata {
     pub(crate) columns: Vec<MssqlColumn>,
     pub(crate) column_names: HashMap<UStr, usize>,
+    // the number of distinct parameters, as MSSQL does not describe their types
+    pub(crate) parameters: usize,
 }
 
 impl<'q> Statement<'q> for MssqlStatement<'q> {
@@ -35,7 +37,7 @@ impl<'q> Statement<'q> for MssqlStatement<'q> {
     }
 
     fn parameters(&self) -> Option<Either<&[MssqlTypeInfo], usize>> {
-        None
+        Some(Either::Right(self.metadata.parameters))
     }
 
     fn columns(&self) -> &[MssqlColumn] {
@@ -68,7 +70,7 @@ impl<'q> From<MssqlStatement<'q>> for crate::any::AnyStatement<'q> {
                 .map(|col| col.clone().into())
                 .collect(),
             column_names: std::sync::Arc::new(statement.metadata.column_names.clone()),
-            parameters: None,
+            parameters: Some(Either::Right(statement.metadata.parameters)),
             sql: statement.sql,
         }
     }
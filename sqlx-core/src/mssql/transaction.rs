@@ -7,7 +7,38 @@ use crate::executor::Executor;
 use crate::mssql::protocol::packet::PacketType;
 use crate::mssql::protocol::sql_batch::SqlBatch;
 use crate::mssql::{Mssql, MssqlConnection};
-use crate::transaction::TransactionManager;
+use crate::query_scalar::query_scalar;
+use crate::transaction::{TransactionManager, TransactionOptions};
+
+// ends the outermost transaction with `sql`, restoring the isolation level of the session if
+// the transaction had changed it
+fn end_transaction_sql(conn: &mut MssqlConnection, sql: &'static str) -> Cow<'static, str> {
+    match conn.stream.reset_isolation_level.take() {
+        Some(level) => Cow::Owned(format!(
+            "{}; SET TRANSACTION ISOLATION LEVEL {}",
+            sql, level
+        )),
+        None => Cow::Borrowed(sql),
+    }
+}
+
+// https://learn.microsoft.com/en-us/sql/relational-databases/system-dynamic-management-views/sys-dm-exec-sessions-transact-sql
+async fn session_isolation_level(conn: &mut MssqlConnection) -> Result<&'static str, Error> {
+    let level: i16 = query_scalar(
+        "SELECT transaction_isolation_level FROM sys.dm_exec_sessions WHERE session_id = @@SPID",
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+
+    Ok(match level {
+        1 => "READ UNCOMMITTED",
+        3 => "REPEATABLE READ",
+        4 => "SERIALIZABLE",
+        5 => "SNAPSHOT",
+        // 0 is unspecified, for which the server uses its default
+        _ => "READ COMMITTED",
+    })
+}
 
 /// Implementation of [`TransactionManager`] for MSSQL.
 pub struct MssqlTransactionManager;
@@ -32,6 +63,44 @@ impl TransactionManager for MssqlTransactionManager {
         })
     }
 
+    fn begin_with_options(
+        conn: &mut MssqlConnection,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            if conn.stream.transaction_depth > 0 {
+                return Err(Error::Savepoint(
+                    "the options of a transaction cannot be applied to a savepoint".into(),
+                ));
+            }
+
+            if options.read_only {
+                return Err(Error::Configuration(
+                    "MSSQL does not support read-only transactions".into(),
+                ));
+            }
+
+            let query = match options.isolation_level {
+                Some(level) => {
+                    let previous = session_isolation_level(conn).await?;
+                    conn.stream.reset_isolation_level = Some(previous);
+
+                    Cow::Owned(format!(
+                        "SET TRANSACTION ISOLATION LEVEL {}; BEGIN TRAN ",
+                        level.as_sql()
+                    ))
+                }
+
+                None => Cow::Borrowed("BEGIN TRAN "),
+            };
+
+            conn.execute(&*query).await?;
+            conn.stream.transaction_depth = 1;
+
+            Ok(())
+        })
+    }
+
     fn commit(conn: &mut MssqlConnection) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(async move {
             let depth = conn.stream.transaction_depth;
@@ -39,7 +108,8 @@ impl TransactionManager for MssqlTransactionManager {
             if depth > 0 {
                 if depth == 1 {
                     // savepoints are not released in MSSQL
-                    conn.execute("COMMIT TRAN").await?;
+                    let query = end_transaction_sql(conn, "COMMIT TRAN");
+                    conn.execute(&*query).await?;
                 }
 
                 conn.stream.transaction_depth = depth - 1;
@@ -55,7 +125,7 @@ impl TransactionManager for MssqlTransactionManager {
 
             if depth > 0 {
                 let query = if depth == 1 {
-                    Cow::Borrowed("ROLLBACK TRAN")
+                    end_transaction_sql(conn, "ROLLBACK TRAN")
                 } else {
                     Cow::Owned(format!("ROLLBACK TRAN _sqlx_savepoint_{}", depth - 1))
                 };
@@ -73,7 +143,7 @@ impl TransactionManager for MssqlTransactionManager {
 
         if depth > 0 {
             let query = if depth == 1 {
-                Cow::Borrowed("ROLLBACK TRAN")
+                end_transaction_sql(conn, "ROLLBACK TRAN")
             } else {
                 Cow::Owned(format!("ROLLBACK TRAN _sqlx_savepoint_{}", depth - 1))
             };
@@ -91,4 +161,34 @@ impl TransactionManager for MssqlTransactionManager {
             conn.stream.transaction_depth = depth - 1;
         }
     }
+
+    fn savepoint<'c>(
+        conn: &'c mut MssqlConnection,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move {
+            conn.execute(&*format!("SAVE TRAN {}", name)).await?;
+
+            Ok(())
+        })
+    }
+
+    fn rollback_to_savepoint<'c>(
+        conn: &'c mut MssqlConnection,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move {
+            conn.execute(&*format!("ROLLBACK TRAN {}", name)).await?;
+
+            Ok(())
+        })
+    }
+
+    fn release_savepoint<'c>(
+        _conn: &'c mut MssqlConnection,
+        _name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        // savepoints are not released in MSSQL
+        Box::pin(async move { Ok(()) })
+    }
 }
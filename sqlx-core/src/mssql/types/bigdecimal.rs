@@ -16,6 +16,7 @@ impl Type<Mssql> for BigDecimal {
             ty: DataType::NumericN,
             size: 17,
             collation: None,
+            type_name: None,
             precision: 38,
         })
     }
@@ -23,6 +23,7 @@ impl Type<Mssql> for NaiveDateTime {
             ty: DataType::DateTime2N,
             size: 8,
             collation: None,
+            type_name: None,
             precision: 0,
         })
     }
@@ -47,6 +48,7 @@ impl Type<Mssql> for NaiveDate {
             ty: DataType::DateN,
             size: 3,
             collation: None,
+            type_name: None,
             precision: 10,
         })
     }
@@ -64,6 +66,7 @@ impl Type<Mssql> for NaiveTime {
             ty: DataType::TimeN,
             size: 5,
             collation: None,
+            type_name: None,
             precision: 0,
         })
     }
@@ -83,6 +86,7 @@ where
             ty: DataType::DateTimeOffsetN,
             size: 8,
             collation: None,
+            type_name: None,
             precision: 34,
         })
     }
@@ -14,6 +14,7 @@ impl Type<Mssql> for Decimal {
             ty: DataType::NumericN,
             size: 17,
             collation: None,
+            type_name: None,
             precision: 38,
         })
     }
@@ -7,11 +7,17 @@ mod bytes;
 mod float;
 mod int;
 mod str;
+mod table_value;
 mod uint;
 
+pub use table_value::{MssqlTableRow, MssqlTableValue};
+
 #[cfg(feature = "chrono")]
 mod chrono;
 
+#[cfg(feature = "time")]
+mod time;
+
 #[cfg(feature = "json")]
 mod json;
 
@@ -21,6 +27,9 @@ mod decimal;
 #[cfg(feature = "bigdecimal")]
 mod bigdecimal;
 
+#[cfg(feature = "uuid")]
+mod uuid;
+
 impl<'q, T: 'q + Encode<'q, Mssql>> Encode<'q, Mssql> for Option<T> {
     fn encode(self, buf: &mut Vec<u8>) -> IsNull {
         if let Some(v) = self {
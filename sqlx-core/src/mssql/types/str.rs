@@ -2,7 +2,7 @@ use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::mssql::io::MssqlBufMutExt;
-use crate::mssql::protocol::type_info::{Collation, CollationFlags, DataType, TypeInfo};
+use crate::mssql::protocol::type_info::{Collation, DataType, TypeInfo};
 use crate::mssql::{Mssql, MssqlTypeInfo, MssqlValueRef};
 use crate::types::Type;
 use std::borrow::Cow;
@@ -49,14 +49,8 @@ impl Encode<'_, Mssql> for &'_ str {
             size,
             scale: 0,
             precision: 0,
-            collation: Some(Collation {
-                locale: 0x0481,
-                flags: CollationFlags::IGNORE_CASE
-                    | CollationFlags::IGNORE_WIDTH
-                    | CollationFlags::IGNORE_KANA,
-                sort: 52,
-                version: 0,
-            }),
+            collation: Some(Collation::DEFAULT),
+            type_name: None,
         }))
     }
 
@@ -0,0 +1,379 @@
+use crate::encode::{Encode, IsNull};
+use crate::error::Error;
+use crate::mssql::io::MssqlBufMutExt;
+use crate::mssql::protocol::col_meta_data::Flags;
+use crate::mssql::protocol::type_info::{Collation, DataType, TypeInfo};
+use crate::mssql::{Mssql, MssqlTypeInfo};
+use crate::types::Type;
+
+// https://docs.microsoft.com/en-us/openspecs/windows_protocols/ms-tds/7a8e6c29-ba28-4e39-95a3-a5e8ee5b8b4b
+const TVP_NULL_TOKEN: u16 = 0xFFFF;
+const TVP_END_TOKEN: u8 = 0x00;
+const TVP_ROW_TOKEN: u8 = 0x01;
+
+/// A table-valued parameter.
+///
+/// The rows are sent as an instance of the user-defined table type named in
+/// [`new`][Self::new]; the columns of each row are matched to the columns of that type
+/// by position.
+///
+/// ```rust,no_run
+/// # use sqlx_core::mssql::types::MssqlTableValue;
+/// # fn example() -> Result<(), sqlx_core::error::Error> {
+/// // CREATE TYPE dbo.MyType AS TABLE (id INT NULL, name NVARCHAR(100) NOT NULL);
+/// let rows = vec![(Some(1_i32), "first"), (None, "second")];
+///
+/// let tvp = MssqlTableValue::new("dbo.MyType").rows(rows)?;
+///
+/// // sqlx::query("EXEC dbo.my_proc @p1").bind(tvp)
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MssqlTableValue {
+    type_name: String,
+    columns: Vec<MssqlTypeInfo>,
+    rows: Vec<u8>,
+}
+
+impl MssqlTableValue {
+    /// Creates an empty table value of the given user-defined table type,
+    /// optionally qualified with its schema (e.g. `dbo.MyType`).
+    pub fn new(type_name: impl Into<String>) -> Self {
+        Self {
+            type_name: type_name.into(),
+            columns: Vec::new(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Appends rows to the table value.
+    ///
+    /// Each row is a tuple with one element per column of the table type, in the order
+    /// the columns were declared. Use `Option` for columns which may be `NULL`.
+    ///
+    /// Returns an error if the rows do not have the same columns as those appended before.
+    pub fn rows<'q, R, I>(mut self, rows: I) -> Result<Self, Error>
+    where
+        R: MssqlTableRow<'q>,
+        I: IntoIterator<Item = R>,
+    {
+        let columns = R::column_types();
+
+        if self.columns.is_empty() {
+            self.columns = columns;
+        } else if columns.len() != self.columns.len() {
+            return Err(Error::Encode(
+                format!(
+                    "table value `{}` has rows of {} columns, not {}",
+                    self.type_name,
+                    self.columns.len(),
+                    columns.len()
+                )
+                .into(),
+            ));
+        } else if columns != self.columns {
+            return Err(Error::Encode(
+                format!(
+                    "table value `{}` has rows with columns of other types",
+                    self.type_name
+                )
+                .into(),
+            ));
+        }
+
+        for row in rows {
+            self.rows.push(TVP_ROW_TOKEN);
+            row.encode_columns(&self.columns, &mut self.rows);
+        }
+
+        Ok(self)
+    }
+
+    fn put_type_name(&self, buf: &mut Vec<u8>) {
+        let (schema, name) = match self.type_name.rsplit_once('.') {
+            Some((schema, name)) => (schema, name),
+            None => ("", &*self.type_name),
+        };
+
+        // the database name must always be empty
+        buf.put_b_varchar(""); // [DbName]
+        buf.put_b_varchar(unquote(schema)); // [OwningSchema]
+        buf.put_b_varchar(unquote(name)); // [TypeName]
+    }
+
+    fn put_column_metadata(&self, buf: &mut Vec<u8>) {
+        if self.columns.is_empty() {
+            buf.extend(&TVP_NULL_TOKEN.to_le_bytes());
+            return;
+        }
+
+        buf.extend(&(self.columns.len() as u16).to_le_bytes()); // [Count]
+
+        for column in &self.columns {
+            buf.extend(&0_u32.to_le_bytes()); // [UserType]
+            buf.extend(&Flags::NULLABLE.bits().to_le_bytes()); // [Flags]
+            column.0.put(buf); // [TYPE_INFO]
+            buf.put_b_varchar(""); // [ColName], must be empty
+        }
+    }
+}
+
+fn unquote(ident: &str) -> &str {
+    ident
+        .strip_prefix('[')
+        .and_then(|ident| ident.strip_suffix(']'))
+        .unwrap_or(ident)
+}
+
+impl Type<Mssql> for MssqlTableValue {
+    fn type_info() -> MssqlTypeInfo {
+        MssqlTypeInfo(TypeInfo::new(DataType::Table, 0))
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        matches!(ty.0.ty, DataType::Table)
+    }
+}
+
+impl Encode<'_, Mssql> for MssqlTableValue {
+    fn produces(&self) -> Option<MssqlTypeInfo> {
+        Some(MssqlTypeInfo(TypeInfo {
+            type_name: Some(self.type_name.clone()),
+            ..TypeInfo::new(DataType::Table, 0)
+        }))
+    }
+
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        // the TVPTYPE byte has already been written as part of the TYPE_INFO
+        self.put_type_name(buf);
+        self.put_column_metadata(buf);
+        buf.push(TVP_END_TOKEN); // no optional metadata tokens
+
+        buf.extend_from_slice(&self.rows);
+        buf.push(TVP_END_TOKEN);
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        self.type_name.len() * 2 + self.columns.len() * 16 + self.rows.len() + 8
+    }
+}
+
+/// A row of a [`MssqlTableValue`].
+///
+/// This is implemented for tuples of up to 16 elements, where every element can be
+/// encoded as a parameter.
+pub trait MssqlTableRow<'q> {
+    #[doc(hidden)]
+    fn column_types() -> Vec<MssqlTypeInfo>;
+
+    #[doc(hidden)]
+    fn encode_columns(&self, columns: &[MssqlTypeInfo], buf: &mut Vec<u8>);
+}
+
+fn column_type<T: Type<Mssql>>() -> MssqlTypeInfo {
+    let mut ty = T::type_info();
+
+    // strings are declared without a collation, which is not accepted in column metadata
+    if matches!(
+        ty.0.ty,
+        DataType::NVarChar | DataType::NChar | DataType::BigVarChar | DataType::BigChar
+    ) && ty.0.collation.is_none()
+    {
+        ty.0.collation = Some(Collation::DEFAULT);
+    }
+
+    ty
+}
+
+macro_rules! impl_table_row_for_tuple {
+    ($( $idx:tt : $T:ident ),*) => {
+        impl<'q, $($T,)*> MssqlTableRow<'q> for ($($T,)*)
+        where
+            $($T: Encode<'q, Mssql> + Type<Mssql>,)*
+        {
+            fn column_types() -> Vec<MssqlTypeInfo> {
+                vec![$(column_type::<$T>(),)*]
+            }
+
+            fn encode_columns(&self, columns: &[MssqlTypeInfo], buf: &mut Vec<u8>) {
+                $(columns[$idx].0.put_value(buf, &self.$idx);)*
+            }
+        }
+    };
+}
+
+impl_table_row_for_tuple!(0: T1);
+
+impl_table_row_for_tuple!(0: T1, 1: T2);
+
+impl_table_row_for_tuple!(0: T1, 1: T2, 2: T3);
+
+impl_table_row_for_tuple!(0: T1, 1: T2, 2: T3, 3: T4);
+
+impl_table_row_for_tuple!(0: T1, 1: T2, 2: T3, 3: T4, 4: T5);
+
+impl_table_row_for_tuple!(0: T1, 1: T2, 2: T3, 3: T4, 4: T5, 5: T6);
+
+impl_table_row_for_tuple!(0: T1, 1: T2, 2: T3, 3: T4, 4: T5, 5: T6, 6: T7);
+
+impl_table_row_for_tuple!(0: T1, 1: T2, 2: T3, 3: T4, 4: T5, 5: T6, 6: T7, 7: T8);
+
+impl_table_row_for_tuple!(0: T1, 1: T2, 2: T3, 3: T4, 4: T5, 5: T6, 6: T7, 7: T8, 8: T9);
+
+impl_table_row_for_tuple!(
+    0: T1,
+    1: T2,
+    2: T3,
+    3: T4,
+    4: T5,
+    5: T6,
+    6: T7,
+    7: T8,
+    8: T9,
+    9: T10
+);
+
+impl_table_row_for_tuple!(
+    0: T1,
+    1: T2,
+    2: T3,
+    3: T4,
+    4: T5,
+    5: T6,
+    6: T7,
+    7: T8,
+    8: T9,
+    9: T10,
+    10: T11
+);
+
+impl_table_row_for_tuple!(
+    0: T1,
+    1: T2,
+    2: T3,
+    3: T4,
+    4: T5,
+    5: T6,
+    6: T7,
+    7: T8,
+    8: T9,
+    9: T10,
+    10: T11,
+    11: T12
+);
+
+impl_table_row_for_tuple!(
+    0: T1,
+    1: T2,
+    2: T3,
+    3: T4,
+    4: T5,
+    5: T6,
+    6: T7,
+    7: T8,
+    8: T9,
+    9: T10,
+    10: T11,
+    11: T12,
+    12: T13
+);
+
+impl_table_row_for_tuple!(
+    0: T1,
+    1: T2,
+    2: T3,
+    3: T4,
+    4: T5,
+    5: T6,
+    6: T7,
+    7: T8,
+    8: T9,
+    9: T10,
+    10: T11,
+    11: T12,
+    12: T13,
+    13: T14
+);
+
+impl_table_row_for_tuple!(
+    0: T1,
+    1: T2,
+    2: T3,
+    3: T4,
+    4: T5,
+    5: T6,
+    6: T7,
+    7: T8,
+    8: T9,
+    9: T10,
+    10: T11,
+    11: T12,
+    12: T13,
+    13: T14,
+    14: T15
+);
+
+impl_table_row_for_tuple!(
+    0: T1,
+    1: T2,
+    2: T3,
+    3: T4,
+    4: T5,
+    5: T6,
+    6: T7,
+    7: T8,
+    8: T9,
+    9: T10,
+    10: T11,
+    11: T12,
+    12: T13,
+    13: T14,
+    14: T15,
+    15: T16
+);
+
+#[test]
+fn test_encode_table_value() {
+    let tvp = MssqlTableValue::new("dbo.[IntList]")
+        .rows(vec![(Some(1_i32),), (None,)])
+        .unwrap();
+
+    let mut buf = Vec::new();
+    let _ = tvp.encode_by_ref(&mut buf);
+
+    #[rustfmt::skip]
+    let expected: &[u8] = &[
+        // DbName, OwningSchema, TypeName
+        0,
+        3, b'd', 0, b'b', 0, b'o', 0,
+        7, b'I', 0, b'n', 0, b't', 0, b'L', 0, b'i', 0, b's', 0, b't', 0,
+        // column count
+        1, 0,
+        // UserType, Flags, TYPE_INFO, ColName
+        0, 0, 0, 0, 1, 0, 0x26, 4, 0,
+        // TVP_END_TOKEN
+        0,
+        // TVP_ROW_TOKEN, 1
+        1, 4, 1, 0, 0, 0,
+        // TVP_ROW_TOKEN, NULL
+        1, 0,
+        // TVP_END_TOKEN
+        0,
+    ];
+
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn test_table_value_rejects_rows_of_other_columns() {
+    let tvp = MssqlTableValue::new("dbo.IntList")
+        .rows(vec![(1_i32,)])
+        .unwrap();
+
+    assert!(tvp.clone().rows(vec![(1_i32, 2_i32)]).is_err());
+    assert!(tvp.clone().rows(vec![("1",)]).is_err());
+    assert!(tvp.rows(vec![(None::<i32>,)]).is_ok());
+}
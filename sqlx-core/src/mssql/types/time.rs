@@ -0,0 +1,159 @@
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::mssql::protocol::type_info::{DataType, TypeInfo};
+use crate::mssql::{Mssql, MssqlTypeInfo, MssqlValueRef};
+use crate::types::Type;
+
+// https://docs.microsoft.com/en-us/openspecs/windows_protocols/ms-tds/786f5b8a-f87d-4980-9070-b9b7274c681d
+
+const NANOS_PER_DAY: i128 = 86_400_000_000_000;
+
+// the date part counts the days since 0001-01-01
+const JULIAN_DAY_OF_0001_01_01: i32 = 1_721_426;
+const DAYS_FROM_0001_01_01_TO_UNIX_EPOCH: i128 = 719_162;
+const MAX_DAYS_FROM_0001_01_01: u32 = 3_652_058;
+
+/// Provides conversion of time::OffsetDateTime to MS SQL DateTimeOffset(7)
+///
+/// The offset is preserved, truncated to whole minutes as MS SQL does not store seconds
+/// in offsets.
+///
+/// Encoding panics if the date in UTC is before `0001-01-01` or after `9999-12-31`, which is
+/// outside of the range of a `DATETIMEOFFSET`.
+impl Type<Mssql> for OffsetDateTime {
+    fn type_info() -> MssqlTypeInfo {
+        MssqlTypeInfo(TypeInfo {
+            scale: 7,
+            ty: DataType::DateTimeOffsetN,
+            size: 10,
+            collation: None,
+            type_name: None,
+            precision: 34,
+        })
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        matches!(ty.0.ty, DataType::DateTimeOffsetN)
+    }
+}
+
+impl Encode<'_, Mssql> for OffsetDateTime {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        // the date and time are stored in UTC, always at full scale, in units of 100ns
+        let nanos = self.unix_timestamp_nanos();
+        let days = nanos.div_euclid(NANOS_PER_DAY) + DAYS_FROM_0001_01_01_TO_UNIX_EPOCH;
+        let ticks = nanos.rem_euclid(NANOS_PER_DAY) / 100;
+
+        // MS SQL supports dates from 0001-01-01 to 9999-12-31
+        let days = u32::try_from(days)
+            .ok()
+            .filter(|&days| days <= MAX_DAYS_FROM_0001_01_01)
+            .unwrap_or_else(|| panic!("OffsetDateTime out of range for Mssql: {}", self));
+
+        buf.extend_from_slice(&(ticks as u64).to_le_bytes()[..5]);
+        buf.extend_from_slice(&days.to_le_bytes()[..3]);
+        buf.extend_from_slice(&self.offset().whole_minutes().to_le_bytes());
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Mssql> for OffsetDateTime {
+    fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        let bytes = value.as_bytes()?;
+        let scale = value.type_info.0.scale;
+
+        if bytes.len() < 8 || scale > 7 {
+            return Err(
+                format!("invalid DATETIMEOFFSET({}) of {} bytes", scale, bytes.len()).into(),
+            );
+        }
+
+        let (time, rest) = bytes.split_at(bytes.len() - 5);
+        let (date, offset) = rest.split_at(3);
+
+        let ticks = time
+            .iter()
+            .rev()
+            .fold(0_u64, |acc, &byte| (acc << 8) | u64::from(byte));
+        let days = u32::from_le_bytes([date[0], date[1], date[2], 0]);
+        let offset_minutes = i16::from_le_bytes([offset[0], offset[1]]);
+
+        let offset = UtcOffset::from_whole_seconds(i32::from(offset_minutes) * 60)?;
+
+        // shift the UTC date and time into the offset before splitting them up again, as the
+        // local date may be out of the range of an `OffsetDateTime` in UTC or vice versa
+        let local = i128::from(days) * NANOS_PER_DAY
+            + i128::from(ticks) * 10_i128.pow(9 - u32::from(scale))
+            + i128::from(offset_minutes) * 60_000_000_000;
+
+        let date = Date::from_julian_day(
+            JULIAN_DAY_OF_0001_01_01 + i32::try_from(local.div_euclid(NANOS_PER_DAY))?,
+        )?;
+
+        let nanos = local.rem_euclid(NANOS_PER_DAY) as u64;
+        let seconds = nanos / 1_000_000_000;
+        let time = Time::from_hms_nano(
+            (seconds / 3600) as u8,
+            (seconds / 60 % 60) as u8,
+            (seconds % 60) as u8,
+            (nanos % 1_000_000_000) as u32,
+        )?;
+
+        Ok(PrimitiveDateTime::new(date, time).assume_offset(offset))
+    }
+}
+
+#[test]
+fn it_encodes_and_decodes_datetimeoffset() {
+    use time::macros::datetime;
+
+    let encode = |value: OffsetDateTime| {
+        let mut buf = Vec::new();
+        let _ = Encode::<Mssql>::encode(value, &mut buf);
+        buf
+    };
+
+    let decode = |bytes: &[u8]| {
+        let type_info = <OffsetDateTime as Type<Mssql>>::type_info();
+        <OffsetDateTime as Decode<Mssql>>::decode(MssqlValueRef {
+            type_info,
+            data: Some(&bytes::Bytes::copy_from_slice(bytes)),
+        })
+        .unwrap()
+    };
+
+    for value in [
+        datetime!(2016-10-23 12:45:37.123_456_7 +05:30),
+        // the day before in UTC
+        datetime!(2016-10-23 00:15:00 +05:30),
+        datetime!(0001-01-01 00:00:00 UTC),
+        datetime!(9999-12-31 23:59:59.999_999_9 UTC),
+        // out of the range of MS SQL locally, but not in UTC
+        datetime!(0000-12-31 20:00:00 -05:00),
+        datetime!(9999-12-31 23:59:59.999_999_9 +14:00),
+    ] {
+        let bytes = encode(value);
+        assert_eq!(bytes.len(), 10);
+
+        let decoded = decode(&bytes);
+        assert_eq!(decoded, value);
+        assert_eq!(decoded.offset(), value.offset());
+    }
+
+    // the dates just out of the range of MS SQL in UTC panic instead of wrapping around
+    for value in [
+        datetime!(0000-12-31 23:59:59.999_999_9 UTC),
+        datetime!(0001-01-01 00:00:00 +00:01),
+        datetime!(9999-12-31 23:59:59.999_999_9 -00:01),
+    ] {
+        assert!(std::panic::catch_unwind(|| encode(value)).is_err());
+    }
+
+    // 2016-10-23 07:15:37.1234567 UTC, shown at +05:30
+    let bytes = encode(datetime!(2016-10-23 12:45:37.123_456_7 +05:30));
+    assert_eq!(&bytes[8..], &330_i16.to_le_bytes());
+}
@@ -0,0 +1,72 @@
+use uuid::Uuid;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::mssql::protocol::type_info::{DataType, TypeInfo};
+use crate::mssql::{Mssql, MssqlTypeInfo, MssqlValueRef};
+use crate::types::Type;
+
+// MSSQL stores a `UNIQUEIDENTIFIER` in the layout of a Windows GUID, where the first three
+// fields are little-endian and the last two are big-endian, unlike the fully big-endian layout
+// of RFC 4122 used by `Uuid::as_bytes`
+
+impl Type<Mssql> for Uuid {
+    fn type_info() -> MssqlTypeInfo {
+        MssqlTypeInfo(TypeInfo::new(DataType::Guid, 16))
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        matches!(ty.0.ty, DataType::Guid)
+    }
+}
+
+impl Encode<'_, Mssql> for Uuid {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        buf.extend_from_slice(&self.to_bytes_le());
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        16
+    }
+}
+
+impl Decode<'_, Mssql> for Uuid {
+    fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        let bytes = value.as_bytes()?;
+        let bytes: [u8; 16] = bytes.try_into().map_err(|_| {
+            format!(
+                "expected 16 bytes for UNIQUEIDENTIFIER, got {}",
+                bytes.len()
+            )
+        })?;
+
+        Ok(Uuid::from_bytes_le(bytes))
+    }
+}
+
+#[test]
+fn it_swaps_the_first_three_fields() {
+    let uuid = Uuid::parse_str("6f9619ff-8b86-d011-b42d-00c04fc964ff").unwrap();
+
+    let mut buf = Vec::new();
+    let _ = Encode::<Mssql>::encode(uuid, &mut buf);
+
+    assert_eq!(
+        buf,
+        [
+            0xff, 0x19, 0x96, 0x6f, 0x86, 0x8b, 0x11, 0xd0, 0xb4, 0x2d, 0x00, 0xc0, 0x4f, 0xc9,
+            0x64, 0xff
+        ]
+    );
+
+    let decoded = <Uuid as Decode<Mssql>>::decode(MssqlValueRef {
+        type_info: <Uuid as Type<Mssql>>::type_info(),
+        data: Some(&bytes::Bytes::from(buf)),
+    })
+    .unwrap();
+
+    assert_eq!(decoded, uuid);
+}
@@ -12,7 +12,10 @@ pub struct MssqlValueRef<'r> {
 }
 
 impl<'r> MssqlValueRef<'r> {
-    pub(crate) fn as_bytes(&self) -> Result<&'r [u8], BoxDynError> {
+    /// The undecoded bytes of the value, which are always in the binary format of its type.
+    ///
+    /// Returns an error if the value is `NULL`.
+    pub fn as_bytes(&self) -> Result<&'r [u8], BoxDynError> {
         match &self.data {
             Some(v) => Ok(v),
             None => Err(UnexpectedNullError.into()),
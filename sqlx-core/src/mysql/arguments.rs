@@ -1,5 +1,8 @@
 use crate::arguments::Arguments;
 use crate::encode::{Encode, IsNull};
+use crate::error::Error;
+use crate::logger::LoggedParam;
+use crate::mysql::protocol::text::{ColumnFlags, ColumnType};
 use crate::mysql::{MySql, MySqlTypeInfo};
 use crate::types::Type;
 
@@ -31,6 +34,88 @@ impl MySqlArguments {
     pub fn len(&self) -> usize {
         self.types.len()
     }
+
+    // each `?` is a parameter of its own, even when the same value is meant
+    pub(crate) fn check_parameter_count(&self, parameters: usize) -> Result<(), Error> {
+        crate::arguments::check_parameter_count(parameters, self.types.len())
+    }
+
+    pub(crate) fn logged_values(&self) -> impl Iterator<Item = LoggedParam<'_>> {
+        let mut buf = &self.values[..];
+
+        self.types.iter().enumerate().map_while(move |(index, ty)| {
+            if self.null_bitmap[index / 8] & (1 << (index % 8)) != 0 {
+                return Some(LoggedParam::Null);
+            }
+
+            // integers and floats are sent with a fixed size and everything else with its
+            // length, including dates and times, whose length always fits in a single byte
+            // https://dev.mysql.com/doc/internals/en/binary-protocol-value.html
+            let unsigned = ty.flags.contains(ColumnFlags::UNSIGNED);
+
+            let size = match ty.r#type {
+                ColumnType::Tiny => 1,
+                ColumnType::Short | ColumnType::Year => 2,
+                ColumnType::Long | ColumnType::Int24 | ColumnType::Float => 4,
+                ColumnType::LongLong | ColumnType::Double => 8,
+
+                _ => {
+                    let value = take_bytes_lenenc(&mut buf)?;
+
+                    return Some(match std::str::from_utf8(value) {
+                        // a character set of 63 is `binary`
+                        Ok(text) if ty.char_set != 63 => LoggedParam::Text(text),
+                        _ => LoggedParam::Bytes(value),
+                    });
+                }
+            };
+
+            let value = take(&mut buf, size)?;
+
+            let mut bytes = [0; 8];
+            bytes[..size].copy_from_slice(value);
+
+            let number = match ty.r#type {
+                ColumnType::Float => f32::from_le_bytes(bytes[..4].try_into().ok()?).to_string(),
+                ColumnType::Double => f64::from_le_bytes(bytes).to_string(),
+                _ if unsigned => u64::from_le_bytes(bytes).to_string(),
+
+                // sign-extend the value
+                _ => {
+                    let shift = 64 - 8 * size;
+                    ((i64::from_le_bytes(bytes) << shift) >> shift).to_string()
+                }
+            };
+
+            Some(LoggedParam::Other(number))
+        })
+    }
+}
+
+fn take<'a>(buf: &mut &'a [u8], size: usize) -> Option<&'a [u8]> {
+    if buf.len() < size {
+        return None;
+    }
+
+    let (value, rest) = buf.split_at(size);
+    *buf = rest;
+
+    Some(value)
+}
+
+fn take_bytes_lenenc<'a>(buf: &mut &'a [u8]) -> Option<&'a [u8]> {
+    let size = match take(buf, 1)?[0] {
+        0xfc => u64::from(u16::from_le_bytes(take(buf, 2)?.try_into().ok()?)),
+        0xfd => {
+            let bytes = take(buf, 3)?;
+            u64::from_le_bytes([bytes[0], bytes[1], bytes[2], 0, 0, 0, 0, 0])
+        }
+        0xfe => u64::from_le_bytes(take(buf, 8)?.try_into().ok()?),
+
+        size => u64::from(size),
+    };
+
+    take(buf, usize::try_from(size).ok()?)
 }
 
 impl<'q> Arguments<'q> for MySqlArguments {
@@ -20,6 +20,14 @@ impl AuthPlugin {
         nonce: &Chain<Bytes, Bytes>,
     ) -> Result<Vec<u8>, Error> {
         match self {
+            // an empty password is sent as an empty response rather than scrambled, which the
+            // server would compare to the hash of an empty password
+            AuthPlugin::CachingSha2Password | AuthPlugin::MySqlNativePassword
+                if password.is_empty() =>
+            {
+                Ok(Vec::new())
+            }
+
             // https://mariadb.com/kb/en/caching_sha2_password-authentication-plugin/
             AuthPlugin::CachingSha2Password => Ok(scramble_sha256(password, nonce).to_vec()),
 
@@ -38,12 +46,17 @@ impl AuthPlugin {
         nonce: &Chain<Bytes, Bytes>,
     ) -> Result<bool, Error> {
         match self {
-            AuthPlugin::CachingSha2Password if packet[0] == 0x01 => {
+            // https://dev.mysql.com/doc/dev/mysql-server/8.0.12/page_caching_sha2_authentication_exchanges.html
+            AuthPlugin::CachingSha2Password if packet[0] == 0x01 && packet.len() == 2 => {
                 match packet[1] {
                     // AUTH_OK
-                    0x03 => Ok(true),
+                    // the password was in the cache of the server, which still follows with
+                    // an OK packet that has to be read before the connection is ready
+                    0x03 => Ok(false),
 
                     // AUTH_CONTINUE
+                    // the password was not in the cache, and has to be sent in full; either in
+                    // clear text over TLS, or encrypted with the public key of the server
                     0x04 => {
                         let payload = encrypt_rsa(stream, 0x02, password, nonce).await?;
 
@@ -142,7 +155,16 @@ async fn encrypt_rsa<'s>(
 
     // server sends a public key response
     let packet = stream.recv_packet().await?;
-    let rsa_pub_key = &packet[1..];
+
+    let rsa_pub_key = match packet.split_first() {
+        Some((0x01, key)) => key,
+
+        _ => {
+            return Err(err_protocol!(
+                "expected the public key of the server during authentication"
+            ))
+        }
+    };
 
     // xor the password with the given nonce
     let mut pass = to_asciz(password);
@@ -22,34 +22,8 @@ impl MySqlConnection {
         let mut plugin = handshake.auth_plugin;
         let mut nonce = handshake.auth_plugin_data;
 
-        // FIXME: server version parse is a bit ugly
-        // expecting MAJOR.MINOR.PATCH
-
-        let mut server_version = handshake.server_version.split('.');
-
-        let server_version_major: u16 = server_version
-            .next()
-            .unwrap_or_default()
-            .parse()
-            .unwrap_or(0);
-
-        let server_version_minor: u16 = server_version
-            .next()
-            .unwrap_or_default()
-            .parse()
-            .unwrap_or(0);
-
-        let server_version_patch: u16 = server_version
-            .next()
-            .unwrap_or_default()
-            .parse()
-            .unwrap_or(0);
-
-        stream.server_version = (
-            server_version_major,
-            server_version_minor,
-            server_version_patch,
-        );
+        stream.server_version = parse_server_version(&handshake.server_version);
+        stream.is_mariadb = handshake.server_version.contains("MariaDB");
 
         stream.capabilities &= handshake.server_capabilities;
         stream.capabilities |= Capabilities::PROTOCOL_41;
@@ -135,8 +109,46 @@ impl MySqlConnection {
         Ok(Self {
             stream,
             transaction_depth: 0,
+            deferred_savepoint_release: None,
             cache_statement: StatementCache::new(options.statement_cache_capacity),
+            statements_to_reset: Vec::new(),
             log_settings: options.log_settings.clone(),
         })
     }
 }
+
+// expecting MAJOR.MINOR.PATCH, possibly followed by a suffix such as `-0ubuntu0.22.04.1`;
+// a missing or unparseable component is reported as `0`
+fn parse_server_version(s: &str) -> (u16, u16, u16) {
+    // MariaDB reports versions like `5.5.5-10.6.4-MariaDB` for compatibility with old clients
+    let s = match s.strip_prefix("5.5.5-") {
+        Some(rest) if s.contains("MariaDB") => rest,
+        _ => s,
+    };
+
+    let mut parts = s.splitn(3, '.').map(|part| {
+        let end = part
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(part.len());
+
+        part[..end].parse().unwrap_or(0)
+    });
+
+    let mut next = || parts.next().unwrap_or(0);
+
+    (next(), next(), next())
+}
+
+#[test]
+fn test_parse_server_version() {
+    assert_eq!(parse_server_version("8.0.34"), (8, 0, 34));
+    assert_eq!(parse_server_version("8.0.34-0ubuntu0.22.04.1"), (8, 0, 34));
+    assert_eq!(parse_server_version("5.7.42-log"), (5, 7, 42));
+    assert_eq!(parse_server_version("5.5.5-10.6.4-MariaDB"), (10, 6, 4));
+    assert_eq!(
+        parse_server_version("10.11.2-MariaDB-1:10.11.2+maria~ubu2204"),
+        (10, 11, 2)
+    );
+    assert_eq!(parse_server_version("8.0"), (8, 0, 0));
+    assert_eq!(parse_server_version("unknown"), (0, 0, 0));
+}
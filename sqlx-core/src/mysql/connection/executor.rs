@@ -8,15 +8,17 @@ use crate::mysql::connection::stream::Waiting;
 use crate::mysql::io::MySqlBufExt;
 use crate::mysql::protocol::response::Status;
 use crate::mysql::protocol::statement::{
-    BinaryRow, Execute as StatementExecute, Prepare, PrepareOk, StmtClose,
+    BinaryRow, Execute as StatementExecute, Prepare, PrepareOk, StmtClose, StmtReset,
 };
 use crate::mysql::protocol::text::{ColumnDefinition, ColumnFlags, Query, TextRow};
+use crate::mysql::protocol::Packet;
 use crate::mysql::statement::{MySqlStatement, MySqlStatementMetadata};
 use crate::mysql::{
     MySql, MySqlArguments, MySqlColumn, MySqlConnection, MySqlQueryResult, MySqlRow, MySqlTypeInfo,
     MySqlValueFormat,
 };
 use crate::HashMap;
+use bytes::Bytes;
 use either::Either;
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
@@ -75,6 +77,8 @@ impl MySqlConnection {
         if persistent && self.cache_statement.is_enabled() {
             // in case of the cache being full, close the least recently used statement
             if let Some((id, _)) = self.cache_statement.insert(sql, (id, metadata.clone())) {
+                self.statements_to_reset
+                    .retain(|&statement| statement != id);
                 self.stream.send_packet(StmtClose { statement: id }).await?;
             }
         }
@@ -82,6 +86,20 @@ impl MySqlConnection {
         Ok((id, metadata))
     }
 
+    // receive the next packet of the response to a query; if the server reports an error while
+    // executing a cached statement, the statement is reset before it is executed again
+    async fn recv_response_packet(&mut self, cached: Option<u32>) -> Result<Packet<Bytes>, Error> {
+        let result = self.stream.recv_packet().await;
+
+        if let (Err(Error::Database(_)), Some(id)) = (&result, cached) {
+            if !self.statements_to_reset.contains(&id) {
+                self.statements_to_reset.push(id);
+            }
+        }
+
+        result
+    }
+
     #[allow(clippy::needless_lifetimes)]
     async fn run<'e, 'c: 'e, 'q: 'e>(
         &'c mut self,
@@ -90,24 +108,44 @@ impl MySqlConnection {
         persistent: bool,
     ) -> Result<impl Stream<Item = Result<Either<MySqlQueryResult, MySqlRow>, Error>> + 'e, Error>
     {
-        let mut logger = QueryLogger::new(sql, self.log_settings.clone());
+        let mut logger = QueryLogger::new(sql, "mysql", self.log_settings.clone());
+
+        if let Some(arguments) = &arguments {
+            logger.record_params(arguments);
+            logger.record_param_values(arguments.logged_values());
+        }
 
         self.stream.wait_until_ready().await?;
         self.stream.waiting.push_back(Waiting::Result);
 
-        Ok(Box::pin(try_stream! {
+        let span = logger.span();
+
+        Ok(Box::pin(span.instrument(try_stream! {
             // make a slot for the shared column data
             // as long as a reference to a row is not held past one iteration, this enables us
             // to re-use this memory freely between result sets
             let mut columns = Arc::new(Vec::new());
 
-            let (mut column_names, format, mut needs_metadata) = if let Some(arguments) = arguments {
+            let (mut column_names, format, mut needs_metadata, cached) = if let Some(arguments) = arguments {
                 let (id, metadata) = self.get_or_prepare(
                     sql,
                     persistent,
                 )
                 .await?;
 
+                arguments.check_parameter_count(metadata.parameters)?;
+
+                // statements that are not cached are not executed again
+                let cached = self.cache_statement.contains_key(sql).then_some(id);
+
+                if let Some(i) = self.statements_to_reset.iter().position(|&statement| statement == id) {
+                    self.statements_to_reset.swap_remove(i);
+
+                    // https://dev.mysql.com/doc/internals/en/com-stmt-reset.html
+                    self.stream.send_packet(StmtReset { statement: id }).await?;
+                    self.stream.recv_ok().await?;
+                }
+
                 // https://dev.mysql.com/doc/internals/en/com-stmt-execute.html
                 self.stream
                     .send_packet(StatementExecute {
@@ -116,18 +154,18 @@ impl MySqlConnection {
                     })
                     .await?;
 
-                (metadata.column_names, MySqlValueFormat::Binary, false)
+                (metadata.column_names, MySqlValueFormat::Binary, false, cached)
             } else {
                 // https://dev.mysql.com/doc/internals/en/com-query.html
                 self.stream.send_packet(Query(sql)).await?;
 
-                (Arc::default(), MySqlValueFormat::Text, true)
+                (Arc::default(), MySqlValueFormat::Text, true, None)
             };
 
             loop {
                 // query response is a meta-packet which may be one of:
                 //  Ok, Err, ResultSet, or (unhandled) LocalInfileRequest
-                let mut packet = self.stream.recv_packet().await?;
+                let mut packet = self.recv_response_packet(cached).await?;
 
                 if packet[0] == 0x00 || packet[0] == 0xff {
                     // first packet in a query response is OK or ERR
@@ -169,7 +207,7 @@ impl MySqlConnection {
 
                 // finally, there will be none or many result-rows
                 loop {
-                    let packet = self.stream.recv_packet().await?;
+                    let packet = self.recv_response_packet(cached).await?;
 
                     if packet[0] == 0xfe && packet.len() < 9 {
                         let eof = packet.eof(self.stream.capabilities)?;
@@ -206,7 +244,7 @@ impl MySqlConnection {
                     r#yield!(v);
                 }
             }
-        }))
+        })))
     }
 }
 
@@ -5,7 +5,8 @@ use crate::mysql::protocol::statement::StmtClose;
 use crate::mysql::protocol::text::{Ping, Quit};
 use crate::mysql::statement::MySqlStatementMetadata;
 use crate::mysql::{MySql, MySqlConnectOptions};
-use crate::transaction::Transaction;
+use crate::net::TlsInfo;
+use crate::transaction::{Transaction, TransactionOptions};
 use futures_core::future::BoxFuture;
 use futures_util::FutureExt;
 use std::fmt::{self, Debug, Formatter};
@@ -30,12 +31,71 @@ pub struct MySqlConnection {
     // transaction status
     pub(crate) transaction_depth: usize,
 
+    // index of the outermost savepoint committed with `commit_with_savepoint_release` but not
+    // released yet
+    pub(crate) deferred_savepoint_release: Option<usize>,
+
     // cache by query string to the statement id and metadata
     cache_statement: StatementCache<(u32, MySqlStatementMetadata)>,
 
+    // ids of cached statements that failed when they were last executed,
+    // to be reset before they are executed again
+    statements_to_reset: Vec<u32>,
+
     log_settings: LogSettings,
 }
 
+impl MySqlConnection {
+    /// The version of the server as `(major, minor, patch)`, parsed from the version string it
+    /// sent when the connection was established.
+    ///
+    /// Any suffix such as in `8.0.34-0ubuntu0.22.04.1` is ignored, and a component that could
+    /// not be parsed is reported as `0`. For MariaDB, this is the version of MariaDB rather than
+    /// the `5.5.5` it reports for compatibility with MySQL; see [`is_mariadb`][Self::is_mariadb].
+    pub fn server_version(&self) -> (u16, u16, u16) {
+        self.stream.server_version
+    }
+
+    /// Whether the server is MariaDB rather than MySQL, whose versions are not comparable.
+    pub fn is_mariadb(&self) -> bool {
+        self.stream.is_mariadb
+    }
+
+    /// The parameters of the TLS session of this connection, or `None` if it is not encrypted.
+    ///
+    /// See [`MySqlConnectOptions::ssl_mode`][crate::mysql::MySqlConnectOptions::ssl_mode] to
+    /// require TLS.
+    pub fn tls_info(&self) -> Option<TlsInfo> {
+        self.stream.tls_info()
+    }
+
+    /// Close all the prepared statements cached by this connection.
+    ///
+    /// This is the same as [`Connection::clear_cached_statements`], and can be used to start
+    /// over from fresh statements, though a statement that failed is already reset before it is
+    /// executed again.
+    pub async fn clear_statement_cache(&mut self) -> Result<(), Error> {
+        self.clear_cached_statements().await
+    }
+
+    /// The settings used to log the statements executed on this connection.
+    pub fn log_settings(&self) -> &LogSettings {
+        &self.log_settings
+    }
+
+    /// Change how the statements executed on this connection are logged,
+    /// starting with the next statement.
+    ///
+    /// This overrides the settings from [`ConnectOptions::log_statements`] and
+    /// [`ConnectOptions::log_slow_statements`] for this connection only.
+    ///
+    /// [`ConnectOptions::log_statements`]: crate::connection::ConnectOptions::log_statements
+    /// [`ConnectOptions::log_slow_statements`]: crate::connection::ConnectOptions::log_slow_statements
+    pub fn set_log_settings(&mut self, settings: LogSettings) {
+        self.log_settings = settings;
+    }
+}
+
 impl Debug for MySqlConnection {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("MySqlConnection").finish()
@@ -73,6 +133,10 @@ impl Connection for MySqlConnection {
         })
     }
 
+    fn shrink_buffers(&mut self) {
+        self.stream.shrink_buffers();
+    }
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         self.stream.wait_until_ready().boxed()
@@ -84,6 +148,8 @@ impl Connection for MySqlConnection {
 
     fn clear_cached_statements(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(async move {
+            self.statements_to_reset.clear();
+
             while let Some((statement_id, _)) = self.cache_statement.remove_lru() {
                 self.stream
                     .send_packet(StmtClose {
@@ -107,4 +173,14 @@ impl Connection for MySqlConnection {
     {
         Transaction::begin(self)
     }
+
+    fn begin_with_options(
+        &mut self,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<Transaction<'_, Self::Database>, Error>>
+    where
+        Self: Sized,
+    {
+        Transaction::begin_with_options(self, options)
+    }
 }
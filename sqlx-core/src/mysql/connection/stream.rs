@@ -15,6 +15,7 @@ use crate::net::{MaybeTlsStream, Socket};
 pub struct MySqlStream {
     stream: BufStream<MaybeTlsStream<Socket>>,
     pub(crate) server_version: (u16, u16, u16),
+    pub(crate) is_mariadb: bool,
     pub(super) capabilities: Capabilities,
     pub(crate) sequence_id: u8,
     pub(crate) waiting: VecDeque<Waiting>,
@@ -43,7 +44,7 @@ impl MySqlStream {
 
         let socket = match options.socket {
             Some(ref path) => Socket::connect_uds(path).await?,
-            None => Socket::connect_tcp(&options.host, options.port).await?,
+            None => Socket::connect_tcp(&options.host, options.port, options.tcp).await?,
         };
 
         let mut capabilities = Capabilities::PROTOCOL_41
@@ -67,6 +68,7 @@ impl MySqlStream {
             waiting: VecDeque::new(),
             capabilities,
             server_version: (0, 0, 0),
+            is_mariadb: false,
             sequence_id: 0,
             collation,
             charset,
@@ -1,7 +1,7 @@
 use std::error::Error;
 use std::fmt::{self, Debug, Display, Formatter};
 
-use crate::error::DatabaseError;
+use crate::error::{DatabaseError, ErrorKind};
 use crate::mysql::protocol::response::ErrPacket;
 use smallvec::alloc::borrow::Cow;
 
@@ -62,6 +62,24 @@ impl DatabaseError for MySqlDatabaseError {
         self.code().map(Cow::Borrowed)
     }
 
+    fn kind(&self) -> ErrorKind {
+        // https://dev.mysql.com/doc/mysql-errors/8.0/en/server-error-reference.html
+        // https://mariadb.com/kb/en/mariadb-error-codes/
+        match self.number() {
+            // ER_DUP_ENTRY, ER_DUP_ENTRY_WITH_KEY_NAME
+            1062 | 1586 => ErrorKind::UniqueViolation,
+            // ER_ROW_IS_REFERENCED(_2), ER_NO_REFERENCED_ROW(_2)
+            1216 | 1217 | 1451 | 1452 => ErrorKind::ForeignKeyViolation,
+            // ER_BAD_NULL_ERROR
+            1048 => ErrorKind::NotNullViolation,
+            // ER_CHECK_CONSTRAINT_VIOLATED, or ER_CONSTRAINT_FAILED on MariaDB
+            3819 | 4025 => ErrorKind::CheckViolation,
+            // ER_LOCK_DEADLOCK
+            1213 => ErrorKind::Deadlock,
+            _ => ErrorKind::Other,
+        }
+    }
+
     #[doc(hidden)]
     fn as_error(&self) -> &(dyn Error + Send + Sync + 'static) {
         self
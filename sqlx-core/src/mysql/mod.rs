@@ -38,6 +38,9 @@ pub use transaction::MySqlTransactionManager;
 pub use type_info::MySqlTypeInfo;
 pub use value::{MySqlValue, MySqlValueFormat, MySqlValueRef};
 
+#[doc(no_inline)]
+pub use crate::net::TlsInfo;
+
 /// An alias for [`Pool`][crate::pool::Pool], specialized for MySQL.
 pub type MySqlPool = crate::pool::Pool<MySql>;
 
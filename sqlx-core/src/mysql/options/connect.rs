@@ -50,6 +50,21 @@ impl ConnectOptions for MySqlConnectOptions {
                 );
             }
             options.push_str(r#"time_zone='+00:00',"#);
+
+            // Both variables make the server interrupt long running statements itself,
+            // MySQL takes milliseconds (and only applies it to `SELECT`) whereas MariaDB
+            // takes (fractional) seconds
+            if let Some(timeout) = self.statement_timeout {
+                if conn.stream.is_mariadb {
+                    options.push_str(&format!(
+                        r#"max_statement_time={:.3},"#,
+                        timeout.as_secs_f64()
+                    ));
+                } else {
+                    options.push_str(&format!(r#"max_execution_time={},"#, timeout.as_millis()));
+                }
+            }
+
             options.push_str(&format!(
                 r#"NAMES {} COLLATE {};"#,
                 conn.stream.charset.as_str(),
@@ -71,4 +86,23 @@ impl ConnectOptions for MySqlConnectOptions {
         self.log_settings.log_slow_statements(level, duration);
         self
     }
+
+    fn log_parameters(&mut self, enabled: bool) -> &mut Self {
+        self.log_settings.log_parameters(enabled);
+        self
+    }
+
+    fn redact_parameters(
+        &mut self,
+        redact: impl Fn(usize) -> bool + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.log_settings.redact_parameters(redact);
+        self
+    }
+
+    #[cfg(feature = "tracing")]
+    fn trace_params(&mut self, enabled: bool) -> &mut Self {
+        self.log_settings.trace_params(enabled);
+        self
+    }
 }
@@ -1,10 +1,12 @@
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 mod connect;
 mod parse;
 mod ssl_mode;
 
-use crate::{connection::LogSettings, net::CertificateInput};
+use crate::connection::LogSettings;
+use crate::net::{CertificateInput, TcpOptions};
 pub use ssl_mode::MySqlSslMode;
 
 /// Options and flags which can be used to configure a MySQL connection.
@@ -26,6 +28,11 @@ pub use ssl_mode::MySqlSslMode;
 /// | `ssl-ca` | `None` | Sets the name of a file containing a list of trusted SSL Certificate Authorities. |
 /// | `statement-cache-capacity` | `100` | The maximum number of prepared statements stored in the cache. Set to `0` to disable. |
 /// | `socket` | `None` | Path to the unix domain socket, which will be used instead of TCP if set. |
+/// | `charset` | `utf8mb4` | The character set to use for the connection. |
+/// | `collation` | `None` | The collation to use for the connection. |
+///
+/// `sslmode` and `sslca` are accepted as aliases of `ssl-mode` and `ssl-ca`.
+/// Any other parameter is rejected with [`Error::Configuration`][crate::error::Error::Configuration].
 ///
 /// # Example
 ///
@@ -56,6 +63,7 @@ pub struct MySqlConnectOptions {
     pub(crate) host: String,
     pub(crate) port: u16,
     pub(crate) socket: Option<PathBuf>,
+    pub(crate) tcp: TcpOptions,
     pub(crate) username: String,
     pub(crate) password: Option<String>,
     pub(crate) database: Option<String>,
@@ -66,6 +74,7 @@ pub struct MySqlConnectOptions {
     pub(crate) collation: Option<String>,
     pub(crate) log_settings: LogSettings,
     pub(crate) pipes_as_concat: bool,
+    pub(crate) statement_timeout: Option<Duration>,
 }
 
 impl Default for MySqlConnectOptions {
@@ -81,6 +90,7 @@ impl MySqlConnectOptions {
             port: 3306,
             host: String::from("localhost"),
             socket: None,
+            tcp: TcpOptions::default(),
             username: String::from("root"),
             password: None,
             database: None,
@@ -91,6 +101,7 @@ impl MySqlConnectOptions {
             statement_cache_capacity: 100,
             log_settings: Default::default(),
             pipes_as_concat: true,
+            statement_timeout: None,
         }
     }
 
@@ -120,6 +131,28 @@ impl MySqlConnectOptions {
         self
     }
 
+    /// Enables TCP keepalive on the connection, sending the first keepalive probe after the
+    /// connection has been idle for the given duration.
+    ///
+    /// This keeps long-lived idle connections from being silently dropped by NATs and
+    /// firewalls. By default set to `None`, which leaves keepalive disabled.
+    ///
+    /// This has no effect when connecting through a Unix domain socket.
+    pub fn tcp_keepalive(mut self, time: Option<Duration>) -> Self {
+        self.tcp.keepalive = time;
+        self
+    }
+
+    /// Sets `TCP_NODELAY` on the connection, which disables Nagle's algorithm.
+    ///
+    /// By default set to `false`.
+    ///
+    /// This has no effect when connecting through a Unix domain socket.
+    pub fn tcp_nodelay(mut self, nodelay: bool) -> Self {
+        self.tcp.nodelay = nodelay;
+        self
+    }
+
     /// Sets the username to connect as.
     pub fn username(mut self, username: &str) -> Self {
         self.username = username.to_owned();
@@ -224,4 +257,30 @@ impl MySqlConnectOptions {
         self.pipes_as_concat = flag_val;
         self
     }
+
+    /// Sets the maximum amount of time a statement is allowed to run before it is
+    /// interrupted by the server.
+    ///
+    /// This sets the `max_execution_time` session variable on MySQL, which only applies to
+    /// read-only `SELECT` statements, and the `max_statement_time` session variable on MariaDB.
+    /// The server interrupts the statement itself, so the connection remains usable afterwards.
+    ///
+    /// A statement which exceeds the timeout fails with error `3024` (`ER_QUERY_TIMEOUT`) on
+    /// MySQL and `1969` (`ER_STATEMENT_TIMEOUT`) on MariaDB. Note that an interrupted `SLEEP()`
+    /// without a table simply returns early instead of failing.
+    ///
+    /// By default, no timeout is set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use sqlx_core::mysql::MySqlConnectOptions;
+    /// let options = MySqlConnectOptions::new()
+    ///     .statement_timeout(Duration::from_secs(30));
+    /// ```
+    pub fn statement_timeout(mut self, timeout: Duration) -> Self {
+        self.statement_timeout = Some(timeout);
+        self
+    }
 }
@@ -43,11 +43,11 @@ impl FromStr for MySqlConnectOptions {
 
         for (key, value) in url.query_pairs().into_iter() {
             match &*key {
-                "ssl-mode" => {
+                "sslmode" | "ssl-mode" => {
                     options = options.ssl_mode(value.parse().map_err(Error::config)?);
                 }
 
-                "ssl-ca" => {
+                "sslca" | "ssl-ca" => {
                     options = options.ssl_ca(&*value);
                 }
 
@@ -68,7 +68,15 @@ impl FromStr for MySqlConnectOptions {
                     options = options.socket(&*value);
                 }
 
-                _ => {}
+                _ => {
+                    return Err(Error::Configuration(
+                        format!(
+                            "unknown query parameter `{}` while parsing connection URL",
+                            key
+                        )
+                        .into(),
+                    ));
+                }
             }
         }
 
@@ -76,6 +84,26 @@ impl FromStr for MySqlConnectOptions {
     }
 }
 
+#[test]
+fn it_parses_query_parameters() {
+    let url = "mysql://u:p@h/db?charset=latin1&sslmode=required";
+    let opts = MySqlConnectOptions::from_str(url).unwrap();
+
+    assert_eq!("latin1", &opts.charset);
+    assert!(matches!(
+        opts.ssl_mode,
+        crate::mysql::MySqlSslMode::Required
+    ));
+}
+
+#[test]
+fn it_rejects_unknown_parameters() {
+    let url = "mysql://u:p@h/db?charset=utf8mb4&application_name=foo";
+    let err = MySqlConnectOptions::from_str(url).unwrap_err();
+
+    assert!(err.to_string().contains("`application_name`"), "{}", err);
+}
+
 #[test]
 fn it_parses_username_with_at_sign_correctly() {
     let url = "mysql://user@hostname:password@hostname:5432/database";
@@ -7,6 +7,11 @@ pub struct MySqlQueryResult {
 }
 
 impl MySqlQueryResult {
+    /// The value generated for an `AUTO_INCREMENT` column by the last statement, with the
+    /// same semantics as `LAST_INSERT_ID()`.
+    ///
+    /// For an insert of multiple rows this is the id of the *first* inserted row.
+    /// It is `0` if the statement did not generate a value.
     pub fn last_insert_id(&self) -> u64 {
         self.last_insert_id
     }
@@ -6,8 +6,9 @@ use crate::mysql::connection::Waiting;
 use crate::mysql::protocol::text::Query;
 use crate::mysql::{MySql, MySqlConnection};
 use crate::transaction::{
-    begin_ansi_transaction_sql, commit_ansi_transaction_sql, rollback_ansi_transaction_sql,
-    TransactionManager,
+    begin_ansi_transaction_sql, commit_ansi_transaction_sql, release_deferred_savepoint_sql,
+    release_savepoint_ansi_sql, rollback_ansi_transaction_sql, rollback_to_savepoint_ansi_sql,
+    savepoint_ansi_sql, TransactionManager, TransactionOptions,
 };
 
 /// Implementation of [`TransactionManager`] for MySQL.
@@ -20,19 +21,57 @@ impl TransactionManager for MySqlTransactionManager {
         Box::pin(async move {
             let depth = conn.transaction_depth;
 
-            conn.execute(&*begin_ansi_transaction_sql(depth)).await?;
+            let sql = begin_ansi_transaction_sql(depth);
+            let sql = release_deferred_savepoint_sql(&mut conn.deferred_savepoint_release, &sql);
+
+            conn.execute(&*sql).await?;
             conn.transaction_depth = depth + 1;
 
             Ok(())
         })
     }
 
+    fn begin_with_options(
+        conn: &mut MySqlConnection,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            if conn.transaction_depth > 0 {
+                return Err(Error::Savepoint(
+                    "the options of a transaction cannot be applied to a savepoint".into(),
+                ));
+            }
+
+            // `SET TRANSACTION` without `SESSION` only applies to the next transaction
+            if let Some(level) = options.isolation_level {
+                conn.execute(&*format!(
+                    "SET TRANSACTION ISOLATION LEVEL {}",
+                    level.as_sql()
+                ))
+                .await?;
+            }
+
+            if options.read_only {
+                conn.execute("START TRANSACTION READ ONLY").await?;
+            } else {
+                conn.execute("START TRANSACTION").await?;
+            }
+
+            conn.transaction_depth = 1;
+
+            Ok(())
+        })
+    }
+
     fn commit(conn: &mut MySqlConnection) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(async move {
             let depth = conn.transaction_depth;
 
             if depth > 0 {
                 conn.execute(&*commit_ansi_transaction_sql(depth)).await?;
+
+                // this also releases any savepoint established after this one
+                conn.deferred_savepoint_release = None;
                 conn.transaction_depth = depth - 1;
             }
 
@@ -40,12 +79,32 @@ impl TransactionManager for MySqlTransactionManager {
         })
     }
 
+    fn commit_with_savepoint_release(
+        conn: &mut MySqlConnection,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            let depth = conn.transaction_depth;
+
+            if depth > 1 {
+                conn.deferred_savepoint_release = Some(depth - 1);
+                conn.transaction_depth = depth - 1;
+
+                return Ok(());
+            }
+
+            Self::commit(conn).await
+        })
+    }
+
     fn rollback(conn: &mut MySqlConnection) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(async move {
             let depth = conn.transaction_depth;
 
             if depth > 0 {
                 conn.execute(&*rollback_ansi_transaction_sql(depth)).await?;
+
+                // this also destroys any savepoint established after this one
+                conn.deferred_savepoint_release = None;
                 conn.transaction_depth = depth - 1;
             }
 
@@ -62,7 +121,50 @@ impl TransactionManager for MySqlTransactionManager {
             conn.stream
                 .write_packet(Query(&*rollback_ansi_transaction_sql(depth)));
 
+            conn.deferred_savepoint_release = None;
             conn.transaction_depth = depth - 1;
         }
     }
+
+    fn savepoint<'c>(
+        conn: &'c mut MySqlConnection,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move {
+            let sql = savepoint_ansi_sql(name, '`');
+            let sql = release_deferred_savepoint_sql(&mut conn.deferred_savepoint_release, &sql);
+
+            conn.execute(&*sql).await?;
+
+            Ok(())
+        })
+    }
+
+    fn rollback_to_savepoint<'c>(
+        conn: &'c mut MySqlConnection,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move {
+            let sql = rollback_to_savepoint_ansi_sql(name, '`');
+            let sql = release_deferred_savepoint_sql(&mut conn.deferred_savepoint_release, &sql);
+
+            conn.execute(&*sql).await?;
+
+            Ok(())
+        })
+    }
+
+    fn release_savepoint<'c>(
+        conn: &'c mut MySqlConnection,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move {
+            let sql = release_savepoint_ansi_sql(name, '`');
+            let sql = release_deferred_savepoint_sql(&mut conn.deferred_savepoint_release, &sql);
+
+            conn.execute(&*sql).await?;
+
+            Ok(())
+        })
+    }
 }
@@ -36,6 +36,13 @@ impl MySqlTypeInfo {
         }
     }
 
+    // used in derive(Type) for `enum`, as the columns of an `ENUM` are sent as strings flagged
+    // as such rather than with their own type
+    #[doc(hidden)]
+    pub fn __enum_compatible(&self) -> bool {
+        matches!(self.r#type, ColumnType::Enum) || self.flags.contains(ColumnFlags::ENUM)
+    }
+
     #[doc(hidden)]
     pub fn __type_feature_gate(&self) -> Option<&'static str> {
         match self.r#type {
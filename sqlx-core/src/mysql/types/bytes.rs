@@ -61,3 +61,29 @@ impl Decode<'_, MySql> for Vec<u8> {
         <&[u8] as Decode<MySql>>::decode(value).map(ToOwned::to_owned)
     }
 }
+
+impl<const N: usize> Type<MySql> for [u8; N] {
+    fn type_info() -> MySqlTypeInfo {
+        <[u8] as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        <&[u8] as Type<MySql>>::compatible(ty)
+    }
+}
+
+impl<const N: usize> Encode<'_, MySql> for [u8; N] {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        <&[u8] as Encode<MySql>>::encode(self.as_slice(), buf)
+    }
+}
+
+impl<const N: usize> Decode<'_, MySql> for [u8; N] {
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        let bytes = <&[u8] as Decode<MySql>>::decode(value)?;
+
+        bytes
+            .try_into()
+            .map_err(|_| format!("expected {} bytes, got {}", N, bytes.len()).into())
+    }
+}
@@ -23,7 +23,7 @@ impl<T: TimeZone> Type<MySql> for DateTime<T> {
 }
 
 /// Note: assumes the connection's `time_zone` is set to `+00:00` (UTC).
-impl Encode<'_, MySql> for DateTime<Utc> {
+impl<Tz: TimeZone> Encode<'_, MySql> for DateTime<Tz> {
     fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
         Encode::<MySql>::encode(&self.naive_utc(), buf)
     }
@@ -38,13 +38,6 @@ impl<'r> Decode<'r, MySql> for DateTime<Utc> {
     }
 }
 
-/// Note: assumes the connection's `time_zone` is set to `+00:00` (UTC).
-impl Encode<'_, MySql> for DateTime<Local> {
-    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
-        Encode::<MySql>::encode(&self.naive_utc(), buf)
-    }
-}
-
 /// Note: assumes the connection's `time_zone` is set to `+00:00` (UTC).
 impl<'r> Decode<'r, MySql> for DateTime<Local> {
     fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
@@ -95,7 +95,13 @@ fn int_decode(value: MySqlValueRef<'_>) -> Result<i64, BoxDynError> {
         MySqlValueFormat::Text => value.as_str()?.parse()?,
         MySqlValueFormat::Binary => {
             let buf = value.as_bytes()?;
-            LittleEndian::read_int(buf, buf.len())
+
+            if value.type_info.flags.contains(ColumnFlags::UNSIGNED) {
+                // an unsigned value is not sign-extended, and may not fit
+                LittleEndian::read_uint(buf, buf.len()).try_into()?
+            } else {
+                LittleEndian::read_int(buf, buf.len())
+            }
         }
     })
 }
@@ -12,6 +12,11 @@ impl<T> Type<MySql> for Json<T> {
         // MySql uses the `CHAR` type to pass JSON data from and to the client
         // NOTE: This is forwards-compatible with MySQL v8+ as CHAR is a common transmission format
         //       and has nothing to do with the native storage ability of MySQL v8+
+        //
+        // `MYSQL_TYPE_JSON` is not accepted as a parameter type by MariaDB or older versions of
+        // MySQL, so we don't send it; a string bound to a `JSON` column is parsed and stored as a
+        // JSON document by the server, and it is only kept as a string when the column's type
+        // is a string type too.
         MySqlTypeInfo::binary(ColumnType::String)
     }
 
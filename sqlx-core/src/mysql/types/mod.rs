@@ -17,6 +17,12 @@
 //! | `f64`                                 | DOUBLE                                               |
 //! | `&str`, [`String`]                    | VARCHAR, CHAR, TEXT                                  |
 //! | `&[u8]`, `Vec<u8>`                    | VARBINARY, BINARY, BLOB                              |
+//! | [`MySqlSet<T>`]                       | SET                                                  |
+//!
+//! A `SET` is decoded into its members with [`MySqlSet`], e.g. `MySqlSet<Vec<String>>`, and the
+//! empty set into an empty collection. The columns
+//! of an `ENUM` can be decoded into a [`String`], or into a Rust enum with `#[derive(sqlx::Type)]`
+//! whose variants are matched by name, which can be adjusted with `#[sqlx(rename_all = "..")]`.
 //!
 //! ### [`chrono`](https://crates.io/crates/chrono)
 //!
@@ -26,10 +32,16 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `chrono::DateTime<Utc>`               | TIMESTAMP                                            |
 //! | `chrono::DateTime<Local>`             | TIMESTAMP                                            |
+//! | `chrono::DateTime<FixedOffset>`       | TIMESTAMP                                            |
 //! | `chrono::NaiveDateTime`               | DATETIME                                             |
 //! | `chrono::NaiveDate`                   | DATE                                                 |
 //! | `chrono::NaiveTime`                   | TIME                                                 |
 //!
+//! A `chrono::DateTime` in any time zone can be bound, and is converted to UTC. A column is
+//! decoded in UTC, or in the local time zone as `DateTime<Local>`; a `DateTime<FixedOffset>`
+//! always has an offset of zero. Both assume that the `time_zone` of the connection is
+//! `+00:00`.
+//!
 //! ### [`time`](https://crates.io/crates/time)
 //!
 //! Requires the `time` Cargo feature flag.
@@ -84,9 +96,12 @@ mod bool;
 mod bytes;
 mod float;
 mod int;
+mod set;
 mod str;
 mod uint;
 
+pub use set::MySqlSet;
+
 #[cfg(feature = "bigdecimal")]
 mod bigdecimal;
 
@@ -0,0 +1,70 @@
+use crate::decode::Decode;
+use crate::error::BoxDynError;
+use crate::mysql::protocol::text::{ColumnFlags, ColumnType};
+use crate::mysql::{MySql, MySqlTypeInfo, MySqlValueRef};
+use crate::types::Type;
+
+// https://dev.mysql.com/doc/refman/8.0/en/set.html
+
+// the value of a `SET` is sent as its members joined by commas, which is unambiguous
+// as the members themselves may not contain commas
+
+fn split_set(value: &str) -> impl Iterator<Item = &str> {
+    // the empty set is the empty string, rather than a single empty member
+    value.split(',').filter(|member| !member.is_empty())
+}
+
+/// The members of a MySQL `SET`, decoded into a collection of strings.
+///
+/// Any collection that can be built from an iterator of [`String`], such as `Vec<String>`,
+/// `HashSet<String>` or `BTreeSet<String>`, can be used. The empty set is decoded into an empty
+/// collection.
+///
+/// ```rust,no_run
+/// # use sqlx_core::error::Error;
+/// # use sqlx_core::mysql::MySqlConnection;
+/// # use sqlx_core::mysql::types::MySqlSet;
+/// # use sqlx_core::query_scalar::query_scalar;
+/// # use std::collections::HashSet;
+/// # async fn example(conn: &mut MySqlConnection) -> Result<(), Error> {
+/// let MySqlSet(toppings): MySqlSet<HashSet<String>> =
+///     query_scalar("SELECT toppings FROM pizzas").fetch_one(conn).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MySqlSet<T>(pub T);
+
+impl<T> Type<MySql> for MySqlSet<T> {
+    fn type_info() -> MySqlTypeInfo {
+        MySqlTypeInfo {
+            r#type: ColumnType::Set,
+            flags: ColumnFlags::SET,
+            char_set: 224, // utf8mb4_unicode_ci
+            max_size: None,
+        }
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        // like those of an `ENUM`, the columns of a `SET` are sent as strings flagged as such
+        matches!(ty.r#type, ColumnType::Set) || ty.flags.contains(ColumnFlags::SET)
+    }
+}
+
+impl<T> Decode<'_, MySql> for MySqlSet<T>
+where
+    T: FromIterator<String>,
+{
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(MySqlSet(
+            split_set(value.as_str()?).map(ToOwned::to_owned).collect(),
+        ))
+    }
+}
+
+#[test]
+fn it_splits_set_members() {
+    assert_eq!(split_set("").count(), 0);
+    assert_eq!(split_set("a").collect::<Vec<_>>(), ["a"]);
+    assert_eq!(split_set("a,b,c").collect::<Vec<_>>(), ["a", "b", "c"]);
+}
@@ -119,7 +119,13 @@ fn uint_decode(value: MySqlValueRef<'_>) -> Result<u64, BoxDynError> {
 
         MySqlValueFormat::Binary => {
             let buf = value.as_bytes()?;
-            LittleEndian::read_uint(buf, buf.len())
+
+            if value.type_info.flags.contains(ColumnFlags::UNSIGNED) {
+                LittleEndian::read_uint(buf, buf.len())
+            } else {
+                // a negative signed value does not fit
+                LittleEndian::read_int(buf, buf.len()).try_into()?
+            }
         }
     })
 }
@@ -6,7 +6,7 @@ use bytes::Bytes;
 use std::borrow::Cow;
 use std::str::from_utf8;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
 #[repr(u8)]
 pub enum MySqlValueFormat {
     Text,
@@ -31,18 +31,26 @@ pub struct MySqlValueRef<'r> {
 }
 
 impl<'r> MySqlValueRef<'r> {
-    pub(crate) fn format(&self) -> MySqlValueFormat {
+    /// The format the value was sent in by the server, which is the binary format
+    /// for prepared queries and the text format for simple queries.
+    pub fn format(&self) -> MySqlValueFormat {
         self.format
     }
 
-    pub(crate) fn as_bytes(&self) -> Result<&'r [u8], BoxDynError> {
+    /// The undecoded bytes of the value, in its [`format`][Self::format].
+    ///
+    /// Returns an error if the value is `NULL`.
+    pub fn as_bytes(&self) -> Result<&'r [u8], BoxDynError> {
         match &self.value {
             Some(v) => Ok(v),
             None => Err(UnexpectedNullError.into()),
         }
     }
 
-    pub(crate) fn as_str(&self) -> Result<&'r str, BoxDynError> {
+    /// The undecoded bytes of the value, as a string.
+    ///
+    /// Returns an error if the value is `NULL` or not valid UTF-8.
+    pub fn as_str(&self) -> Result<&'r str, BoxDynError> {
         Ok(from_utf8(self.as_bytes()?)?)
     }
 }
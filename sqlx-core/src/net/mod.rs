@@ -1,8 +1,8 @@
 mod socket;
 mod tls;
 
-pub use socket::Socket;
-pub use tls::{CertificateInput, MaybeTlsStream};
+pub use socket::{Socket, TcpOptions};
+pub use tls::{CertificateInput, MaybeTlsStream, TlsInfo};
 
 #[cfg(feature = "_rt-async-std")]
 type PollReadBuf<'a> = [u8];
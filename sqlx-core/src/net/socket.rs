@@ -5,9 +5,33 @@ use std::net::SocketAddr;
 use std::path::Path;
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use std::time::Duration;
 
 use sqlx_rt::{AsyncRead, AsyncWrite, TcpStream};
 
+/// Options applied to TCP sockets right after they are connected.
+///
+/// These have no effect on Unix domain sockets.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TcpOptions {
+    pub keepalive: Option<Duration>,
+    pub nodelay: bool,
+}
+
+impl TcpOptions {
+    pub fn apply(&self, stream: &TcpStream) -> io::Result<()> {
+        stream.set_nodelay(self.nodelay)?;
+
+        if let Some(time) = self.keepalive {
+            let keepalive = socket2::TcpKeepalive::new().with_time(time);
+
+            socket2::SockRef::from(stream).set_tcp_keepalive(&keepalive)?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub enum Socket {
     Tcp(TcpStream),
@@ -17,11 +41,14 @@ pub enum Socket {
 }
 
 impl Socket {
-    pub async fn connect_tcp(host: &str, port: u16) -> io::Result<Self> {
+    pub async fn connect_tcp(host: &str, port: u16, options: TcpOptions) -> io::Result<Self> {
         // Trim square brackets from host if it's an IPv6 address as the `url` crate doesn't do that.
-        TcpStream::connect((host.trim_matches(|c| c == '[' || c == ']'), port))
-            .await
-            .map(Socket::Tcp)
+        let stream =
+            TcpStream::connect((host.trim_matches(|c| c == '[' || c == ']'), port)).await?;
+
+        options.apply(&stream)?;
+
+        Ok(Socket::Tcp(stream))
     }
 
     #[cfg(unix)]
@@ -132,3 +159,34 @@ impl AsyncWrite for Socket {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_applies_tcp_options() -> io::Result<()> {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let port = listener.local_addr()?.port();
+
+        let options = TcpOptions {
+            keepalive: Some(Duration::from_secs(60)),
+            nodelay: true,
+        };
+
+        let socket = sqlx_rt::test_block_on(Socket::connect_tcp("127.0.0.1", port, options))?;
+
+        let stream = match &socket {
+            Socket::Tcp(stream) => stream,
+            #[cfg(unix)]
+            Socket::Unix(_) => unreachable!(),
+        };
+
+        let sock = socket2::SockRef::from(stream);
+
+        assert!(sock.nodelay()?);
+        assert!(sock.keepalive()?);
+
+        Ok(())
+    }
+}
@@ -53,6 +53,28 @@ impl std::fmt::Display for CertificateInput {
     }
 }
 
+/// The parameters negotiated for the TLS session of a connection.
+///
+/// How much is known depends on the TLS backend: `rustls` reports every field, while
+/// `native-tls` has no portable way to query the protocol version or the cipher suite across
+/// OpenSSL, Secure Transport and SChannel, so only the certificate of the server is reported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TlsInfo {
+    /// The version of the protocol, e.g. `TLSv1_3`.
+    ///
+    /// This is always `None` with `native-tls`, which does not expose it.
+    pub protocol_version: Option<String>,
+
+    /// The cipher suite, e.g. `TLS13_AES_256_GCM_SHA384`.
+    ///
+    /// This is always `None` with `native-tls`, which does not expose it.
+    pub cipher_suite: Option<String>,
+
+    /// The DER encoding of the certificate presented by the server, if any.
+    pub peer_certificate_der: Option<Vec<u8>>,
+}
+
 #[cfg(feature = "_tls-rustls")]
 mod rustls;
 
@@ -110,6 +132,65 @@ where
 
         Ok(())
     }
+
+    /// The parameters of the TLS session, or `None` if the stream is not encrypted.
+    pub fn tls_info(&self) -> Option<TlsInfo> {
+        // the certificate is only for information here, so failing to encode it is not an error
+        let peer_certificate_der = self.peer_certificate().ok().flatten();
+
+        match self {
+            MaybeTlsStream::Raw(_) | MaybeTlsStream::Upgrading => None,
+
+            #[cfg(feature = "_tls-rustls")]
+            MaybeTlsStream::Tls(s) => {
+                let conn = s.get_ref().1;
+
+                Some(TlsInfo {
+                    protocol_version: conn.protocol_version().map(|v| format!("{:?}", v)),
+                    cipher_suite: conn
+                        .negotiated_cipher_suite()
+                        .map(|suite| format!("{:?}", suite.suite())),
+                    peer_certificate_der,
+                })
+            }
+
+            // `native-tls` does not expose the negotiated version or cipher suite
+            #[cfg(feature = "_tls-native-tls")]
+            MaybeTlsStream::Tls(_) => Some(TlsInfo {
+                protocol_version: None,
+                cipher_suite: None,
+                peer_certificate_der,
+            }),
+        }
+    }
+
+    /// The DER encoding of the certificate presented by the server, or `None` if the stream
+    /// is not encrypted.
+    pub fn peer_certificate(&self) -> Result<Option<Vec<u8>>, Error> {
+        match self {
+            MaybeTlsStream::Raw(_) | MaybeTlsStream::Upgrading => Ok(None),
+
+            #[cfg(feature = "_tls-rustls")]
+            MaybeTlsStream::Tls(s) => Ok(s
+                .get_ref()
+                .1
+                .peer_certificates()
+                .and_then(|certs| certs.first())
+                .map(|cert| cert.0.clone())),
+
+            #[cfg(all(feature = "_rt-async-std", feature = "_tls-native-tls"))]
+            MaybeTlsStream::Tls(s) => match s.peer_certificate()? {
+                Some(cert) => Ok(Some(cert.to_der()?)),
+                None => Ok(None),
+            },
+
+            #[cfg(all(not(feature = "_rt-async-std"), feature = "_tls-native-tls"))]
+            MaybeTlsStream::Tls(s) => match s.get_ref().peer_certificate()? {
+                Some(cert) => Ok(Some(cert.to_der()?)),
+                None => Ok(None),
+            },
+        }
+    }
 }
 
 #[cfg(feature = "_tls-native-tls")]
@@ -24,6 +24,7 @@ pub struct PoolConnection<DB: Database> {
 pub(super) struct Live<DB: Database> {
     pub(super) raw: DB::Connection,
     pub(super) created_at: Instant,
+    pub(super) max_lifetime: Option<Duration>,
 }
 
 pub(super) struct Idle<DB: Database> {
@@ -177,11 +178,16 @@ impl<DB: Database> DerefMut for Idle<DB> {
 }
 
 impl<DB: Database> Floating<DB, Live<DB>> {
-    pub fn new_live(conn: DB::Connection, guard: DecrementSizeGuard<DB>) -> Self {
+    pub fn new_live(
+        conn: DB::Connection,
+        max_lifetime: Option<Duration>,
+        guard: DecrementSizeGuard<DB>,
+    ) -> Self {
         Self {
             inner: Live {
                 raw: conn,
                 created_at: Instant::now(),
+                max_lifetime,
             },
             guard,
         }
@@ -280,6 +286,7 @@ impl<DB: Database> Floating<DB, Live<DB>> {
         PoolConnectionMetadata {
             age: self.created_at.elapsed(),
             idle_for: Duration::ZERO,
+            max_lifetime: self.max_lifetime,
         }
     }
 }
@@ -329,6 +336,7 @@ impl<DB: Database> Floating<DB, Idle<DB>> {
             // https://github.com/launchbadge/sqlx/issues/1912
             age: now.saturating_duration_since(self.created_at),
             idle_for: now.saturating_duration_since(self.idle_since),
+            max_lifetime: self.max_lifetime,
         }
     }
 }
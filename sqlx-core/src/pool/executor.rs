@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use either::Either;
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
@@ -36,6 +38,28 @@ where
         })
     }
 
+    fn fetch_many_with_size<'e, 'q: 'e, E: 'q>(
+        self,
+        query: E,
+        size: usize,
+    ) -> BoxStream<'e, Result<Either<DB::QueryResult, DB::Row>, Error>>
+    where
+        E: Execute<'q, Self::Database>,
+    {
+        let pool = self.clone();
+
+        Box::pin(try_stream! {
+            let mut conn = pool.acquire().await?;
+            let mut s = conn.fetch_many_with_size(query, size);
+
+            while let Some(v) = s.try_next().await? {
+                r#yield!(v);
+            }
+
+            Ok(())
+        })
+    }
+
     fn fetch_optional<'e, 'q: 'e, E: 'q>(
         self,
         query: E,
@@ -69,6 +93,66 @@ where
     }
 }
 
+// a pool is already reference-counted, but it is often shared as an `Arc<Pool>` all the same
+impl<'p, DB: Database> Executor<'p> for &'_ Arc<Pool<DB>>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+{
+    type Database = DB;
+
+    #[inline]
+    fn fetch_many<'e, 'q: 'e, E: 'q>(
+        self,
+        query: E,
+    ) -> BoxStream<'e, Result<Either<DB::QueryResult, DB::Row>, Error>>
+    where
+        E: Execute<'q, Self::Database>,
+    {
+        (**self).fetch_many(query)
+    }
+
+    #[inline]
+    fn fetch_many_with_size<'e, 'q: 'e, E: 'q>(
+        self,
+        query: E,
+        size: usize,
+    ) -> BoxStream<'e, Result<Either<DB::QueryResult, DB::Row>, Error>>
+    where
+        E: Execute<'q, Self::Database>,
+    {
+        (**self).fetch_many_with_size(query, size)
+    }
+
+    #[inline]
+    fn fetch_optional<'e, 'q: 'e, E: 'q>(
+        self,
+        query: E,
+    ) -> BoxFuture<'e, Result<Option<DB::Row>, Error>>
+    where
+        E: Execute<'q, Self::Database>,
+    {
+        (**self).fetch_optional(query)
+    }
+
+    #[inline]
+    fn prepare_with<'e, 'q: 'e>(
+        self,
+        sql: &'q str,
+        parameters: &'e [<Self::Database as Database>::TypeInfo],
+    ) -> BoxFuture<'e, Result<<Self::Database as HasStatement<'q>>::Statement, Error>> {
+        (**self).prepare_with(sql, parameters)
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn describe<'e, 'q: 'e>(
+        self,
+        sql: &'q str,
+    ) -> BoxFuture<'e, Result<Describe<Self::Database>, Error>> {
+        (**self).describe(sql)
+    }
+}
+
 // NOTE: required due to lack of lazy normalization
 #[allow(unused_macros)]
 macro_rules! impl_executor_for_pool_connection {
@@ -94,6 +178,25 @@ macro_rules! impl_executor_for_pool_connection {
                 (**self).fetch_many(query)
             }
 
+            #[inline]
+            fn fetch_many_with_size<'e, 'q: 'e, E: 'q>(
+                self,
+                query: E,
+                size: usize,
+            ) -> futures_core::stream::BoxStream<
+                'e,
+                Result<
+                    either::Either<<$DB as crate::database::Database>::QueryResult, $R>,
+                    crate::error::Error,
+                >,
+            >
+            where
+                'c: 'e,
+                E: crate::executor::Execute<'q, $DB>,
+            {
+                (**self).fetch_many_with_size(query, size)
+            }
+
             #[inline]
             fn fetch_optional<'e, 'q: 'e, E: 'q>(
                 self,
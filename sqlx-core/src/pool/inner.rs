@@ -9,7 +9,9 @@ use crossbeam_queue::ArrayQueue;
 use futures_intrusive::sync::{Semaphore, SemaphoreReleaser};
 
 use std::cmp;
+use std::collections::hash_map::RandomState;
 use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
 use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::task::Poll;
@@ -25,6 +27,7 @@ pub(crate) struct PoolInner<DB: Database> {
     pub(super) semaphore: Semaphore,
     pub(super) size: AtomicU32,
     pub(super) num_idle: AtomicUsize,
+    num_waiting: AtomicUsize,
     is_closed: AtomicBool,
     pub(super) on_closed: event_listener::Event,
     pub(super) options: PoolOptions<DB>,
@@ -52,6 +55,7 @@ impl<DB: Database> PoolInner<DB> {
             semaphore: Semaphore::new(options.fair, semaphore_capacity),
             size: AtomicU32::new(0),
             num_idle: AtomicUsize::new(0),
+            num_waiting: AtomicUsize::new(0),
             is_closed: AtomicBool::new(false),
             on_closed: event_listener::Event::new(),
             options,
@@ -77,6 +81,10 @@ impl<DB: Database> PoolInner<DB> {
         self.num_idle.load(Ordering::Acquire)
     }
 
+    pub(super) fn num_waiting(&self) -> usize {
+        self.num_waiting.load(Ordering::Acquire)
+    }
+
     pub(super) fn is_closed(&self) -> bool {
         self.is_closed.load(Ordering::Acquire)
     }
@@ -102,6 +110,32 @@ impl<DB: Database> PoolInner<DB> {
         }
     }
 
+    pub(super) fn close_timeout<'a>(
+        self: &'a Arc<Self>,
+        timeout: Duration,
+    ) -> impl Future<Output = ()> + 'a {
+        let close = self.close();
+
+        async move {
+            if sqlx_rt::timeout(timeout, close).await.is_ok() {
+                return;
+            }
+
+            log::warn!(
+                "pool did not close within {:?}; abandoning {} checked-out connection(s)",
+                timeout,
+                self.size().saturating_sub(self.idle_conns.len() as u32)
+            );
+
+            // Drop any idle connections left without closing them gracefully.
+            // Connections which are still checked out see that the pool is closed
+            // and close themselves instead of returning to the idle queue when released.
+            while let Some(idle) = self.idle_conns.pop() {
+                drop(idle.live.float((*self).clone()));
+            }
+        }
+    }
+
     pub(crate) fn close_event(&self) -> CloseEvent {
         CloseEvent {
             listener: (!self.is_closed()).then(|| self.on_closed.listen()),
@@ -113,6 +147,9 @@ impl<DB: Database> PoolInner<DB> {
     /// If we steal a permit from the parent but *don't* open a connection,
     /// it should be returned to the parent.
     async fn acquire_permit<'a>(self: &'a Arc<Self>) -> Result<SemaphoreReleaser<'a>, Error> {
+        // counts this task as waiting until it gets a permit, gives up, or is cancelled
+        let _waiting = WaitingGuard::new(&self.num_waiting);
+
         let parent = self
             .parent()
             // If we're already at the max size, we shouldn't try to steal from the parent.
@@ -180,6 +217,37 @@ impl<DB: Database> PoolInner<DB> {
         self.pop_idle(permit).ok()
     }
 
+    /// Like [`try_acquire()`][Self::try_acquire], but opens a new connection if none is idle
+    /// and the pool is below `max_connections`.
+    pub(super) async fn try_acquire_or_connect(
+        self: &Arc<Self>,
+    ) -> Result<Option<Floating<DB, Live<DB>>>, Error> {
+        if self.is_closed() {
+            return Err(Error::PoolClosed);
+        }
+
+        // unlike `acquire_permit()`, this does not line up behind the tasks already waiting
+        let permit = match self.semaphore.try_acquire(1) {
+            Some(permit) => permit,
+            None => return Ok(None),
+        };
+
+        let guard = match self.pop_idle(permit) {
+            Ok(conn) => match check_idle_conn(conn, &self.options).await {
+                Ok(live) => return Ok(Some(live)),
+                Err(guard) => guard,
+            },
+            Err(permit) => match self.try_increment_size(permit) {
+                Ok(guard) => guard,
+                Err(_) => return Ok(None),
+            },
+        };
+
+        let deadline = Instant::now() + self.options.acquire_timeout;
+
+        self.connect(deadline, guard).await.map(Some)
+    }
+
     fn pop_idle<'a>(
         self: &'a Arc<Self>,
         permit: SemaphoreReleaser<'a>,
@@ -227,14 +295,26 @@ impl<DB: Database> PoolInner<DB> {
     }
 
     pub(super) async fn acquire(self: &Arc<Self>) -> Result<Floating<DB, Live<DB>>, Error> {
+        self.acquire_with_timeout(self.options.acquire_timeout)
+            .await
+    }
+
+    /// Acquire a connection, waiting at most `timeout` instead of `options.acquire_timeout`.
+    ///
+    /// The inner future is always polled once before the timeout is checked, so a zero
+    /// `timeout` still returns an idle connection if one is immediately available.
+    pub(super) async fn acquire_with_timeout(
+        self: &Arc<Self>,
+        timeout: Duration,
+    ) -> Result<Floating<DB, Live<DB>>, Error> {
         if self.is_closed() {
             return Err(Error::PoolClosed);
         }
 
-        let deadline = Instant::now() + self.options.acquire_timeout;
+        let deadline = Instant::now() + timeout;
 
         sqlx_rt::timeout(
-            self.options.acquire_timeout,
+            timeout,
             async {
                 loop {
                     // Handles the close-event internally
@@ -289,18 +369,31 @@ impl<DB: Database> PoolInner<DB> {
         let mut backoff = Duration::from_millis(10);
         let max_backoff = deadline_as_timeout::<DB>(deadline)? / 5;
 
+        // the error of the last failed attempt, returned instead of `PoolTimedOut` if we run out
+        // of time retrying so that the cause (e.g. the connection being refused) isn't lost
+        let mut last_error = None;
+
         loop {
             let timeout = deadline_as_timeout::<DB>(deadline)?;
 
             // result here is `Result<Result<C, Error>, TimeoutError>`
             // if this block does not return, sleep for the backoff timeout and try again
-            match sqlx_rt::timeout(timeout, self.connect_options.connect()).await {
+            let error = match sqlx_rt::timeout(timeout, self.connect_options.connect()).await {
                 // successfully established connection
                 Ok(Ok(mut raw)) => {
+                    let max_lifetime = self.options.max_lifetime.map(|lifetime| {
+                        jitter_lifetime(
+                            lifetime,
+                            self.options.max_lifetime_jitter,
+                            random_fraction(),
+                        )
+                    });
+
                     // See comment on `PoolOptions::after_connect`
                     let meta = PoolConnectionMetadata {
                         age: Duration::ZERO,
                         idle_for: Duration::ZERO,
+                        max_lifetime,
                     };
 
                     let res = if let Some(callback) = &self.options.after_connect {
@@ -309,32 +402,62 @@ impl<DB: Database> PoolInner<DB> {
                         Ok(())
                     };
 
+                    let res = match (res, &self.options.preload_statements) {
+                        (Ok(()), Some(preload)) => match preload(&mut raw).await {
+                            // a statement that the database rejects would be rejected on
+                            // every connection, so there is no point in retrying
+                            Err(Error::Database(e)) => {
+                                let _ = raw.close().await;
+                                return Err(Error::Database(e));
+                            }
+
+                            res => res,
+                        },
+
+                        (res, _) => res,
+                    };
+
                     match res {
-                        Ok(()) => return Ok(Floating::new_live(raw, guard)),
+                        Ok(()) => return Ok(Floating::new_live(raw, max_lifetime, guard)),
                         Err(e) => {
                             log::error!("error returned from after_connect: {:?}", e);
                             // The connection is broken, don't try to close nicely.
                             let _ = raw.close_hard().await;
 
                             // Fall through to the backoff.
+                            e
                         }
                     }
                 }
 
-                // an IO error while connecting is assumed to be the system starting up
-                Ok(Err(Error::Io(e))) if e.kind() == std::io::ErrorKind::ConnectionRefused => (),
+                // a transport error while connecting is assumed to be the server starting up
+                // or restarting, e.g. after the connection we just discarded was dropped
+                Ok(Err(e)) if e.is_transport() => {
+                    log::debug!("transport error while connecting, retrying: {}", e);
+                    e
+                }
 
                 // We got a transient database error, retry.
-                Ok(Err(Error::Database(error))) if error.is_transient_in_connect_phase() => (),
+                Ok(Err(Error::Database(error))) if error.is_transient_in_connect_phase() => {
+                    Error::Database(error)
+                }
 
                 // Any other error while connection should immediately
                 // terminate and bubble the error up
                 Ok(Err(e)) => return Err(e),
 
                 // timed out
-                Err(_) => return Err(Error::PoolTimedOut),
+                Err(_) => return Err(last_error.unwrap_or(Error::PoolTimedOut)),
+            };
+
+            // don't sleep past the deadline, as `acquire()` would then time out first and
+            // replace the error with `PoolTimedOut`
+            if Instant::now() + backoff >= deadline {
+                return Err(error);
             }
 
+            last_error = Some(error);
+
             // If the connection is refused, wait in exponentially
             // increasing steps for the server to come up,
             // capped by a factor of the remaining time until the deadline
@@ -399,13 +522,38 @@ impl<DB: Database> Drop for PoolInner<DB> {
     }
 }
 
-/// Returns `true` if the connection has exceeded `options.max_lifetime` if set, `false` otherwise.
-fn is_beyond_max_lifetime<DB: Database>(live: &Live<DB>, options: &PoolOptions<DB>) -> bool {
-    options
-        .max_lifetime
+/// Returns `true` if the connection has exceeded its `max_lifetime` if set, `false` otherwise.
+fn is_beyond_max_lifetime<DB: Database>(live: &Live<DB>) -> bool {
+    live.max_lifetime
         .map_or(false, |max| live.created_at.elapsed() > max)
 }
 
+/// Moves `lifetime` by up to `jitter` either way, by `fraction` of the range in between,
+/// without making it any shorter than zero.
+fn jitter_lifetime(lifetime: Duration, jitter: Duration, fraction: f64) -> Duration {
+    // `Duration::mul_f64()` panics when its result does not fit, which rounding can make it do
+    // even for a factor of one, so this is computed by hand and kept within `jitter`
+    let scaled = |factor: f64| {
+        Duration::try_from_secs_f64(jitter.as_secs_f64() * factor)
+            .map_or(jitter, |scaled| cmp::min(scaled, jitter))
+    };
+
+    if fraction < 0.5 {
+        lifetime.saturating_sub(scaled(1.0 - 2.0 * fraction))
+    } else {
+        lifetime.saturating_add(scaled(2.0 * fraction - 1.0))
+    }
+}
+
+/// Returns a random number in `[0, 1)`, which is good enough to spread out the lifetimes of
+/// connections without depending on `rand`.
+fn random_fraction() -> f64 {
+    // each `RandomState` is seeded with different keys
+    let random = RandomState::new().build_hasher().finish();
+
+    (random >> 11) as f64 / (1_u64 << 53) as f64
+}
+
 /// Returns `true` if the connection has exceeded `options.idle_timeout` if set, `false` otherwise.
 fn is_beyond_idle_timeout<DB: Database>(idle: &Idle<DB>, options: &PoolOptions<DB>) -> bool {
     options
@@ -419,7 +567,7 @@ async fn check_idle_conn<DB: Database>(
 ) -> Result<Floating<DB, Live<DB>>, DecrementSizeGuard<DB>> {
     // If the connection we pulled has expired, close the connection and
     // immediately create a new connection
-    if is_beyond_max_lifetime(&conn, options) {
+    if is_beyond_max_lifetime(&conn) {
         return Err(conn.close().await);
     }
 
@@ -429,7 +577,17 @@ async fn check_idle_conn<DB: Database>(
             // an error here means the other end has hung up or we lost connectivity
             // either way we're fine to just discard the connection
             // the error itself here isn't necessarily unexpected so WARN is too strong
-            log::info!("ping on idle connection returned error: {}", e);
+            //
+            // `acquire()` will open a fresh connection in its place, so the caller never
+            // sees a connection that the server (or a proxy in between) has already closed
+            if e.is_transport() {
+                log::info!(
+                    "idle connection was closed by the server, reconnecting: {}",
+                    e
+                );
+            } else {
+                log::info!("ping on idle connection returned error: {}", e);
+            }
             // connection is broken so don't try to close nicely
             return Err(conn.close_hard().await);
         }
@@ -512,8 +670,7 @@ async fn do_reap<DB: Database>(pool: &Arc<PoolInner<DB>>) {
         // only connections waiting in the queue
         .filter_map(|_| pool.try_acquire())
         .partition::<Vec<_>, _>(|conn| {
-            is_beyond_idle_timeout(conn, &pool.options)
-                || is_beyond_max_lifetime(conn, &pool.options)
+            is_beyond_idle_timeout(conn, &pool.options) || is_beyond_max_lifetime(conn)
         });
 
     for conn in keep {
@@ -526,6 +683,22 @@ async fn do_reap<DB: Database>(pool: &Arc<PoolInner<DB>>) {
     }
 }
 
+/// RAII guard counting a task waiting for a permit in `PoolInner::num_waiting`.
+struct WaitingGuard<'a>(&'a AtomicUsize);
+
+impl<'a> WaitingGuard<'a> {
+    fn new(num_waiting: &'a AtomicUsize) -> Self {
+        num_waiting.fetch_add(1, Ordering::AcqRel);
+        Self(num_waiting)
+    }
+}
+
+impl Drop for WaitingGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
 /// RAII guard returned by `Pool::try_increment_size()` and others.
 ///
 /// Will decrement the pool size if dropped, to avoid semantically "leaking" connections
@@ -573,3 +746,31 @@ impl<DB: Database> Drop for DecrementSizeGuard<DB> {
         }
     }
 }
+
+#[test]
+fn it_jitters_lifetimes_without_going_below_zero() {
+    let lifetime = Duration::from_secs(60);
+    let jitter = Duration::from_secs(6);
+
+    assert_eq!(
+        jitter_lifetime(lifetime, jitter, 0.0),
+        Duration::from_secs(54)
+    );
+    assert_eq!(jitter_lifetime(lifetime, jitter, 0.5), lifetime);
+    assert_eq!(jitter_lifetime(lifetime, Duration::ZERO, 0.9), lifetime);
+
+    let short = Duration::from_millis(1);
+    assert_eq!(jitter_lifetime(short, jitter, 0.0), Duration::ZERO);
+    assert_eq!(jitter_lifetime(short, jitter, 0.5), short);
+
+    // a jitter too large to be scaled exactly saturates instead of panicking
+    assert_eq!(
+        jitter_lifetime(lifetime, Duration::MAX, 0.0),
+        Duration::ZERO
+    );
+    assert!(jitter_lifetime(lifetime, Duration::MAX, 0.9) > lifetime);
+
+    let fractions: Vec<f64> = (0..100).map(|_| random_fraction()).collect();
+    assert!(fractions.iter().all(|f| (0.0..1.0).contains(f)));
+    assert!(fractions.iter().any(|&f| f != fractions[0]));
+}
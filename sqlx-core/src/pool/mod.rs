@@ -68,8 +68,9 @@ use crate::any::{Any, AnyKind};
 use crate::connection::Connection;
 use crate::database::Database;
 use crate::error::Error;
-use crate::transaction::Transaction;
+use crate::transaction::{Transaction, TransactionOptions};
 use event_listener::EventListener;
+use futures_core::future::BoxFuture;
 use futures_core::FusedFuture;
 use futures_util::FutureExt;
 use std::fmt;
@@ -243,6 +244,26 @@ pub use self::options::{PoolConnectionMetadata, PoolOptions};
 /// well and queries will generally benefit from these caches being "warm" (populated with data).
 pub struct Pool<DB: Database>(pub(crate) Arc<PoolInner<DB>>);
 
+/// A snapshot of the connection counts of a [`Pool`].
+///
+/// See [`Pool::status()`] for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PoolStatus {
+    /// The number of connections currently open, including idle ones. See [`Pool::size()`].
+    pub size: u32,
+
+    /// The number of open connections waiting in the pool. See [`Pool::num_idle()`].
+    pub idle: usize,
+
+    /// The number of open connections currently checked out of the pool.
+    pub in_use: u32,
+
+    /// The number of tasks blocked in [`Pool::acquire()`] and friends, waiting for a connection
+    /// to be released or for room to open a new one.
+    pub waiting: usize,
+}
+
 /// A future that resolves when the pool is closed.
 ///
 /// See [`Pool::close_event()`] for details.
@@ -319,7 +340,15 @@ impl<DB: Database> Pool<DB> {
     ///
     /// The total time this method is allowed to execute is capped by
     /// [`PoolOptions::acquire_timeout`].
-    /// If that timeout elapses, this will return [`Error::PoolClosed`].
+    /// If that timeout elapses, this will return [`Error::PoolTimedOut`].
+    ///
+    /// If a new connection has to be opened, which is always the case for the first call on a pool
+    /// created with [`PoolOptions::connect_lazy()`], errors that are likely to be temporary
+    /// (such as the connection being refused while the server is restarting) are retried until
+    /// the timeout. If it elapses while retrying, the error of the last attempt is returned
+    /// instead of [`Error::PoolTimedOut`], so the cause is available through
+    /// [`source()`][std::error::Error::source]. Other errors, such as a failure to resolve
+    /// the hostname or to authenticate, are returned right away.
     ///
     /// ### Note: Cancellation/Timeout May Drop Connections
     /// If `acquire` is cancelled or times out after it acquires a connection from the idle queue or
@@ -343,19 +372,140 @@ impl<DB: Database> Pool<DB> {
         async move { shared.acquire().await.map(|conn| conn.reattach()) }
     }
 
+    /// Retrieves a connection from the pool, waiting at most `timeout` for one to become
+    /// available.
+    ///
+    /// This behaves exactly like [`acquire`][Self::acquire], including the fair ordering of
+    /// waiters and the `max_connections` limit, except that `timeout` replaces
+    /// [`PoolOptions::acquire_timeout`] for this call only.
+    /// If the timeout elapses, this will return [`Error::PoolTimedOut`].
+    ///
+    /// A zero `timeout` means "try once, don't wait": an idle connection is returned if one is
+    /// immediately available, otherwise this returns [`Error::PoolTimedOut`] without queueing
+    /// behind other waiters.
+    pub fn acquire_timeout(
+        &self,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<PoolConnection<DB>, Error>> + 'static {
+        let shared = self.0.clone();
+        async move {
+            shared
+                .acquire_with_timeout(timeout)
+                .await
+                .map(|conn| conn.reattach())
+        }
+    }
+
     /// Attempts to retrieve a connection from the pool if there is one available.
     ///
     /// Returns `None` immediately if there are no idle connections available in the pool
     /// or there are tasks waiting for a connection which have yet to wake.
+    ///
+    /// This never opens a new connection; see
+    /// [`try_acquire_or_connect`][Self::try_acquire_or_connect] for that.
     pub fn try_acquire(&self) -> Option<PoolConnection<DB>> {
         self.0.try_acquire().map(|conn| conn.into_live().reattach())
     }
 
+    /// Attempts to retrieve a connection from the pool without waiting for one to be released,
+    /// opening a new one if none is idle and the pool has fewer than
+    /// [`max_connections`][PoolOptions::max_connections].
+    ///
+    /// Returns `Ok(None)` right away if the pool is at its limit with every connection in use,
+    /// or if there are tasks waiting for a connection which have yet to wake; this never lines
+    /// up behind them. Otherwise, an idle connection is checked as with
+    /// [`acquire`][Self::acquire], and opening a new connection may take up to
+    /// [`PoolOptions::acquire_timeout`], returning any error from connecting.
+    pub async fn try_acquire_or_connect(&self) -> Result<Option<PoolConnection<DB>>, Error> {
+        Ok(self
+            .0
+            .try_acquire_or_connect()
+            .await?
+            .map(|conn| conn.reattach()))
+    }
+
     /// Retrieves a connection and immediately begins a new transaction.
     pub async fn begin(&self) -> Result<Transaction<'static, DB>, Error> {
         Ok(Transaction::begin(MaybePoolConnection::PoolConnection(self.acquire().await?)).await?)
     }
 
+    /// Retrieves a connection and immediately begins a new transaction with the given isolation
+    /// level and access mode.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # #[cfg(feature = "postgres")]
+    /// # async fn example(
+    /// #     pool: sqlx_core::postgres::PgPool,
+    /// # ) -> Result<(), sqlx_core::error::Error> {
+    /// # use sqlx_core::transaction::{IsolationLevel, TransactionOptions};
+    /// let mut tx = pool
+    ///     .begin_with_options(TransactionOptions::new().isolation_level(IsolationLevel::Serializable))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn begin_with_options(
+        &self,
+        options: TransactionOptions,
+    ) -> Result<Transaction<'static, DB>, Error> {
+        Transaction::begin_with_options(
+            MaybePoolConnection::PoolConnection(self.acquire().await?),
+            options,
+        )
+        .await
+    }
+
+    /// Retrieves a connection, begins a new transaction and runs the function inside it.
+    ///
+    /// If the function returns an error, the transaction is rolled back and the error returned.
+    /// Otherwise the transaction is committed and the function's result returned.
+    ///
+    /// If the returned future is dropped before it completes, the transaction is dropped with it,
+    /// which rolls it back before the connection is reused.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # #[cfg(feature = "postgres")]
+    /// # async fn example(
+    /// #     pool: sqlx_core::postgres::PgPool,
+    /// # ) -> Result<(), sqlx_core::error::Error> {
+    /// # use sqlx_core::query::query;
+    /// pool.begin_with(|tx| Box::pin(async move {
+    ///     query("INSERT INTO users (name) VALUES ($1)")
+    ///         .bind("alice")
+    ///         .execute(&mut *tx)
+    ///         .await
+    /// }))
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn begin_with<F, R, E>(&self, callback: F) -> Result<R, E>
+    where
+        for<'c> F:
+            FnOnce(&'c mut Transaction<'static, DB>) -> BoxFuture<'c, Result<R, E>> + Send + Sync,
+        R: Send,
+        E: From<Error> + Send,
+    {
+        let mut transaction = self.begin().await?;
+
+        match callback(&mut transaction).await {
+            Ok(ret) => {
+                transaction.commit().await?;
+
+                Ok(ret)
+            }
+            Err(err) => {
+                transaction.rollback().await?;
+
+                Err(err)
+            }
+        }
+    }
+
     /// Attempts to retrieve a connection and immediately begins a new transaction if successful.
     pub async fn try_begin(&self) -> Result<Option<Transaction<'static, DB>>, Error> {
         match self.try_acquire() {
@@ -392,6 +542,20 @@ impl<DB: Database> Pool<DB> {
         self.0.close()
     }
 
+    /// Shut down the connection pool like [`.close()`][Pool::close], but stop waiting for
+    /// checked-out connections after `timeout`.
+    ///
+    /// This is useful during shutdown when a checked-out connection may be stuck, for example
+    /// on a long-running query, which would make `.close().await` wait indefinitely.
+    ///
+    /// When `timeout` elapses, any idle connections which have not been closed yet are dropped
+    /// without closing them gracefully, and the returned `Future` resolves. Connections which
+    /// are still checked out are left to their current owners; they will never be returned
+    /// to the pool, and are closed as soon as they are dropped.
+    pub fn close_timeout(&self, timeout: Duration) -> impl Future<Output = ()> + '_ {
+        self.0.close_timeout(timeout)
+    }
+
     /// Returns `true` if [`.close()`][Pool::close] has been called on the pool, `false` otherwise.
     pub fn is_closed(&self) -> bool {
         self.0.is_closed()
@@ -489,6 +653,29 @@ impl<DB: Database> Pool<DB> {
         self.0.num_idle()
     }
 
+    /// Returns the maximum number of connections this pool will maintain at once.
+    ///
+    /// See [`PoolOptions::max_connections`].
+    pub fn max_connections(&self) -> u32 {
+        self.0.options.max_connections
+    }
+
+    /// Returns a snapshot of the connection counts of this pool, e.g. to report as metrics.
+    ///
+    /// The counts are read one after the other without synchronization, so under load they may
+    /// not be exactly consistent with each other.
+    pub fn status(&self) -> PoolStatus {
+        let size = self.0.size();
+        let idle = self.0.num_idle();
+
+        PoolStatus {
+            size,
+            idle,
+            in_use: size.saturating_sub(idle as u32),
+            waiting: self.0.num_waiting(),
+        }
+    }
+
     /// Get the connection options for this pool
     pub fn connect_options(&self) -> &<DB::Connection as Connection>::Options {
         &self.0.connect_options
@@ -1,6 +1,7 @@
 use crate::connection::Connection;
 use crate::database::Database;
 use crate::error::Error;
+use crate::executor::Executor;
 use crate::pool::inner::PoolInner;
 use crate::pool::Pool;
 use futures_core::future::BoxFuture;
@@ -74,10 +75,16 @@ pub struct PoolOptions<DB: Database> {
                 + Sync,
         >,
     >,
+    pub(crate) preload_statements: Option<
+        Arc<
+            dyn Fn(&mut DB::Connection) -> BoxFuture<'_, Result<(), Error>> + 'static + Send + Sync,
+        >,
+    >,
     pub(crate) max_connections: u32,
     pub(crate) acquire_timeout: Duration,
     pub(crate) min_connections: u32,
     pub(crate) max_lifetime: Option<Duration>,
+    pub(crate) max_lifetime_jitter: Duration,
     pub(crate) idle_timeout: Option<Duration>,
     pub(crate) fair: bool,
 
@@ -98,6 +105,11 @@ pub struct PoolConnectionMetadata {
     /// Only relevant for [`before_acquire`][PoolOptions::before_acquire].
     /// For other callbacks, this is [`Duration::ZERO`].
     pub idle_for: Duration,
+
+    /// The lifetime after which the connection is closed, which is
+    /// [`max_lifetime`][PoolOptions::max_lifetime] with a random amount of
+    /// [`max_lifetime_jitter`][PoolOptions::max_lifetime_jitter] applied.
+    pub max_lifetime: Option<Duration>,
 }
 
 impl<DB: Database> Default for PoolOptions<DB> {
@@ -119,6 +131,7 @@ impl<DB: Database> PoolOptions<DB> {
             after_connect: None,
             before_acquire: None,
             after_release: None,
+            preload_statements: None,
             test_before_acquire: true,
             // A production application will want to set a higher limit than this.
             max_connections: 10,
@@ -126,6 +139,7 @@ impl<DB: Database> PoolOptions<DB> {
             acquire_timeout: Duration::from_secs(30),
             idle_timeout: Some(Duration::from_secs(10 * 60)),
             max_lifetime: Some(Duration::from_secs(30 * 60)),
+            max_lifetime_jitter: Duration::ZERO,
             fair: true,
             parent_pool: None,
         }
@@ -205,6 +219,19 @@ impl<DB: Database> PoolOptions<DB> {
         self
     }
 
+    /// Spread out the lifetimes of connections by up to `jitter` either side of
+    /// [`max_lifetime`][Self::max_lifetime], chosen at random for each connection.
+    ///
+    /// Connections opened at the same time, such as when the pool first fills up, would
+    /// otherwise all be closed and reopened at the same time too. A jitter longer than the
+    /// maximum lifetime does not make a lifetime any shorter than zero.
+    ///
+    /// Defaults to zero, for no jitter.
+    pub fn max_lifetime_jitter(mut self, jitter: Duration) -> Self {
+        self.max_lifetime_jitter = jitter;
+        self
+    }
+
     /// Set a maximum idle duration for individual connections.
     ///
     /// Any connection that remains in the idle queue longer than this will be closed.
@@ -218,6 +245,16 @@ impl<DB: Database> PoolOptions<DB> {
     /// If true, the health of a connection will be verified by a call to [`Connection::ping`]
     /// before returning the connection.
     ///
+    /// If the ping fails, for example because the server restarted or a proxy dropped the
+    /// idle socket, the connection is discarded and [`Pool::acquire()`] transparently opens a
+    /// new one in its place. Transport errors while opening that connection (connection
+    /// refused or reset, broken pipe, unexpected EOF) are retried with backoff until
+    /// [`acquire_timeout`][Self::acquire_timeout] expires; any other error, such as
+    /// an authentication failure, is returned immediately.
+    ///
+    /// Queries themselves are never retried, as that is not safe for non-idempotent statements;
+    /// an error on a connection that breaks *after* it was acquired is returned to the caller.
+    ///
     /// Defaults to `true`.
     pub fn test_before_acquire(mut self, test: bool) -> Self {
         self.test_before_acquire = test;
@@ -403,6 +440,65 @@ impl<DB: Database> PoolOptions<DB> {
         self
     }
 
+    /// Prepare the given statements on every new connection, after
+    /// [`after_connect`][Self::after_connect], so that they are already in the connection's
+    /// statement cache the first time they are executed.
+    ///
+    /// The statements are cached by their SQL, which must match that of the queries executed
+    /// later exactly.
+    ///
+    /// The statements are prepared without any parameter types, so the database infers them
+    /// from the SQL alone. Because the cache is keyed by the SQL, the inferred types then stay
+    /// pinned for every later execution of that query on the connection, whatever the values
+    /// bound to it. On PostgreSQL, for example, `SELECT $1` is prepared with a `TEXT` parameter,
+    /// and an `i32` bound to it afterwards is sent to the server as if it were text. Cast each
+    /// parameter in the SQL, as in `$1::int4`, so that the inferred types are the ones you
+    /// intend to bind.
+    ///
+    /// Unlike an error returned from `after_connect`, a statement that fails to prepare is not
+    /// retried, as it would fail again on any connection. Instead, the error is returned
+    /// straight away from [`connect`][Self::connect] or whichever call opened the connection.
+    ///
+    /// ```no_run
+    /// # async fn f() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sqlx::postgres::PgPoolOptions;
+    ///
+    /// let pool = PgPoolOptions::new()
+    ///     .preload_statements(["SELECT name FROM users WHERE id = $1"])
+    ///     .connect("postgres:// …").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn preload_statements<I>(self, statements: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+        for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    {
+        let statements: Arc<[String]> = statements.into_iter().map(Into::into).collect();
+
+        self.preload_with(move |conn| {
+            let statements = Arc::clone(&statements);
+
+            Box::pin(async move {
+                for sql in statements.iter() {
+                    conn.prepare(sql).await?;
+                }
+
+                Ok(())
+            })
+        })
+    }
+
+    fn preload_with<F>(mut self, callback: F) -> Self
+    where
+        for<'c> F:
+            Fn(&'c mut DB::Connection) -> BoxFuture<'c, Result<(), Error>> + 'static + Send + Sync,
+    {
+        self.preload_statements = Some(Arc::new(callback));
+        self
+    }
+
     /// Set the parent `Pool` from which the new pool will inherit its semaphore.
     ///
     /// This is currently an internal-only API.
@@ -492,6 +588,7 @@ impl<DB: Database> Debug for PoolOptions<DB> {
             .field("min_connections", &self.min_connections)
             .field("connect_timeout", &self.acquire_timeout)
             .field("max_lifetime", &self.max_lifetime)
+            .field("max_lifetime_jitter", &self.max_lifetime_jitter)
             .field("idle_timeout", &self.idle_timeout)
             .field("test_before_acquire", &self.test_before_acquire)
             .finish()
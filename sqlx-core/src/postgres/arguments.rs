@@ -1,10 +1,17 @@
+use std::cmp;
 use std::fmt::{self, Write};
+use std::iter;
 use std::ops::{Deref, DerefMut};
+use std::str;
+
+use byteorder::{BigEndian, ByteOrder};
 
 use crate::arguments::Arguments;
 use crate::encode::{Encode, IsNull};
 use crate::error::Error;
 use crate::ext::ustr::UStr;
+use crate::logger::LoggedParam;
+use crate::postgres::type_info::PgType;
 use crate::postgres::{PgConnection, PgTypeInfo, Postgres};
 use crate::types::Type;
 
@@ -73,6 +80,74 @@ impl PgArguments {
         self.buffer.count += 1;
     }
 
+    // Encoded values of each bind parameter, or `None` for NULL
+    fn values(&self) -> impl Iterator<Item = Option<&[u8]>> {
+        // each value is written to the buffer as a length-prefixed byte string in its
+        // binary encoding, with a length of -1 for NULL
+        let mut buf = &self.buffer.buffer[..];
+
+        iter::from_fn(move || {
+            if buf.len() < 4 {
+                return None;
+            }
+
+            let len = i32::from_be_bytes([buf[0], buf[1], buf[2], buf[3]]);
+            buf = &buf[4..];
+
+            if len < 0 {
+                return Some(None);
+            }
+
+            let (value, rest) = buf.split_at(cmp::min(len as usize, buf.len()));
+            buf = rest;
+
+            Some(Some(value))
+        })
+    }
+
+    pub(crate) fn logged_values(&self) -> impl Iterator<Item = LoggedParam<'_>> {
+        self.types.iter().zip(self.values()).map(|(ty, value)| {
+            let value = match value {
+                Some(value) => value,
+                None => return LoggedParam::Null,
+            };
+
+            let number = match (&ty.0, value.len()) {
+                (PgType::Bool, 1) => Some((value[0] != 0).to_string()),
+                (PgType::Int2, 2) => Some(BigEndian::read_i16(value).to_string()),
+                (PgType::Int4, 4) => Some(BigEndian::read_i32(value).to_string()),
+                (PgType::Int8, 8) => Some(BigEndian::read_i64(value).to_string()),
+                (PgType::Float4, 4) => Some(BigEndian::read_f32(value).to_string()),
+                (PgType::Float8, 8) => Some(BigEndian::read_f64(value).to_string()),
+                _ => None,
+            };
+
+            if let Some(number) = number {
+                return LoggedParam::Other(number);
+            }
+
+            match (&ty.0, str::from_utf8(value)) {
+                (
+                    PgType::Text
+                    | PgType::Varchar
+                    | PgType::Bpchar
+                    | PgType::Name
+                    | PgType::Json
+                    | PgType::Unknown,
+                    Ok(text),
+                ) => LoggedParam::Text(text),
+
+                _ => LoggedParam::Bytes(value),
+            }
+        })
+    }
+
+    // the server would reject the [Bind] with an error that refers to the statement by its
+    // generated name; each placeholder counts once, however often it is used
+    pub(crate) fn check_parameter_count(&self, parameters: &[PgTypeInfo]) -> Result<(), Error> {
+        crate::arguments::check_parameter_count(parameters.len(), self.types.len())
+    }
+
     // Apply patches
     // This should only go out and ask postgres if we have not seen the type name yet
     pub(crate) async fn apply_patches(
@@ -123,6 +198,14 @@ impl<'q> Arguments<'q> for PgArguments {
     }
 }
 
+impl fmt::Debug for PgArguments {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.types.iter().zip(self.values()))
+            .finish()
+    }
+}
+
 impl PgArgumentBuffer {
     pub(crate) fn encode<'q, T>(&mut self, value: T)
     where
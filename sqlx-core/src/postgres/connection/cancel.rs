@@ -0,0 +1,99 @@
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+
+use bytes::Bytes;
+
+use crate::error::Error;
+use crate::postgres::connection::{stream::PgStream, tls};
+use crate::postgres::message::CancelRequest;
+use crate::postgres::{PgConnectOptions, PgConnection};
+
+// https://www.postgresql.org/docs/current/protocol-flow.html#id-1.10.5.7.9
+
+/// A handle to cancel the statement currently executing on a [`PgConnection`].
+///
+/// Created by [`PgConnection::cancel_handle()`]. The handle does not borrow the connection,
+/// so it can be sent to another task and used while the connection is busy.
+#[derive(Clone)]
+pub struct PgCancelHandle {
+    options: Arc<PgConnectOptions>,
+    process_id: u32,
+    secret_key: u32,
+}
+
+impl PgCancelHandle {
+    /// The process ID of the backend that this handle would cancel the statement of.
+    pub fn process_id(&self) -> u32 {
+        self.process_id
+    }
+
+    /// Ask the server to cancel the statement currently executing on the connection.
+    ///
+    /// This opens a separate connection to the server, using the same options as the original
+    /// connection, and sends a `CancelRequest` on it.
+    ///
+    /// The server does not report whether the cancellation succeeded. If it did, the statement
+    /// that was executing fails with a [`Database`][Error::Database] error with the SQLSTATE code
+    /// `57014` (`query_canceled`). If the connection was idle, or has moved on to a different
+    /// statement by the time the request is processed, the request has no effect
+    /// or may cancel that statement instead.
+    pub async fn cancel(&self) -> Result<(), Error> {
+        let mut stream = PgStream::connect(&self.options).await?;
+
+        tls::maybe_upgrade(&mut stream, &self.options).await?;
+
+        stream
+            .send(CancelRequest {
+                process_id: self.process_id,
+                secret_key: self.secret_key,
+            })
+            .await?;
+
+        // the server closes the connection without a response once it has processed the request
+        match stream.read::<Bytes>(1).await {
+            Ok(_) => Err(err_protocol!("unexpected response to a cancel request")),
+            Err(error) if error.is_transport() => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl Debug for PgCancelHandle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // the secret key is deliberately left out
+        f.debug_struct("PgCancelHandle")
+            .field("process_id", &self.process_id)
+            .finish()
+    }
+}
+
+impl PgConnection {
+    /// Get a handle that can be used to cancel the statement currently executing on this
+    /// connection, from another task.
+    ///
+    /// ```rust,no_run
+    /// # use sqlx_core::error::Error;
+    /// # use sqlx_core::executor::Executor;
+    /// # use sqlx_core::postgres::PgConnection;
+    /// # async fn example(mut conn: PgConnection) -> Result<(), Error> {
+    /// let cancel = conn.cancel_handle();
+    ///
+    /// sqlx_rt::spawn(async move {
+    ///     sqlx_rt::sleep(std::time::Duration::from_secs(1)).await;
+    ///     cancel.cancel().await
+    /// });
+    ///
+    /// // fails with SQLSTATE 57014 (`query_canceled`) after a second
+    /// let res = conn.execute("SELECT pg_sleep(30)").await;
+    /// assert!(res.is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cancel_handle(&self) -> PgCancelHandle {
+        PgCancelHandle {
+            options: Arc::clone(&self.options),
+            process_id: self.process_id,
+            secret_key: self.secret_key,
+        }
+    }
+}
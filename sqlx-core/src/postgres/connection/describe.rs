@@ -343,13 +343,20 @@ WHERE rngtypid = $1
             return Ok(*oid);
         }
 
+        // `_` and `%` are wildcards for `ILIKE`; array types are named with a
+        // leading underscore (e.g., `_inventory_item`) so these must be escaped
+        let pattern = name
+            .replace('\\', "\\\\")
+            .replace('_', "\\_")
+            .replace('%', "\\%");
+
         // language=SQL
         let (oid,): (Oid,) = query_as(
             "
 SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
                 ",
         )
-        .bind(name)
+        .bind(pattern)
         .fetch_optional(&mut *self)
         .await?
         .ok_or_else(|| Error::TypeNotFound {
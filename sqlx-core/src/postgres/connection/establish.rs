@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use crate::HashMap;
 
 use crate::common::StatementCache;
@@ -8,7 +10,7 @@ use crate::postgres::message::{
     Authentication, BackendKeyData, MessageFormat, Password, ReadyForQuery, Startup,
 };
 use crate::postgres::types::Oid;
-use crate::postgres::{PgConnectOptions, PgConnection};
+use crate::postgres::{PgChannelBinding, PgConnectOptions, PgConnection};
 
 // https://www.postgresql.org/docs/current/protocol-flow.html#id-1.10.5.7.3
 // https://www.postgresql.org/docs/current/protocol-flow.html#id-1.10.5.7.11
@@ -61,6 +63,7 @@ impl PgConnection {
 
         let mut process_id = 0;
         let mut secret_key = 0;
+        let mut channel_bound = false;
         let transaction_status;
 
         loop {
@@ -70,9 +73,15 @@ impl PgConnection {
                     Authentication::Ok => {
                         // the authentication exchange is successfully completed
                         // do nothing; no more information is required to continue
+
+                        if !channel_bound {
+                            ensure_channel_binding_not_required(options)?;
+                        }
                     }
 
                     Authentication::CleartextPassword => {
+                        ensure_channel_binding_not_required(options)?;
+
                         // The frontend must now send a [PasswordMessage] containing the
                         // password in clear-text form.
 
@@ -89,6 +98,8 @@ impl PgConnection {
                         // using the 4-byte random salt specified in the
                         // [AuthenticationMD5Password] message.
 
+                        ensure_channel_binding_not_required(options)?;
+
                         stream
                             .send(Password::Md5 {
                                 username: &options.username,
@@ -99,7 +110,7 @@ impl PgConnection {
                     }
 
                     Authentication::Sasl(body) => {
-                        sasl::authenticate(&mut stream, options, body).await?;
+                        channel_bound = sasl::authenticate(&mut stream, options, body).await?;
                     }
 
                     method => {
@@ -141,9 +152,12 @@ impl PgConnection {
             stream,
             process_id,
             secret_key,
+            options: Arc::new(options.clone()),
             transaction_status,
             transaction_depth: 0,
+            deferred_savepoint_release: None,
             pending_ready_for_query_count: 0,
+            pending_portal_sync: false,
             next_statement_id: Oid(1),
             cache_statement: StatementCache::new(options.statement_cache_capacity),
             cache_type_oid: HashMap::new(),
@@ -152,3 +166,15 @@ impl PgConnection {
         })
     }
 }
+
+// with channel binding required, a server must not be trusted with the password, or with the
+// connection at all, unless it authenticates itself by channel binding
+fn ensure_channel_binding_not_required(options: &PgConnectOptions) -> Result<(), Error> {
+    if options.channel_binding == PgChannelBinding::Require {
+        return Err(Error::Tls(
+            "channel binding is required, but the server did not authenticate with it".into(),
+        ));
+    }
+
+    Ok(())
+}
@@ -29,19 +29,21 @@ async fn prepare(
     let id = conn.next_statement_id;
     conn.next_statement_id.incr_one();
 
-    // build a list of type OIDs to send to the database in the PARSE command
-    // we have not yet started the query sequence, so we are *safe* to cleanly make
-    // additional queries here to get any missing OIDs
+    let metadata = parse(conn, sql, parameters, Some(id), metadata).await?;
 
-    let mut param_types = Vec::with_capacity(parameters.len());
+    Ok((id, metadata))
+}
 
-    for ty in parameters {
-        param_types.push(if let PgType::DeclareWithName(name) = &ty.0 {
-            conn.fetch_type_id_by_name(name).await?
-        } else {
-            ty.0.oid()
-        });
-    }
+// parse the query as the given statement, or as the unnamed statement if there is none, and
+// describe it if we do not have its metadata yet
+async fn parse(
+    conn: &mut PgConnection,
+    sql: &str,
+    parameters: &[PgTypeInfo],
+    statement: Option<Oid>,
+    metadata: Option<Arc<PgStatementMetadata>>,
+) -> Result<Arc<PgStatementMetadata>, Error> {
+    let param_types = param_types(conn, parameters).await?;
 
     // flush and wait until we are re-ready
     conn.wait_until_ready().await?;
@@ -50,12 +52,15 @@ async fn prepare(
     conn.stream.write(Parse {
         param_types: &*param_types,
         query: sql,
-        statement: id,
+        statement,
     });
 
     if metadata.is_none() {
         // get the statement columns and parameters
-        conn.stream.write(message::Describe::Statement(id));
+        conn.stream.write(match statement {
+            Some(id) => message::Describe::Statement(id),
+            None => message::Describe::UnnamedStatement,
+        });
     }
 
     // we ask for the server to immediately send us the result of the PARSE command
@@ -97,7 +102,27 @@ async fn prepare(
         })
     };
 
-    Ok((id, metadata))
+    Ok(metadata)
+}
+
+// build a list of type OIDs to send to the database in the PARSE command
+// we have not yet started the query sequence, so we are *safe* to cleanly make
+// additional queries here to get any missing OIDs
+async fn param_types(
+    conn: &mut PgConnection,
+    parameters: &[PgTypeInfo],
+) -> Result<Vec<Oid>, Error> {
+    let mut param_types = Vec::with_capacity(parameters.len());
+
+    for ty in parameters {
+        param_types.push(if let PgType::DeclareWithName(name) = &ty.0 {
+            conn.fetch_type_id_by_name(name).await?
+        } else {
+            ty.0.oid()
+        });
+    }
+
+    Ok(param_types)
 }
 
 async fn recv_desc_params(conn: &mut PgConnection) -> Result<ParameterDescription, Error> {
@@ -160,7 +185,7 @@ impl PgConnection {
         self.pending_ready_for_query_count += 1;
     }
 
-    async fn get_or_prepare<'a>(
+    pub(super) async fn get_or_prepare(
         &mut self,
         sql: &str,
         parameters: &[PgTypeInfo],
@@ -196,10 +221,16 @@ impl PgConnection {
         query: &'q str,
         arguments: Option<PgArguments>,
         limit: u8,
+        fetch_size: u32,
         persistent: bool,
         metadata_opt: Option<Arc<PgStatementMetadata>>,
     ) -> Result<impl Stream<Item = Result<Either<PgQueryResult, PgRow>, Error>> + 'e, Error> {
-        let mut logger = QueryLogger::new(query, self.log_settings.clone());
+        let mut logger = QueryLogger::new(query, "postgresql", self.log_settings.clone());
+
+        if let Some(arguments) = &arguments {
+            logger.record_params(arguments);
+            logger.record_param_values(arguments.logged_values());
+        }
 
         // before we continue, wait until we are "ready" to accept more queries
         self.wait_until_ready().await?;
@@ -207,16 +238,49 @@ impl PgConnection {
         let mut metadata: Arc<PgStatementMetadata>;
 
         let format = if let Some(mut arguments) = arguments {
-            // prepare the statement if this our first time executing it
-            // always return the statement ID here
-            let (statement, metadata_) = self
-                .get_or_prepare(query, &arguments.types, persistent, metadata_opt)
-                .await?;
+            let statement = if self.options.prepared_statements {
+                // prepare the statement if this our first time executing it
+                // always return the statement ID here
+                let (statement, metadata_) = self
+                    .get_or_prepare(query, &arguments.types, persistent, metadata_opt)
+                    .await?;
+
+                metadata = metadata_;
+
+                arguments.check_parameter_count(&metadata.parameters)?;
+
+                // patch holes created during encoding
+                arguments.apply_patches(self, &metadata.parameters).await?;
+
+                Some(statement)
+            } else {
+                // describe the query first, as the types of its columns that we do not know
+                // yet can not be fetched once the rows are coming in
+                metadata = match metadata_opt {
+                    Some(metadata) => metadata,
+                    None => parse(self, query, &arguments.types, None, None).await?,
+                };
 
-            metadata = metadata_;
+                arguments.check_parameter_count(&metadata.parameters)?;
 
-            // patch holes created during encoding
-            arguments.apply_patches(self, &metadata.parameters).await?;
+                // patch holes created during encoding
+                arguments.apply_patches(self, &metadata.parameters).await?;
+
+                let param_types = param_types(self, &arguments.types).await?;
+
+                self.wait_until_ready().await?;
+
+                // parse the query again as the unnamed statement, which lives until the next
+                // one is parsed; it is bound and executed before the [Sync] below, so that
+                // a pooler can not hand the server connection to someone else in between
+                self.stream.write(Parse {
+                    param_types: &*param_types,
+                    query,
+                    statement: None,
+                });
+
+                None
+            };
 
             // consume messages till `ReadyForQuery` before bind and execute
             self.wait_until_ready().await?;
@@ -231,32 +295,47 @@ impl PgConnection {
                 result_formats: &[PgValueFormat::Binary],
             });
 
-            // executes the portal up to the passed limit
-            // the protocol-level limit acts nearly identically to the `LIMIT` in SQL
-            self.stream.write(message::Execute {
-                portal: None,
-                limit: limit.into(),
-            });
-            // From https://www.postgresql.org/docs/current/protocol-flow.html:
-            //
-            // "An unnamed portal is destroyed at the end of the transaction, or as
-            // soon as the next Bind statement specifying the unnamed portal as
-            // destination is issued. (Note that a simple Query message also
-            // destroys the unnamed portal."
-
-            // we ask the database server to close the unnamed portal and free the associated resources
-            // earlier - after the execution of the current query.
-            self.stream.write(message::Close::Portal(None));
-
-            // finally, [Sync] asks postgres to process the messages that we sent and respond with
-            // a [ReadyForQuery] message when it's completely done. Theoretically, we could send
-            // dozens of queries before a [Sync] and postgres can handle that. Execution on the server
-            // is still serial but it would reduce round-trips. Some kind of builder pattern that is
-            // termed batching might suit this.
-            self.write_sync();
-
-            // prepared statements are binary
-            PgValueFormat::Binary
+            if fetch_size > 0 {
+                // executes the portal one chunk at a time; the portal would be destroyed by
+                // a [Sync], so we [Flush] instead and ask for the next chunk once this one
+                // has been consumed (see `PortalSuspended` below)
+                self.stream.write(message::Execute {
+                    portal: None,
+                    limit: fetch_size,
+                });
+                self.stream.write(message::Flush);
+                self.pending_portal_sync = true;
+
+                // prepared statements are binary
+                PgValueFormat::Binary
+            } else {
+                // executes the portal up to the passed limit
+                // the protocol-level limit acts nearly identically to the `LIMIT` in SQL
+                self.stream.write(message::Execute {
+                    portal: None,
+                    limit: limit.into(),
+                });
+                // From https://www.postgresql.org/docs/current/protocol-flow.html:
+                //
+                // "An unnamed portal is destroyed at the end of the transaction, or as
+                // soon as the next Bind statement specifying the unnamed portal as
+                // destination is issued. (Note that a simple Query message also
+                // destroys the unnamed portal."
+
+                // we ask the database server to close the unnamed portal and free the associated resources
+                // earlier - after the execution of the current query.
+                self.stream.write(message::Close::Portal(None));
+
+                // finally, [Sync] asks postgres to process the messages that we sent and respond with
+                // a [ReadyForQuery] message when it's completely done. Theoretically, we could send
+                // dozens of queries before a [Sync] and postgres can handle that. Execution on the server
+                // is still serial but it would reduce round-trips. Some kind of builder pattern that is
+                // termed batching might suit this.
+                self.write_sync();
+
+                // prepared statements are binary
+                PgValueFormat::Binary
+            }
         } else {
             // Query will trigger a ReadyForQuery
             self.stream.write(Query(query));
@@ -271,7 +350,9 @@ impl PgConnection {
 
         self.stream.flush().await?;
 
-        Ok(try_stream! {
+        let span = logger.span();
+
+        Ok(span.instrument(try_stream! {
             loop {
                 let message = self.stream.recv().await?;
 
@@ -294,6 +375,12 @@ impl PgConnection {
                         // a SQL command completed normally
                         let cc: CommandComplete = message.decode()?;
 
+                        // a portal executed in chunks has now completed
+                        if self.pending_portal_sync {
+                            self.write_portal_sync();
+                            self.stream.flush().await?;
+                        }
+
                         let rows_affected = cc.rows_affected();
                         logger.increase_rows_affected(rows_affected);
                         r#yield!(Either::Left(PgQueryResult {
@@ -303,12 +390,27 @@ impl PgConnection {
 
                     MessageFormat::EmptyQueryResponse => {
                         // empty query string passed to an unprepared execute
+                        if self.pending_portal_sync {
+                            self.write_portal_sync();
+                            self.stream.flush().await?;
+                        }
                     }
 
                     // Message::ErrorResponse is handled in self.stream.recv()
 
                     // incomplete query execution has finished
-                    MessageFormat::PortalSuspended => {}
+                    MessageFormat::PortalSuspended => {
+                        if self.pending_portal_sync {
+                            // every row of the previous chunk has been consumed,
+                            // ask for the next one
+                            self.stream.write(message::Execute {
+                                portal: None,
+                                limit: fetch_size,
+                            });
+                            self.stream.write(message::Flush);
+                            self.stream.flush().await?;
+                        }
+                    }
 
                     MessageFormat::RowDescription => {
                         // indicates that a *new* set of rows are about to be returned
@@ -353,7 +455,7 @@ impl PgConnection {
             }
 
             Ok(())
-        })
+        }))
     }
 }
 
@@ -374,7 +476,34 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
         let persistent = query.persistent();
 
         Box::pin(try_stream! {
-            let s = self.run(sql, arguments, 0, persistent, metadata).await?;
+            let s = self.run(sql, arguments, 0, 0, persistent, metadata).await?;
+            pin_mut!(s);
+
+            while let Some(v) = s.try_next().await? {
+                r#yield!(v);
+            }
+
+            Ok(())
+        })
+    }
+
+    fn fetch_many_with_size<'e, 'q: 'e, E: 'q>(
+        self,
+        mut query: E,
+        size: usize,
+    ) -> BoxStream<'e, Result<Either<PgQueryResult, PgRow>, Error>>
+    where
+        'c: 'e,
+        E: Execute<'q, Self::Database>,
+    {
+        let sql = query.sql();
+        let metadata = query.statement().map(|s| Arc::clone(&s.metadata));
+        let arguments = query.take_arguments();
+        let persistent = query.persistent();
+        let fetch_size = u32::try_from(size).unwrap_or(u32::MAX);
+
+        Box::pin(try_stream! {
+            let s = self.run(sql, arguments, 0, fetch_size, persistent, metadata).await?;
             pin_mut!(s);
 
             while let Some(v) = s.try_next().await? {
@@ -399,7 +528,7 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
         let persistent = query.persistent();
 
         Box::pin(async move {
-            let s = self.run(sql, arguments, 1, persistent, metadata).await?;
+            let s = self.run(sql, arguments, 1, 0, persistent, metadata).await?;
             pin_mut!(s);
 
             while let Some(s) = s.try_next().await? {
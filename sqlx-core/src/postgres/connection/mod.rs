@@ -10,19 +10,24 @@ use crate::connection::{Connection, LogSettings};
 use crate::error::Error;
 use crate::ext::ustr::UStr;
 use crate::io::Decode;
+use crate::net::TlsInfo;
 use crate::postgres::message::{
     Close, Message, MessageFormat, Query, ReadyForQuery, Terminate, TransactionStatus,
 };
 use crate::postgres::statement::PgStatementMetadata;
 use crate::postgres::types::Oid;
 use crate::postgres::{PgConnectOptions, PgTypeInfo, Postgres};
-use crate::transaction::Transaction;
+use crate::transaction::{Transaction, TransactionOptions};
+use crate::types::Type;
 
+pub use self::cancel::PgCancelHandle;
 pub use self::stream::PgStream;
 
+mod cancel;
 pub(crate) mod describe;
 mod establish;
 mod executor;
+mod pipeline;
 mod sasl;
 mod stream;
 mod tls;
@@ -36,14 +41,16 @@ pub struct PgConnection {
 
     // process id of this backend
     // used to send cancel requests
-    #[allow(dead_code)]
     process_id: u32,
 
     // secret key of this backend
     // used to send cancel requests
-    #[allow(dead_code)]
     secret_key: u32,
 
+    // options this connection was established with
+    // used to open a separate connection for cancel requests
+    options: Arc<PgConnectOptions>,
+
     // sequence of statement IDs for use in preparing statements
     // in PostgreSQL, the statement is prepared to a user-supplied identifier
     next_statement_id: Oid,
@@ -58,21 +65,98 @@ pub struct PgConnection {
     // number of ReadyForQuery messages that we are currently expecting
     pub(crate) pending_ready_for_query_count: usize,
 
+    // set while the unnamed portal is being executed in chunks,
+    // the closing `Sync` is sent once it completes or the next time we need the connection
+    pub(crate) pending_portal_sync: bool,
+
     // current transaction status
-    transaction_status: TransactionStatus,
+    pub(crate) transaction_status: TransactionStatus,
     pub(crate) transaction_depth: usize,
 
+    // index of the outermost savepoint committed with `commit_with_savepoint_release` but not
+    // released yet
+    pub(crate) deferred_savepoint_release: Option<usize>,
+
     log_settings: LogSettings,
 }
 
 impl PgConnection {
-    /// the version number of the server in `libpq` format
+    /// The version number of the server in `libpq` format, e.g. `150002` for 15.2 and `90603`
+    /// for 9.6.3, parsed from the `server_version` parameter it reported.
+    ///
+    /// Any suffix such as in `14.2 (Debian 14.2-1.pgdg110+1)` is ignored. This is `None` if the
+    /// version could not be parsed.
     pub fn server_version_num(&self) -> Option<u32> {
         self.stream.server_version_num
     }
 
+    /// The parameters of the TLS session of this connection, or `None` if it is not encrypted.
+    ///
+    /// See [`PgConnectOptions::ssl_mode`][crate::postgres::PgConnectOptions::ssl_mode] to
+    /// require TLS.
+    pub fn tls_info(&self) -> Option<TlsInfo> {
+        self.stream.tls_info()
+    }
+
+    /// Treat values of the type named `name`, such as one defined by an extension, as values of
+    /// `T` on this connection.
+    ///
+    /// The OID of the type is looked up in `pg_type` once, and columns of the type are then
+    /// described as `T::type_info()`, so that they can be decoded as `T`. This is only correct
+    /// if the type has the same binary representation as that of `T`; for instance, `citext`
+    /// can be read as [`String`] like `TEXT`:
+    ///
+    /// ```rust,no_run
+    /// # use sqlx_core::error::Error;
+    /// # use sqlx_core::postgres::PgConnection;
+    /// # use sqlx_core::query_scalar::query_scalar;
+    /// # async fn example(conn: &mut PgConnection) -> Result<(), Error> {
+    /// conn.register_type::<String>("citext").await?;
+    ///
+    /// let email: String = query_scalar("SELECT email FROM users")
+    ///     .fetch_one(&mut *conn)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Statements already prepared on this connection keep the types they were described with,
+    /// so this should be called right after connecting, e.g. in
+    /// [`PoolOptions::after_connect`][crate::pool::PoolOptions::after_connect].
+    ///
+    /// Returns [`Error::TypeNotFound`] if there is no type named `name` in the database.
+    pub async fn register_type<T>(&mut self, name: &str) -> Result<(), Error>
+    where
+        T: Type<Postgres>,
+    {
+        let oid = self.fetch_type_id_by_name(name).await?;
+
+        self.cache_type_info.insert(oid, T::type_info());
+
+        Ok(())
+    }
+
+    /// The settings used to log the statements executed on this connection.
+    pub fn log_settings(&self) -> &LogSettings {
+        &self.log_settings
+    }
+
+    /// Change how the statements executed on this connection are logged,
+    /// starting with the next statement.
+    ///
+    /// This overrides the settings from [`ConnectOptions::log_statements`] and
+    /// [`ConnectOptions::log_slow_statements`] for this connection only.
+    ///
+    /// [`ConnectOptions::log_statements`]: crate::connection::ConnectOptions::log_statements
+    /// [`ConnectOptions::log_slow_statements`]: crate::connection::ConnectOptions::log_slow_statements
+    pub fn set_log_settings(&mut self, settings: LogSettings) {
+        self.log_settings = settings;
+    }
+
     // will return when the connection is ready for another query
     pub(in crate::postgres) async fn wait_until_ready(&mut self) -> Result<(), Error> {
+        self.write_portal_sync();
+
         if !self.stream.wbuf.is_empty() {
             self.stream.flush().await?;
         }
@@ -107,10 +191,20 @@ impl PgConnection {
         Ok(())
     }
 
+    /// Close a portal that was left suspended by a chunked fetch, if any.
+    pub(in crate::postgres) fn write_portal_sync(&mut self) {
+        if self.pending_portal_sync {
+            self.pending_portal_sync = false;
+            self.stream.write(Close::Portal(None));
+            self.write_sync();
+        }
+    }
+
     /// Queue a simple query (not prepared) to execute the next time this connection is used.
     ///
     /// Used for rolling back transactions and releasing advisory locks.
     pub(crate) fn queue_simple_query(&mut self, query: &str) {
+        self.write_portal_sync();
         self.pending_ready_for_query_count += 1;
         self.stream.write(Query(query));
     }
@@ -169,6 +263,16 @@ impl Connection for PgConnection {
         Transaction::begin(self)
     }
 
+    fn begin_with_options(
+        &mut self,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<Transaction<'_, Self::Database>, Error>>
+    where
+        Self: Sized,
+    {
+        Transaction::begin_with_options(self, options)
+    }
+
     fn cached_statements_size(&self) -> usize {
         self.cache_statement.len()
     }
@@ -196,6 +300,10 @@ impl Connection for PgConnection {
         })
     }
 
+    fn shrink_buffers(&mut self) {
+        self.stream.shrink_buffers();
+    }
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         self.wait_until_ready().boxed()
@@ -0,0 +1,210 @@
+use std::sync::Arc;
+
+use crate::error::Error;
+use crate::executor::Execute;
+use crate::logger::QueryLogger;
+use crate::postgres::message::{self, Bind, CommandComplete, MessageFormat, Query};
+use crate::postgres::{PgConnection, PgQueryResult, PgValueFormat, Postgres};
+
+// https://www.postgresql.org/docs/current/protocol-flow.html#PROTOCOL-FLOW-PIPELINING
+
+impl PgConnection {
+    /// Execute a sequence of queries without waiting for the result of each one before sending
+    /// the next, and return the result of each query in order.
+    ///
+    /// This saves a round trip to the server per query, which makes a difference for batches of
+    /// small, independent queries such as inserts. The statements still have to be prepared
+    /// first; those that are persistent and executed before are taken from the cache.
+    ///
+    /// Every statement in the pipeline is prepared before any of the queries are executed, so
+    /// a query cannot depend on a schema change made earlier in the same pipeline. For example,
+    /// an insert into a table created by a previous query fails to prepare, as the table does
+    /// not exist yet, and its result is that error. Execute such statements before the pipeline.
+    ///
+    /// Each query is followed by its own `Sync`, so that when one fails, the queries after it
+    /// are still executed and their results are still reported, unless they run in the same
+    /// transaction: outside of an explicit transaction, each query is committed on its own.
+    /// Any rows returned by the queries are discarded, as with
+    /// [`Executor::execute`][crate::executor::Executor::execute].
+    ///
+    /// The outer `Result` is an error if the connection itself failed, in which case the
+    /// results of the queries are lost.
+    ///
+    /// ```rust,no_run
+    /// # use sqlx_core::error::Error;
+    /// # use sqlx_core::postgres::PgConnection;
+    /// # use sqlx_core::query::query;
+    /// # async fn example(conn: &mut PgConnection) -> Result<(), Error> {
+    /// let queries = (0..100).map(|i| query("INSERT INTO users (id) VALUES ($1)").bind(i));
+    ///
+    /// for result in conn.pipeline(queries).await? {
+    ///     println!("inserted {} rows", result?.rows_affected());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn pipeline<'q, E>(
+        &mut self,
+        queries: impl IntoIterator<Item = E>,
+    ) -> Result<Vec<Result<PgQueryResult, Error>>, Error>
+    where
+        E: Execute<'q, Postgres>,
+    {
+        self.wait_until_ready().await?;
+
+        // prepare every statement before sending any of them, as preparing waits for
+        // the connection to be ready
+        let mut pending = Vec::new();
+
+        for mut query in queries {
+            let sql = query.sql();
+            let metadata = query.statement().map(|s| Arc::clone(&s.metadata));
+            let arguments = query.take_arguments();
+            let persistent = query.persistent();
+
+            let mut logger = QueryLogger::new(sql, "postgresql", self.log_settings.clone());
+
+            let arguments = match arguments {
+                Some(mut arguments) => {
+                    logger.record_params(&arguments);
+                    logger.record_param_values(arguments.logged_values());
+
+                    let prepared = async {
+                        let (statement, metadata) = self
+                            .get_or_prepare(sql, &arguments.types, persistent, metadata)
+                            .await?;
+
+                        arguments.apply_patches(self, &metadata.parameters).await?;
+
+                        Ok::<_, Error>((statement, metadata))
+                    };
+
+                    match prepared.await {
+                        Ok((statement, metadata)) => {
+                            if let Err(error) =
+                                arguments.check_parameter_count(&metadata.parameters)
+                            {
+                                pending.push((sql, logger, Err(error)));
+                                continue;
+                            }
+
+                            Some((statement, arguments))
+                        }
+
+                        // the query is invalid, but the others may still be executed
+                        Err(error @ Error::Database(_)) => {
+                            pending.push((sql, logger, Err(error)));
+                            continue;
+                        }
+
+                        Err(error) => return Err(error),
+                    }
+                }
+
+                None => None,
+            };
+
+            pending.push((sql, logger, Ok(arguments)));
+        }
+
+        self.wait_until_ready().await?;
+
+        for (sql, _, query) in &pending {
+            match query {
+                Ok(Some((statement, arguments))) => {
+                    self.stream.write(Bind {
+                        portal: None,
+                        statement: Some(*statement),
+                        formats: &[PgValueFormat::Binary],
+                        num_params: arguments.types.len() as i16,
+                        params: &arguments.buffer,
+                        result_formats: &[PgValueFormat::Binary],
+                    });
+
+                    self.stream.write(message::Execute {
+                        portal: None,
+                        limit: 0,
+                    });
+
+                    self.stream.write(message::Close::Portal(None));
+
+                    // the server skips the remaining messages up to the next [Sync] on error,
+                    // so each query has its own
+                    self.write_sync();
+                }
+
+                Ok(None) => {
+                    self.stream.write(Query(sql));
+                    self.pending_ready_for_query_count += 1;
+                }
+
+                Err(_) => {}
+            }
+        }
+
+        self.stream.flush().await?;
+
+        let mut results = Vec::with_capacity(pending.len());
+
+        for (_, mut logger, query) in pending {
+            if let Err(error) = query {
+                results.push(Err(error));
+                continue;
+            }
+
+            let mut result = Ok(PgQueryResult::default());
+
+            loop {
+                let message = match self.stream.recv().await {
+                    Ok(message) => message,
+
+                    // the server now skips to the next [ReadyForQuery]
+                    Err(error @ Error::Database(_)) => {
+                        result = Err(error);
+                        continue;
+                    }
+
+                    Err(error) => return Err(error),
+                };
+
+                match message.format {
+                    MessageFormat::BindComplete
+                    | MessageFormat::CloseComplete
+                    | MessageFormat::RowDescription
+                    | MessageFormat::EmptyQueryResponse => {}
+
+                    MessageFormat::DataRow => {
+                        logger.increment_rows_returned();
+                    }
+
+                    MessageFormat::CommandComplete => {
+                        let cc: CommandComplete = message.decode()?;
+                        let rows_affected = cc.rows_affected();
+
+                        logger.increase_rows_affected(rows_affected);
+
+                        if let Ok(done) = &mut result {
+                            done.rows_affected += rows_affected;
+                        }
+                    }
+
+                    MessageFormat::ReadyForQuery => {
+                        self.handle_ready_for_query(message)?;
+                        break;
+                    }
+
+                    _ => {
+                        return Err(err_protocol!(
+                            "pipeline: unexpected message: {:?}",
+                            message.format
+                        ));
+                    }
+                }
+            }
+
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+}
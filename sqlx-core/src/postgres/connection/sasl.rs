@@ -3,23 +3,24 @@ use crate::postgres::connection::stream::PgStream;
 use crate::postgres::message::{
     Authentication, AuthenticationSasl, MessageFormat, SaslInitialResponse, SaslResponse,
 };
-use crate::postgres::PgConnectOptions;
+use crate::postgres::{PgChannelBinding, PgConnectOptions};
 use hmac::{Hmac, Mac};
 use rand::Rng;
-use sha2::{Digest, Sha256};
+use sha2::{Digest, Sha256, Sha384, Sha512};
 use stringprep::saslprep;
 
-const GS2_HEADER: &str = "n,,";
 const CHANNEL_ATTR: &str = "c";
 const USERNAME_ATTR: &str = "n";
 const CLIENT_PROOF_ATTR: &str = "p";
 const NONCE_ATTR: &str = "r";
 
+/// Authenticates with SCRAM-SHA-256, returning whether the authentication is bound to the
+/// TLS connection.
 pub(crate) async fn authenticate(
     stream: &mut PgStream,
     options: &PgConnectOptions,
     data: AuthenticationSasl,
-) -> Result<(), Error> {
+) -> Result<bool, Error> {
     let mut has_sasl = false;
     let mut has_sasl_plus = false;
     let mut unknown = Vec::new();
@@ -47,11 +48,51 @@ pub(crate) async fn authenticate(
         ));
     }
 
+    let certificate = match options.channel_binding {
+        PgChannelBinding::Disable => None,
+        PgChannelBinding::Prefer | PgChannelBinding::Require => stream.peer_certificate()?,
+    };
+
+    // gs2-header = gs2-cbind-flag "," [ authzid ] ","
+    let (plus, gs2_header, cbind_data) = match certificate {
+        Some(certificate) if has_sasl_plus => (
+            true,
+            "p=tls-server-end-point,,",
+            tls_server_end_point(&certificate),
+        ),
+
+        // the client supports channel binding but thinks the server does not, which lets a
+        // server that does detect that the mechanism list was tampered with
+        Some(_) => (false, "y,,", Vec::new()),
+
+        None => (false, "n,,", Vec::new()),
+    };
+
+    if !plus && options.channel_binding == PgChannelBinding::Require {
+        return Err(Error::Tls(
+            if stream.is_tls() {
+                "channel binding is required, but the server does not support it"
+            } else {
+                "channel binding is required, but the connection is not encrypted"
+            }
+            .into(),
+        ));
+    }
+
+    if !plus && !has_sasl {
+        return Err(err_protocol!(
+            "the server only supports SCRAM-SHA-256-PLUS, which requires an encrypted connection"
+        ));
+    }
+
     // channel-binding = "c=" base64
+    let mut cbind_input = gs2_header.as_bytes().to_vec();
+    cbind_input.extend_from_slice(&cbind_data);
+
     let channel_binding = format!(
         "{}={}",
         CHANNEL_ATTR,
-        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, GS2_HEADER)
+        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, cbind_input)
     );
 
     // "n=" saslname ;; Usernames are prepared using SASLprep.
@@ -71,14 +112,14 @@ pub(crate) async fn authenticate(
 
     let client_first_message = format!(
         "{gs2_header}{client_first_message_bare}",
-        gs2_header = GS2_HEADER,
+        gs2_header = gs2_header,
         client_first_message_bare = client_first_message_bare
     );
 
     stream
         .send(SaslInitialResponse {
             response: &client_first_message,
-            plus: false,
+            plus,
         })
         .await?;
 
@@ -169,7 +210,87 @@ pub(crate) async fn authenticate(
     // authentication is only considered valid if this verification passes
     mac.verify_slice(&data.verifier).map_err(Error::protocol)?;
 
-    Ok(())
+    Ok(plus)
+}
+
+// OIDs of the signature algorithms with a hash other than SHA-256 that may sign a certificate
+const SHA384_WITH_RSA_ENCRYPTION: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0c];
+const SHA512_WITH_RSA_ENCRYPTION: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0d];
+const ECDSA_WITH_SHA384: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x03];
+const ECDSA_WITH_SHA512: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x04];
+
+// The `tls-server-end-point` channel binding data is the hash of the server certificate, with
+// the hash function of its signature algorithm, or SHA-256 if that is MD5 or SHA-1
+// https://www.rfc-editor.org/rfc/rfc5929#section-4.1
+fn tls_server_end_point(certificate: &[u8]) -> Vec<u8> {
+    match signature_algorithm(certificate) {
+        Some(SHA384_WITH_RSA_ENCRYPTION | ECDSA_WITH_SHA384) => {
+            Sha384::digest(certificate).to_vec()
+        }
+
+        Some(SHA512_WITH_RSA_ENCRYPTION | ECDSA_WITH_SHA512) => {
+            Sha512::digest(certificate).to_vec()
+        }
+
+        _ => Sha256::digest(certificate).to_vec(),
+    }
+}
+
+// Certificate ::= SEQUENCE {
+//     tbsCertificate       TBSCertificate,
+//     signatureAlgorithm   AlgorithmIdentifier,
+//     signatureValue       BIT STRING }
+//
+// AlgorithmIdentifier ::= SEQUENCE {
+//     algorithm            OBJECT IDENTIFIER,
+//     parameters           ANY DEFINED BY algorithm OPTIONAL }
+//
+// https://www.rfc-editor.org/rfc/rfc5280#section-4.1
+fn signature_algorithm(certificate: &[u8]) -> Option<&[u8]> {
+    const SEQUENCE: u8 = 0x30;
+    const OBJECT_IDENTIFIER: u8 = 0x06;
+
+    let (certificate, _) = der_element(certificate, SEQUENCE)?;
+    let (_, rest) = der_element(certificate, SEQUENCE)?;
+    let (algorithm, _) = der_element(rest, SEQUENCE)?;
+    let (oid, _) = der_element(algorithm, OBJECT_IDENTIFIER)?;
+
+    Some(oid)
+}
+
+// splits a DER encoded element with the given tag off the front of `buf`, returning its
+// contents and the rest of the buffer
+fn der_element(buf: &[u8], tag: u8) -> Option<(&[u8], &[u8])> {
+    let (&actual, buf) = buf.split_first()?;
+
+    if actual != tag {
+        return None;
+    }
+
+    let (&len, mut buf) = buf.split_first()?;
+
+    // lengths of 128 and above are encoded in as many bytes as the low bits specify
+    let len = if len < 0x80 {
+        usize::from(len)
+    } else {
+        let size = usize::from(len & 0x7f);
+
+        if size > 4 || buf.len() < size {
+            return None;
+        }
+
+        let (len, rest) = buf.split_at(size);
+        buf = rest;
+
+        len.iter()
+            .fold(0, |acc, &byte| (acc << 8) | usize::from(byte))
+    };
+
+    if buf.len() < len {
+        return None;
+    }
+
+    Some(buf.split_at(len))
 }
 
 // nonce is a sequence of random printable bytes
@@ -218,3 +339,38 @@ fn hi<'a>(s: &'a str, salt: &'a [u8], iter_count: u32) -> Result<[u8; 32], Error
 
     Ok(hi.into())
 }
+
+#[test]
+fn it_hashes_the_certificate_with_its_signature_algorithm() {
+    let pem = include_str!("../../../../tests/certs/server.crt");
+    let base64: String = pem
+        .lines()
+        .filter(|line| !line.starts_with("-----"))
+        .collect();
+    let certificate =
+        base64::Engine::decode(&base64::engine::general_purpose::STANDARD, base64).unwrap();
+
+    // sha256WithRSAEncryption
+    assert_eq!(
+        signature_algorithm(&certificate),
+        Some(&[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b][..])
+    );
+    assert_eq!(
+        tls_server_end_point(&certificate),
+        Sha256::digest(&certificate).to_vec()
+    );
+
+    // a certificate with an empty `tbsCertificate` signed with ECDSA and SHA-384
+    let certificate = [
+        0x30, 0x11, // Certificate
+        0x30, 0x00, // tbsCertificate
+        // signatureAlgorithm
+        0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x03, 0x03, 0x01,
+        0x00, // signatureValue
+    ];
+
+    assert_eq!(signature_algorithm(&certificate), Some(ECDSA_WITH_SHA384));
+    assert_eq!(tls_server_end_point(&certificate).len(), 48);
+
+    assert_eq!(signature_algorithm(&certificate[..10]), None);
+}
@@ -11,6 +11,7 @@ use crate::error::Error;
 use crate::io::{BufStream, Decode, Encode};
 use crate::net::{MaybeTlsStream, Socket};
 use crate::postgres::message::{Message, MessageFormat, Notice, Notification, ParameterStatus};
+use crate::postgres::options::PgNoticeHandler;
 use crate::postgres::{PgConnectOptions, PgDatabaseError, PgSeverity};
 
 // the stream is a separate type from the connection to uphold the invariant where an instantiated
@@ -33,13 +34,16 @@ pub struct PgStream {
     pub(crate) parameter_statuses: BTreeMap<String, String>,
 
     pub(crate) server_version_num: Option<u32>,
+
+    // called with each notice, as set by `PgConnectOptions::on_notice`
+    notice_handler: Option<PgNoticeHandler>,
 }
 
 impl PgStream {
     pub(super) async fn connect(options: &PgConnectOptions) -> Result<Self, Error> {
         let socket = match options.fetch_socket() {
             Some(ref path) => Socket::connect_uds(path).await?,
-            None => Socket::connect_tcp(&options.host, options.port).await?,
+            None => Socket::connect_tcp(&options.host, options.port, options.tcp).await?,
         };
 
         let inner = BufStream::new(MaybeTlsStream::Raw(socket));
@@ -49,6 +53,7 @@ impl PgStream {
             notifications: None,
             parameter_statuses: BTreeMap::default(),
             server_version_num: None,
+            notice_handler: options.notice_handler.clone(),
         })
     }
 
@@ -132,9 +137,6 @@ impl PgStream {
                 }
 
                 MessageFormat::NoticeResponse => {
-                    // do we need this to be more configurable?
-                    // if you are reading this comment and think so, open an issue
-
                     let notice: Notice = message.decode()?;
 
                     let lvl = match notice.severity() {
@@ -159,6 +161,10 @@ impl PgStream {
                         );
                     }
 
+                    if let Some(PgNoticeHandler(handler)) = &self.notice_handler {
+                        handler(PgDatabaseError(notice));
+                    }
+
                     continue;
                 }
 
@@ -246,6 +252,12 @@ mod tests {
         // new style without minor version, e.g.  */
         assert_eq!(parse_server_version("10devel"), Some(100000));
         assert_eq!(parse_server_version("13devel87"), Some(130000));
+        // with a distribution suffix
+        assert_eq!(
+            parse_server_version("14.2 (Debian 14.2-1.pgdg110+1)"),
+            Some(140002)
+        );
+        assert_eq!(parse_server_version("15.4-1.pgdg120+1"), Some(150004));
         // unknown
         assert_eq!(parse_server_version("unknown"), None);
     }
@@ -8,9 +8,16 @@ use crate::postgres::message::{
 use crate::postgres::Postgres;
 use bytes::{BufMut, Bytes};
 use futures_core::stream::BoxStream;
+use futures_util::Sink;
 use smallvec::alloc::borrow::Cow;
 use sqlx_rt::{AsyncRead, AsyncReadExt, AsyncWriteExt};
 use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+// the amount of `COPY` data the `Sink` implementation of `PgCopyIn` will buffer
+// before waiting for it to be written to the socket
+const COPY_SINK_BUFFER_SIZE: usize = 64 * 1024;
 
 impl PgConnection {
     /// Issue a `COPY FROM STDIN` statement and transition the connection to streaming data
@@ -106,6 +113,10 @@ impl Pool<Postgres> {
 ///
 /// Created by [PgConnection::copy_in_raw] or [Pool::copy_out_raw].
 ///
+/// `PgCopyIn` also implements [`Sink<Bytes>`][Sink], so a stream of chunks can be forwarded
+/// to it (e.g., with `SinkExt::send_all`). Chunks are buffered up to a fixed size before the
+/// sink waits for the socket to accept them, so a fast producer cannot outrun the connection.
+///
 /// ### Note
 /// [PgCopyIn::finish] or [PgCopyIn::abort] *must* be called when finished or the connection
 /// will return an error the next time it is used.
@@ -291,6 +302,52 @@ impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
     }
 }
 
+impl<C: DerefMut<Target = PgConnection> + Unpin> Sink<Bytes> for PgCopyIn<C> {
+    type Error = Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let conn = self
+            .get_mut()
+            .conn
+            .as_deref_mut()
+            .expect("PgCopyIn::poll_ready: conn taken");
+
+        if conn.stream.wbuf.len() >= COPY_SINK_BUFFER_SIZE {
+            return conn.stream.poll_write_and_flush(cx);
+        }
+
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Bytes) -> Result<()> {
+        self.get_mut()
+            .conn
+            .as_deref_mut()
+            .expect("PgCopyIn::start_send: conn taken")
+            .stream
+            .write(CopyData(item));
+
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.get_mut()
+            .conn
+            .as_deref_mut()
+            .expect("PgCopyIn::poll_flush: conn taken")
+            .stream
+            .poll_write_and_flush(cx)
+    }
+
+    /// Flushes any buffered data.
+    ///
+    /// This does *not* complete the `COPY`; [PgCopyIn::finish] or [PgCopyIn::abort]
+    /// must still be called.
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.poll_flush(cx)
+    }
+}
+
 impl<C: DerefMut<Target = PgConnection>> Drop for PgCopyIn<C> {
     fn drop(&mut self) {
         if let Some(mut conn) = self.conn.take() {
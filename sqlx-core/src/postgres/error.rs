@@ -4,7 +4,7 @@ use std::fmt::{self, Debug, Display, Formatter};
 use atoi::atoi;
 use smallvec::alloc::borrow::Cow;
 
-use crate::error::DatabaseError;
+use crate::error::{DatabaseError, ErrorKind};
 use crate::postgres::message::{Notice, PgSeverity};
 
 /// An error returned from the PostgreSQL database.
@@ -202,4 +202,21 @@ impl DatabaseError for PgDatabaseError {
     fn constraint(&self) -> Option<&str> {
         self.constraint()
     }
+
+    fn kind(&self) -> ErrorKind {
+        // https://www.postgresql.org/docs/current/errcodes-appendix.html
+        match self.code() {
+            // unique_violation
+            "23505" => ErrorKind::UniqueViolation,
+            // foreign_key_violation
+            "23503" => ErrorKind::ForeignKeyViolation,
+            // not_null_violation
+            "23502" => ErrorKind::NotNullViolation,
+            // check_violation
+            "23514" => ErrorKind::CheckViolation,
+            // deadlock_detected
+            "40P01" => ErrorKind::Deadlock,
+            _ => ErrorKind::Other,
+        }
+    }
 }
@@ -8,8 +8,8 @@ pub struct Bind<'a> {
     /// The ID of the destination portal (`None` selects the unnamed portal).
     pub portal: Option<Oid>,
 
-    /// The id of the source prepared statement.
-    pub statement: Oid,
+    /// The id of the source prepared statement (`None` selects the unnamed statement).
+    pub statement: Option<Oid>,
 
     /// The parameter format codes. Each must presently be zero (text) or one (binary).
     ///
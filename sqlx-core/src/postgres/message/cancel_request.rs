@@ -0,0 +1,35 @@
+use crate::io::Encode;
+
+// https://www.postgresql.org/docs/current/protocol-flow.html#id-1.10.5.7.9
+
+pub struct CancelRequest {
+    /// The process ID of the target backend.
+    pub process_id: u32,
+
+    /// The secret key for the target backend.
+    pub secret_key: u32,
+}
+
+impl Encode<'_> for CancelRequest {
+    #[inline]
+    fn encode_with(&self, buf: &mut Vec<u8>, _: ()) {
+        buf.extend(&16_u32.to_be_bytes());
+        buf.extend(&(((1234 << 16) | 5678) as u32).to_be_bytes());
+        buf.extend(&self.process_id.to_be_bytes());
+        buf.extend(&self.secret_key.to_be_bytes());
+    }
+}
+
+#[test]
+fn test_encode_cancel_request() {
+    const EXPECTED: &[u8] = b"\x00\x00\x00\x10\x04\xd2\x16.\x00\x00'\xc6\x89R\xc5+";
+
+    let mut buf = Vec::new();
+    CancelRequest {
+        process_id: 10182,
+        secret_key: 2303903019,
+    }
+    .encode(&mut buf);
+
+    assert_eq!(buf, EXPECTED);
+}
@@ -6,8 +6,8 @@ use crate::postgres::types::Oid;
 
 #[derive(Debug)]
 pub struct Parse<'a> {
-    /// The ID of the destination prepared statement.
-    pub statement: Oid,
+    /// The ID of the destination prepared statement (`None` selects the unnamed statement).
+    pub statement: Option<Oid>,
 
     /// The query string to be parsed.
     pub query: &'a str,
@@ -45,7 +45,23 @@ fn test_encode_parse() {
 
     let mut buf = Vec::new();
     let m = Parse {
-        statement: Oid(1),
+        statement: Some(Oid(1)),
+        query: "SELECT $1",
+        param_types: &[Oid(25)],
+    };
+
+    m.encode(&mut buf);
+
+    assert_eq!(buf, EXPECTED);
+}
+
+#[test]
+fn test_encode_parse_unnamed_statement() {
+    const EXPECTED: &[u8] = b"P\0\0\0\x15\0SELECT $1\0\0\x01\0\0\0\x19";
+
+    let mut buf = Vec::new();
+    let m = Parse {
+        statement: None,
         query: "SELECT $1",
         param_types: &[Oid(25)],
     };
@@ -0,0 +1,43 @@
+use crate::error::Error;
+use std::str::FromStr;
+
+/// Options for controlling the use of SCRAM channel binding, which ties the authentication
+/// to the TLS connection it takes place over, protecting against a server being impersonated.
+///
+/// It is used by the [`channel_binding`](super::PgConnectOptions::channel_binding) method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PgChannelBinding {
+    /// Never use channel binding.
+    Disable,
+
+    /// Use channel binding if the connection is encrypted and the server supports it.
+    Prefer,
+
+    /// Only authenticate with channel binding; fail if the connection is not encrypted, or
+    /// if the server does not support it or does not authenticate with SCRAM.
+    Require,
+}
+
+impl Default for PgChannelBinding {
+    fn default() -> Self {
+        PgChannelBinding::Prefer
+    }
+}
+
+impl FromStr for PgChannelBinding {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Ok(match &*s.to_ascii_lowercase() {
+            "disable" => PgChannelBinding::Disable,
+            "prefer" => PgChannelBinding::Prefer,
+            "require" => PgChannelBinding::Require,
+
+            _ => {
+                return Err(Error::Configuration(
+                    format!("unknown value {:?} for `channel_binding`", s).into(),
+                ));
+            }
+        })
+    }
+}
@@ -24,4 +24,23 @@ impl ConnectOptions for PgConnectOptions {
         self.log_settings.log_slow_statements(level, duration);
         self
     }
+
+    fn log_parameters(&mut self, enabled: bool) -> &mut Self {
+        self.log_settings.log_parameters(enabled);
+        self
+    }
+
+    fn redact_parameters(
+        &mut self,
+        redact: impl Fn(usize) -> bool + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.log_settings.redact_parameters(redact);
+        self
+    }
+
+    #[cfg(feature = "tracing")]
+    fn trace_params(&mut self, enabled: bool) -> &mut Self {
+        self.log_settings.trace_params(enabled);
+        self
+    }
 }
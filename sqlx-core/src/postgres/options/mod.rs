@@ -1,13 +1,19 @@
 use std::borrow::Cow;
 use std::env::var;
-use std::fmt::{Display, Write};
+use std::fmt::{self, Debug, Display, Formatter, Write};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
 
+mod channel_binding;
 mod connect;
 mod parse;
 mod pgpass;
 mod ssl_mode;
-use crate::{connection::LogSettings, net::CertificateInput};
+use crate::connection::LogSettings;
+use crate::net::{CertificateInput, TcpOptions};
+use crate::postgres::PgDatabaseError;
+pub use channel_binding::PgChannelBinding;
 pub use ssl_mode::PgSslMode;
 
 /// Options and flags which can be used to configure a PostgreSQL connection.
@@ -26,8 +32,10 @@ pub use ssl_mode::PgSslMode;
 /// |Parameter|Default|Description|
 /// |---------|-------|-----------|
 /// | `sslmode` | `prefer` | Determines whether or with what priority a secure SSL TCP/IP connection will be negotiated. See [`PgSslMode`]. |
+/// | `channel_binding` | `prefer` | Determines whether SCRAM authentication is bound to the TLS connection. See [`PgChannelBinding`]. |
 /// | `sslrootcert` | `None` | Sets the name of a file containing a list of trusted SSL Certificate Authorities. |
 /// | `statement-cache-capacity` | `100` | The maximum number of prepared statements stored in the cache. Set to `0` to disable. |
+/// | `prepared_statements` | `true` | Set to `false` to never prepare named statements, e.g. behind PgBouncer in transaction mode. See [`PgConnectOptions::prepared_statements`]. |
 /// | `host` | `None` | Path to the directory containing a PostgreSQL unix domain socket, which will be used instead of TCP if set. |
 /// | `hostaddr` | `None` | Same as `host`, but only accepts IP addresses. |
 /// | `application-name` | `None` | The name will be displayed in the pg_stat_activity view and included in CSV log entries. |
@@ -37,6 +45,8 @@ pub use ssl_mode::PgSslMode;
 /// | `dbname` | `None` | The database name. |
 /// | `options` | `None` | The runtime parameters to send to the server at connection start. |
 ///
+/// Any other parameter is rejected with [`Error::Configuration`][crate::error::Error::Configuration].
+///
 /// The URL scheme designator can be either `postgresql://` or `postgres://`.
 /// Each of the URL parts is optional.
 ///
@@ -80,16 +90,29 @@ pub struct PgConnectOptions {
     pub(crate) host: String,
     pub(crate) port: u16,
     pub(crate) socket: Option<PathBuf>,
+    pub(crate) tcp: TcpOptions,
     pub(crate) username: String,
     pub(crate) password: Option<String>,
     pub(crate) database: Option<String>,
     pub(crate) ssl_mode: PgSslMode,
     pub(crate) ssl_root_cert: Option<CertificateInput>,
+    pub(crate) channel_binding: PgChannelBinding,
     pub(crate) statement_cache_capacity: usize,
+    pub(crate) prepared_statements: bool,
     pub(crate) application_name: Option<String>,
     pub(crate) log_settings: LogSettings,
     pub(crate) extra_float_digits: Option<Cow<'static, str>>,
     pub(crate) options: Option<String>,
+    pub(crate) notice_handler: Option<PgNoticeHandler>,
+}
+
+#[derive(Clone)]
+pub(crate) struct PgNoticeHandler(pub(crate) Arc<dyn Fn(PgDatabaseError) + Send + Sync>);
+
+impl Debug for PgNoticeHandler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("PgNoticeHandler")
+    }
 }
 
 impl Default for PgConnectOptions {
@@ -111,6 +134,7 @@ impl PgConnectOptions {
     ///  * `PGDATABASE`
     ///  * `PGSSLROOTCERT`
     ///  * `PGSSLMODE`
+    ///  * `PGCHANNELBINDING`
     ///  * `PGAPPNAME`
     ///
     /// # Example
@@ -139,6 +163,7 @@ impl PgConnectOptions {
             port,
             host,
             socket: None,
+            tcp: TcpOptions::default(),
             username,
             password: var("PGPASSWORD").ok(),
             database,
@@ -147,11 +172,17 @@ impl PgConnectOptions {
                 .ok()
                 .and_then(|v| v.parse().ok())
                 .unwrap_or_default(),
+            channel_binding: var("PGCHANNELBINDING")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_default(),
             statement_cache_capacity: 100,
+            prepared_statements: true,
             application_name: var("PGAPPNAME").ok(),
             extra_float_digits: Some("3".into()),
             log_settings: Default::default(),
             options: var("PGOPTIONS").ok(),
+            notice_handler: None,
         }
     }
 
@@ -214,6 +245,28 @@ impl PgConnectOptions {
         self
     }
 
+    /// Enables TCP keepalive on the connection, sending the first keepalive probe after the
+    /// connection has been idle for the given duration.
+    ///
+    /// This keeps long-lived idle connections from being silently dropped by NATs and
+    /// firewalls. By default set to `None`, which leaves keepalive disabled.
+    ///
+    /// This has no effect when connecting through a Unix domain socket.
+    pub fn tcp_keepalive(mut self, time: Option<Duration>) -> Self {
+        self.tcp.keepalive = time;
+        self
+    }
+
+    /// Sets `TCP_NODELAY` on the connection, which disables Nagle's algorithm.
+    ///
+    /// By default set to `false`.
+    ///
+    /// This has no effect when connecting through a Unix domain socket.
+    pub fn tcp_nodelay(mut self, nodelay: bool) -> Self {
+        self.tcp.nodelay = nodelay;
+        self
+    }
+
     /// Sets the username to connect as.
     ///
     /// Defaults to be the same as the operating system name of
@@ -294,6 +347,32 @@ impl PgConnectOptions {
         self
     }
 
+    /// Sets whether SCRAM authentication is bound to the TLS connection with the
+    /// `SCRAM-SHA-256-PLUS` mechanism, so that a server cannot be impersonated by one that
+    /// does not have the private key of its certificate, even if the certificate is not
+    /// verified.
+    ///
+    /// By default, channel binding is [`Prefer`](PgChannelBinding::Prefer)red, and used
+    /// whenever the connection is encrypted and the server supports it.
+    ///
+    /// This changes the authentication mechanism of every TLS connection to a server that
+    /// offers `SCRAM-SHA-256-PLUS` (Postgres 11+), from `SCRAM-SHA-256` before this option
+    /// existed. Use [`Disable`](PgChannelBinding::Disable) to keep authenticating as before, e.g.
+    /// through a proxy that terminates TLS, where the binding would not match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::{PgChannelBinding, PgConnectOptions, PgSslMode};
+    /// let options = PgConnectOptions::new()
+    ///     .ssl_mode(PgSslMode::Require)
+    ///     .channel_binding(PgChannelBinding::Require);
+    /// ```
+    pub fn channel_binding(mut self, channel_binding: PgChannelBinding) -> Self {
+        self.channel_binding = channel_binding;
+        self
+    }
+
     /// Sets the name of a file containing SSL certificate authority (CA) certificate(s).
     /// If the file exists, the server's certificate will be verified to be signed by
     /// one of these authorities.
@@ -339,6 +418,37 @@ impl PgConnectOptions {
         self
     }
 
+    /// Sets whether queries with arguments are executed as named prepared statements.
+    ///
+    /// Connection poolers such as PgBouncer in transaction mode may run each transaction on a
+    /// different server connection, which does not have the statements prepared on another.
+    /// When disabled, each query is instead parsed as the unnamed statement, bound and executed
+    /// in a single exchange with the server, which poolers support. The arguments are still
+    /// sent separately from the SQL in their binary format, so no escaping is involved, but
+    /// nothing is stored in the statement cache: unless it is run from a
+    /// [`PgStatement`][crate::postgres::PgStatement], the query is described in an exchange of
+    /// its own every time, so that the types of its columns are known before its rows are
+    /// decoded.
+    ///
+    /// [`Executor::prepare`][crate::executor::Executor::prepare],
+    /// [`Executor::describe`][crate::executor::Executor::describe] and
+    /// [`PgConnection::pipeline`][crate::postgres::PgConnection::pipeline] still prepare named
+    /// statements.
+    ///
+    /// The default is `true`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .prepared_statements(false);
+    /// ```
+    pub fn prepared_statements(mut self, enabled: bool) -> Self {
+        self.prepared_statements = enabled;
+        self
+    }
+
     /// Sets the application name. Defaults to None
     ///
     /// # Example
@@ -433,6 +543,58 @@ impl PgConnectOptions {
         self
     }
 
+    /// Sets the maximum amount of time any statement is allowed to run before it is
+    /// aborted by the server, by setting the `statement_timeout` runtime parameter.
+    ///
+    /// A statement which exceeds the timeout fails with a [`Database`] error with the
+    /// SQLSTATE code `57014` (`query_canceled`). The server cancels the statement itself,
+    /// so the connection remains usable afterwards.
+    ///
+    /// The timeout is sent with millisecond precision; a duration of zero disables the timeout.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .statement_timeout(Duration::from_secs(30));
+    /// ```
+    ///
+    /// [`Database`]: crate::error::Error::Database
+    pub fn statement_timeout(self, timeout: Duration) -> Self {
+        self.options([("statement_timeout", format!("{}ms", timeout.as_millis()))])
+    }
+
+    /// Sets a function to be called with each notice or warning the server sends, such as those
+    /// raised with `RAISE NOTICE` in a procedure.
+    ///
+    /// The notice is passed as a [`PgDatabaseError`], which carries its
+    /// [`severity`][PgDatabaseError::severity], [`message`][PgDatabaseError::message],
+    /// [`detail`][PgDatabaseError::detail] and other fields. Notices are still logged under the
+    /// `sqlx::postgres::notice` target as well.
+    ///
+    /// The function is shared by every connection opened with these options, and called on the
+    /// task that is reading from the connection, so it should return quickly. Notices sent while
+    /// the connection is idle are only read, and passed to the function, once the connection is
+    /// used again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new().on_notice(|notice| {
+    ///     println!("{:?}: {}", notice.severity(), notice.message());
+    /// });
+    /// ```
+    pub fn on_notice<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(PgDatabaseError) + Send + Sync + 'static,
+    {
+        self.notice_handler = Some(PgNoticeHandler(Arc::new(handler)));
+        self
+    }
+
     /// We try using a socket if hostname starts with `/` or if socket parameter
     /// is specified.
     pub(crate) fn fetch_socket(&self) -> Option<String> {
@@ -486,3 +648,12 @@ fn test_options_formatting() {
     let options = PgConnectOptions::new();
     assert_eq!(options.options, None);
 }
+
+#[test]
+fn test_statement_timeout_formatting() {
+    let options = PgConnectOptions::new().statement_timeout(Duration::from_millis(1500));
+    assert_eq!(
+        options.options.as_deref(),
+        Some("-c statement_timeout=1500ms")
+    );
+}
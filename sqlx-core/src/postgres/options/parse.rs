@@ -53,6 +53,10 @@ impl FromStr for PgConnectOptions {
                     options = options.ssl_mode(value.parse().map_err(Error::config)?);
                 }
 
+                "channel_binding" | "channel-binding" => {
+                    options = options.channel_binding(value.parse().map_err(Error::config)?);
+                }
+
                 "sslrootcert" | "ssl-root-cert" | "ssl-ca" => {
                     options = options.ssl_root_cert(&*value);
                 }
@@ -62,6 +66,10 @@ impl FromStr for PgConnectOptions {
                         options.statement_cache_capacity(value.parse().map_err(Error::config)?);
                 }
 
+                "prepared_statements" | "prepared-statements" => {
+                    options = options.prepared_statements(value.parse().map_err(Error::config)?);
+                }
+
                 "host" => {
                     if value.starts_with("/") {
                         options = options.socket(&*value);
@@ -83,7 +91,9 @@ impl FromStr for PgConnectOptions {
 
                 "password" => options = options.password(&*value),
 
-                "application_name" => options = options.application_name(&*value),
+                "application_name" | "application-name" => {
+                    options = options.application_name(&*value)
+                }
 
                 "options" => {
                     if let Some(options) = options.options.as_mut() {
@@ -100,7 +110,15 @@ impl FromStr for PgConnectOptions {
                     }
                 }
 
-                _ => log::warn!("ignoring unrecognized connect parameter: {}={}", key, value),
+                _ => {
+                    return Err(Error::Configuration(
+                        format!(
+                            "unknown query parameter `{}` while parsing connection URL",
+                            key
+                        )
+                        .into(),
+                    ));
+                }
             }
         }
 
@@ -145,6 +163,17 @@ fn it_parses_port_correctly_from_parameter() {
     assert_eq!(1234, opts.port);
 }
 
+#[test]
+fn it_parses_prepared_statements_correctly_from_parameter() {
+    let opts = PgConnectOptions::from_str("postgres:///").unwrap();
+    assert!(opts.prepared_statements);
+
+    let opts = PgConnectOptions::from_str("postgres:///?prepared_statements=false").unwrap();
+    assert!(!opts.prepared_statements);
+
+    assert!(PgConnectOptions::from_str("postgres:///?prepared_statements=no").is_err());
+}
+
 #[test]
 fn it_parses_dbname_correctly_from_parameter() {
     let url = "postgres:///?dbname=some_db";
@@ -180,6 +209,14 @@ fn it_parses_application_name_correctly_from_parameter() {
     assert_eq!(Some("some_name"), opts.application_name.as_deref());
 }
 
+#[test]
+fn it_rejects_unknown_parameters() {
+    let url = "postgres:///?application_name=some_name&charset=utf8mb4";
+    let err = PgConnectOptions::from_str(url).unwrap_err();
+
+    assert!(err.to_string().contains("`charset`"), "{}", err);
+}
+
 #[test]
 fn it_parses_username_with_at_sign_correctly() {
     let url = "postgres://user@hostname:password@hostname:5432/database";
@@ -232,3 +269,17 @@ fn it_parses_sqlx_options_correctly() {
         opts.options
     );
 }
+
+#[test]
+fn it_parses_channel_binding_correctly_from_parameter() {
+    let url = "postgres:///?channel_binding=require";
+    let opts = PgConnectOptions::from_str(url).unwrap();
+
+    assert_eq!(
+        opts.channel_binding,
+        crate::postgres::PgChannelBinding::Require
+    );
+
+    let url = "postgres:///?channel_binding=sometimes";
+    assert!(PgConnectOptions::from_str(url).is_err());
+}
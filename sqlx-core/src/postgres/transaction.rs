@@ -2,10 +2,13 @@ use futures_core::future::BoxFuture;
 
 use crate::error::Error;
 use crate::executor::Executor;
+use crate::postgres::message::TransactionStatus;
 use crate::postgres::{PgConnection, Postgres};
 use crate::transaction::{
-    begin_ansi_transaction_sql, commit_ansi_transaction_sql, rollback_ansi_transaction_sql,
-    TransactionManager,
+    begin_ansi_transaction_sql, begin_ansi_transaction_with_options_sql,
+    commit_ansi_transaction_sql, release_deferred_savepoint_sql, release_savepoint_ansi_sql,
+    rollback_ansi_transaction_sql, rollback_to_savepoint_ansi_sql, savepoint_ansi_sql,
+    TransactionManager, TransactionOptions,
 };
 
 /// Implementation of [`TransactionManager`] for PostgreSQL.
@@ -16,7 +19,29 @@ impl TransactionManager for PgTransactionManager {
 
     fn begin(conn: &mut PgConnection) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(async move {
-            conn.execute(&*begin_ansi_transaction_sql(conn.transaction_depth))
+            let sql = begin_ansi_transaction_sql(conn.transaction_depth);
+            let sql = release_deferred_savepoint_sql(&mut conn.deferred_savepoint_release, &sql);
+
+            conn.execute(&*sql).await?;
+
+            conn.transaction_depth += 1;
+
+            Ok(())
+        })
+    }
+
+    fn begin_with_options(
+        conn: &mut PgConnection,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            if conn.transaction_depth > 0 {
+                return Err(Error::Savepoint(
+                    "the options of a transaction cannot be applied to a savepoint".into(),
+                ));
+            }
+
+            conn.execute(&*begin_ansi_transaction_with_options_sql(options))
                 .await?;
 
             conn.transaction_depth += 1;
@@ -31,6 +56,8 @@ impl TransactionManager for PgTransactionManager {
                 conn.execute(&*commit_ansi_transaction_sql(conn.transaction_depth))
                     .await?;
 
+                // this also releases any savepoint established after this one
+                conn.deferred_savepoint_release = None;
                 conn.transaction_depth -= 1;
             }
 
@@ -38,12 +65,36 @@ impl TransactionManager for PgTransactionManager {
         })
     }
 
+    fn commit_with_savepoint_release(conn: &mut PgConnection) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            let depth = conn.transaction_depth;
+
+            if depth > 1 {
+                // the status is only up to date once the previous queries have completed
+                conn.wait_until_ready().await?;
+
+                // the savepoint can not be released in a failed transaction, which is reported
+                // by committing it right away
+                if !matches!(conn.transaction_status, TransactionStatus::Error) {
+                    conn.deferred_savepoint_release = Some(depth - 1);
+                    conn.transaction_depth -= 1;
+
+                    return Ok(());
+                }
+            }
+
+            Self::commit(conn).await
+        })
+    }
+
     fn rollback(conn: &mut PgConnection) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(async move {
             if conn.transaction_depth > 0 {
                 conn.execute(&*rollback_ansi_transaction_sql(conn.transaction_depth))
                     .await?;
 
+                // this also destroys any savepoint established after this one
+                conn.deferred_savepoint_release = None;
                 conn.transaction_depth -= 1;
             }
 
@@ -55,7 +106,50 @@ impl TransactionManager for PgTransactionManager {
         if conn.transaction_depth > 0 {
             conn.queue_simple_query(&rollback_ansi_transaction_sql(conn.transaction_depth));
 
+            conn.deferred_savepoint_release = None;
             conn.transaction_depth -= 1;
         }
     }
+
+    fn savepoint<'c>(
+        conn: &'c mut PgConnection,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move {
+            let sql = savepoint_ansi_sql(name, '"');
+            let sql = release_deferred_savepoint_sql(&mut conn.deferred_savepoint_release, &sql);
+
+            conn.execute(&*sql).await?;
+
+            Ok(())
+        })
+    }
+
+    fn rollback_to_savepoint<'c>(
+        conn: &'c mut PgConnection,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move {
+            let sql = rollback_to_savepoint_ansi_sql(name, '"');
+            let sql = release_deferred_savepoint_sql(&mut conn.deferred_savepoint_release, &sql);
+
+            conn.execute(&*sql).await?;
+
+            Ok(())
+        })
+    }
+
+    fn release_savepoint<'c>(
+        conn: &'c mut PgConnection,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move {
+            let sql = release_savepoint_ansi_sql(name, '"');
+            let sql = release_deferred_savepoint_sql(&mut conn.deferred_savepoint_release, &sql);
+
+            conn.execute(&*sql).await?;
+
+            Ok(())
+        })
+    }
 }
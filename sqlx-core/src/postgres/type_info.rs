@@ -115,8 +115,14 @@ pub enum PgType {
     Int8RangeArray,
     Jsonpath,
     JsonpathArray,
+    TsVector,
+    TsVectorArray,
     Money,
     MoneyArray,
+    Regclass,
+    RegclassArray,
+    Regtype,
+    RegtypeArray,
 
     // https://www.postgresql.org/docs/9.3/datatype-pseudo.html
     Void,
@@ -165,6 +171,21 @@ impl PgTypeInfo {
         self.0.kind()
     }
 
+    /// Returns `true` if this is a polymorphic array type, `anyarray` or `anycompatiblearray`,
+    /// whose element type is only known from each value.
+    pub(crate) fn is_polymorphic_array(&self) -> bool {
+        // a type that is merely declared has no kind yet, e.g. that of a column whose type
+        // could not be fetched in the middle of a query
+        match &self.0 {
+            PgType::Custom(ty) => {
+                matches!(ty.kind, PgTypeKind::Pseudo)
+                    && ["anyarray", "anycompatiblearray"].contains(&&*ty.name)
+            }
+
+            _ => false,
+        }
+    }
+
     #[doc(hidden)]
     pub fn __type_feature_gate(&self) -> Option<&'static str> {
         if [
@@ -329,6 +350,12 @@ impl PgType {
             3927 => PgType::Int8RangeArray,
             4072 => PgType::Jsonpath,
             4073 => PgType::JsonpathArray,
+            3614 => PgType::TsVector,
+            3643 => PgType::TsVectorArray,
+            2205 => PgType::Regclass,
+            2206 => PgType::Regtype,
+            2210 => PgType::RegclassArray,
+            2211 => PgType::RegtypeArray,
 
             _ => {
                 return None;
@@ -437,6 +464,12 @@ impl PgType {
             PgType::Int8RangeArray => Oid(3927),
             PgType::Jsonpath => Oid(4072),
             PgType::JsonpathArray => Oid(4073),
+            PgType::TsVector => Oid(3614),
+            PgType::TsVectorArray => Oid(3643),
+            PgType::Regclass => Oid(2205),
+            PgType::RegclassArray => Oid(2210),
+            PgType::Regtype => Oid(2206),
+            PgType::RegtypeArray => Oid(2211),
             PgType::Custom(ty) => ty.oid,
 
             PgType::DeclareWithOid(oid) => *oid,
@@ -537,8 +570,14 @@ impl PgType {
             PgType::Int8RangeArray => "INT8RANGE[]",
             PgType::Jsonpath => "JSONPATH",
             PgType::JsonpathArray => "JSONPATH[]",
+            PgType::TsVector => "TSVECTOR",
+            PgType::TsVectorArray => "TSVECTOR[]",
             PgType::Money => "MONEY",
             PgType::MoneyArray => "MONEY[]",
+            PgType::Regclass => "REGCLASS",
+            PgType::RegclassArray => "REGCLASS[]",
+            PgType::Regtype => "REGTYPE",
+            PgType::RegtypeArray => "REGTYPE[]",
             PgType::Void => "VOID",
             PgType::Custom(ty) => &*ty.name,
             PgType::DeclareWithOid(_) => "?",
@@ -637,8 +676,14 @@ impl PgType {
             PgType::Int8RangeArray => "_int8range",
             PgType::Jsonpath => "jsonpath",
             PgType::JsonpathArray => "_jsonpath",
+            PgType::TsVector => "tsvector",
+            PgType::TsVectorArray => "_tsvector",
             PgType::Money => "money",
             PgType::MoneyArray => "_money",
+            PgType::Regclass => "regclass",
+            PgType::RegclassArray => "_regclass",
+            PgType::Regtype => "regtype",
+            PgType::RegtypeArray => "_regtype",
             PgType::Void => "void",
             PgType::Custom(ty) => &*ty.name,
             PgType::DeclareWithOid(_) => "?",
@@ -737,8 +782,14 @@ impl PgType {
             PgType::Int8RangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::Int8Range)),
             PgType::Jsonpath => &PgTypeKind::Simple,
             PgType::JsonpathArray => &PgTypeKind::Array(PgTypeInfo(PgType::Jsonpath)),
+            PgType::TsVector => &PgTypeKind::Simple,
+            PgType::TsVectorArray => &PgTypeKind::Array(PgTypeInfo(PgType::TsVector)),
             PgType::Money => &PgTypeKind::Simple,
             PgType::MoneyArray => &PgTypeKind::Array(PgTypeInfo(PgType::Money)),
+            PgType::Regclass => &PgTypeKind::Simple,
+            PgType::RegclassArray => &PgTypeKind::Array(PgTypeInfo(PgType::Regclass)),
+            PgType::Regtype => &PgTypeKind::Simple,
+            PgType::RegtypeArray => &PgTypeKind::Array(PgTypeInfo(PgType::Regtype)),
 
             PgType::Void => &PgTypeKind::Pseudo,
 
@@ -804,6 +855,10 @@ impl PgType {
             PgType::Macaddr8Array => Some(Cow::Owned(PgTypeInfo(PgType::Macaddr8))),
             PgType::Money => None,
             PgType::MoneyArray => Some(Cow::Owned(PgTypeInfo(PgType::Money))),
+            PgType::Regclass => None,
+            PgType::RegclassArray => Some(Cow::Owned(PgTypeInfo(PgType::Regclass))),
+            PgType::Regtype => None,
+            PgType::RegtypeArray => Some(Cow::Owned(PgTypeInfo(PgType::Regtype))),
             PgType::Macaddr => None,
             PgType::MacaddrArray => Some(Cow::Owned(PgTypeInfo(PgType::Macaddr))),
             PgType::Inet => None,
@@ -850,6 +905,8 @@ impl PgType {
             PgType::Int8RangeArray => Some(Cow::Owned(PgTypeInfo(PgType::Int8Range))),
             PgType::Jsonpath => None,
             PgType::JsonpathArray => Some(Cow::Owned(PgTypeInfo(PgType::Jsonpath))),
+            PgType::TsVector => None,
+            PgType::TsVectorArray => Some(Cow::Owned(PgTypeInfo(PgType::TsVector))),
             // There is no `UnknownArray`
             PgType::Unknown => None,
             // There is no `VoidArray`
@@ -924,6 +981,14 @@ impl PgTypeInfo {
     pub(crate) const JSONPATH: Self = Self(PgType::Jsonpath);
     pub(crate) const JSONPATH_ARRAY: Self = Self(PgType::JsonpathArray);
 
+    //
+    // text search types
+    // https://www.postgresql.org/docs/current/datatype-textsearch.html
+    //
+
+    pub(crate) const TS_VECTOR: Self = Self(PgType::TsVector);
+    pub(crate) const TS_VECTOR_ARRAY: Self = Self(PgType::TsVectorArray);
+
     //
     // network address types
     // https://www.postgresql.org/docs/current/datatype-net-types.html
@@ -978,6 +1043,13 @@ impl PgTypeInfo {
     pub(crate) const OID: Self = Self(PgType::Oid);
     pub(crate) const OID_ARRAY: Self = Self(PgType::OidArray);
 
+    // references to a relation and to a type, which are sent as their OID
+    // https://www.postgresql.org/docs/current/datatype-oid.html
+    pub(crate) const REGCLASS: Self = Self(PgType::Regclass);
+    pub(crate) const REGCLASS_ARRAY: Self = Self(PgType::RegclassArray);
+    pub(crate) const REGTYPE: Self = Self(PgType::Regtype);
+    pub(crate) const REGTYPE_ARRAY: Self = Self(PgType::RegtypeArray);
+
     // small-range integer; -32768 to +32767
     pub(crate) const INT2: Self = Self(PgType::Int2);
     pub(crate) const INT2_ARRAY: Self = Self(PgType::Int2Array);
@@ -3,7 +3,7 @@ use std::borrow::Cow;
 
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
-use crate::error::BoxDynError;
+use crate::error::{mismatched_types, BoxDynError};
 use crate::postgres::type_info::PgType;
 use crate::postgres::types::Oid;
 use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
@@ -38,7 +38,8 @@ where
     }
 
     fn compatible(ty: &PgTypeInfo) -> bool {
-        T::array_compatible(ty)
+        // the elements of a polymorphic array are checked when decoding
+        ty.is_polymorphic_array() || T::array_compatible(ty)
     }
 }
 
@@ -51,7 +52,8 @@ where
     }
 
     fn compatible(ty: &PgTypeInfo) -> bool {
-        T::array_compatible(ty)
+        // the elements of a polymorphic array are checked when decoding
+        ty.is_polymorphic_array() || T::array_compatible(ty)
     }
 }
 
@@ -64,7 +66,8 @@ where
     }
 
     fn compatible(ty: &PgTypeInfo) -> bool {
-        T::array_compatible(ty)
+        // the elements of a polymorphic array are checked when decoding
+        ty.is_polymorphic_array() || T::array_compatible(ty)
     }
 }
 
@@ -168,8 +171,11 @@ where
 
                 // the OID of the element
                 let element_type_oid = Oid(buf.get_u32());
+                let is_polymorphic = value.type_info.is_polymorphic_array();
                 let element_type_info: PgTypeInfo = PgTypeInfo::try_from_oid(element_type_oid)
                     .or_else(|| value.type_info.try_array_element().map(Cow::into_owned))
+                    // the elements of a polymorphic array may be of a custom type
+                    .or_else(|| is_polymorphic.then_some(PgTypeInfo::with_oid(element_type_oid)))
                     .ok_or_else(|| {
                         BoxDynError::from(format!(
                             "failed to resolve array element type for oid {}",
@@ -177,6 +183,11 @@ where
                         ))
                     })?;
 
+                // the type of the column did not tell whether the elements can be decoded
+                if is_polymorphic && !T::compatible(&element_type_info) {
+                    return Err(mismatched_types::<Postgres, T>(&element_type_info));
+                }
+
                 // length of the array axis
                 let len = buf.get_i32();
 
@@ -218,14 +229,15 @@ where
 
                 // NOTE: Nearly *all* types use ',' as the sequence delimiter. Yes, there is one
                 //       that does not. The BOX (not PostGIS) type uses ';' as a delimiter.
-
-                // TODO: When we add support for BOX we need to figure out some way to make the
-                //       delimiter selection
-
-                let delimiter = ',';
+                let delimiter = if element_type_info == PgTypeInfo::BOX {
+                    ';'
+                } else {
+                    ','
+                };
                 let mut done = false;
                 let mut in_quotes = false;
                 let mut in_escape = false;
+                let mut quoted = false;
                 let mut value = String::with_capacity(10);
                 let mut chars = s.chars();
                 let mut elements = Vec::with_capacity(4);
@@ -241,6 +253,7 @@ where
 
                                 '"' => {
                                     in_quotes = !in_quotes;
+                                    quoted = true;
                                 }
 
                                 '\\' => {
@@ -263,7 +276,8 @@ where
                         }
                     }
 
-                    let value_opt = if value == "NULL" {
+                    // an element that is the string "NULL" rather than NULL is quoted
+                    let value_opt = if value == "NULL" && !quoted {
                         None
                     } else {
                         Some(value.as_bytes())
@@ -277,6 +291,7 @@ where
                     })?);
 
                     value.clear();
+                    quoted = false;
                 }
 
                 Ok(elements)
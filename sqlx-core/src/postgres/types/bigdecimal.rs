@@ -7,6 +7,7 @@ use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::postgres::types::numeric::{PgNumeric, PgNumericSign};
+use crate::postgres::types::PgMoney;
 use crate::postgres::{
     PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
 };
@@ -16,6 +17,10 @@ impl Type<Postgres> for BigDecimal {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::NUMERIC
     }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::NUMERIC || *ty == PgTypeInfo::MONEY
+    }
 }
 
 impl PgHasArrayType for BigDecimal {
@@ -28,13 +33,13 @@ impl TryFrom<PgNumeric> for BigDecimal {
     type Error = BoxDynError;
 
     fn try_from(numeric: PgNumeric) -> Result<Self, BoxDynError> {
-        let (digits, sign, weight) = match numeric {
+        let (digits, sign, weight, dscale) = match numeric {
             PgNumeric::Number {
                 digits,
                 sign,
                 weight,
-                ..
-            } => (digits, sign, weight),
+                scale,
+            } => (digits, sign, weight, scale),
 
             PgNumeric::NotANumber => {
                 return Err("BigDecimal does not support NaN values".into());
@@ -43,7 +48,7 @@ impl TryFrom<PgNumeric> for BigDecimal {
 
         if digits.is_empty() {
             // Postgres returns an empty digit array for 0 but BigInt expects at least one zero
-            return Ok(0u64.into());
+            return Ok(BigDecimal::new(BigInt::from(0), dscale.into()));
         }
 
         let sign = match sign {
@@ -64,7 +69,9 @@ impl TryFrom<PgNumeric> for BigDecimal {
         let bigint = BigInt::from_radix_be(sign, &cents, 100)
             .ok_or("PgNumeric contained an out-of-range digit")?;
 
-        Ok(BigDecimal::new(bigint, scale))
+        // the digits are padded to a multiple of 4 decimal places,
+        // so restore the display scale the value was stored with (e.g. `1.50` for `numeric(3, 2)`)
+        Ok(BigDecimal::new(bigint, scale).with_scale(dscale.into()))
     }
 }
 
@@ -158,8 +165,14 @@ impl Encode<'_, Postgres> for BigDecimal {
     }
 }
 
+/// `MONEY` values are decoded with two fractional digits, which is what most locales use;
+/// decode to [`PgMoney`] instead for a locale with a different `frac_digits`.
 impl Decode<'_, Postgres> for BigDecimal {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        if value.type_info == PgTypeInfo::MONEY {
+            return Ok(PgMoney::decode(value)?.to_bigdecimal(2));
+        }
+
         match value.format() {
             PgValueFormat::Binary => PgNumeric::decode(value.as_bytes()?)?.try_into(),
             PgValueFormat::Text => Ok(value.as_str()?.parse::<BigDecimal>()?),
@@ -430,3 +443,49 @@ mod bigdecimal_to_pgnumeric {
         );
     }
 }
+
+#[cfg(test)]
+mod pgnumeric_to_bigdecimal {
+    use super::{BigDecimal, PgNumeric, PgNumericSign};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn preserves_scale() {
+        // 1.50::numeric(3, 2)
+        let decimal = BigDecimal::try_from(PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            scale: 2,
+            weight: 0,
+            digits: vec![1, 5000],
+        })
+        .unwrap();
+
+        assert_eq!(decimal.to_string(), "1.50");
+    }
+
+    #[test]
+    fn preserves_scale_of_zero() {
+        let decimal = BigDecimal::try_from(PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            scale: 3,
+            weight: 0,
+            digits: vec![],
+        })
+        .unwrap();
+
+        assert_eq!(decimal.to_string(), "0.000");
+    }
+
+    #[test]
+    fn integer() {
+        let decimal = BigDecimal::try_from(PgNumeric::Number {
+            sign: PgNumericSign::Negative,
+            scale: 0,
+            weight: 1,
+            digits: vec![1],
+        })
+        .unwrap();
+
+        assert_eq!(decimal.to_string(), "-10000");
+    }
+}
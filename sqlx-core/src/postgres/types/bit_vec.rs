@@ -38,7 +38,7 @@ impl Encode<'_, Postgres> for BitVec {
     }
 
     fn size_hint(&self) -> usize {
-        mem::size_of::<i32>() + self.len()
+        mem::size_of::<i32>() + (self.len() + 7) / 8
     }
 }
 
@@ -66,14 +66,12 @@ impl Decode<'_, Postgres> for BitVec {
                     ))?;
                 }
 
-                let mut bitvec = BitVec::from_bytes(&bytes);
+                let mut bitvec = BitVec::from_bytes(bytes);
 
                 // Chop off zeroes from the back. We get bits in bytes, so if
                 // our bitvec is not in full bytes, extra zeroes are added to
                 // the end.
-                while bitvec.len() > len as usize {
-                    bitvec.pop();
-                }
+                bitvec.truncate(len as usize);
 
                 Ok(bitvec)
             }
@@ -84,9 +84,18 @@ impl<const N: usize> Decode<'_, Postgres> for [u8; N] {
         let mut bytes = [0u8; N];
         match value.format() {
             PgValueFormat::Binary => {
-                bytes = value.as_bytes()?.try_into()?;
+                let value = value.as_bytes()?;
+                bytes = value
+                    .try_into()
+                    .map_err(|_| format!("expected {} bytes, got {}", N, value.len()))?;
+            }
+            PgValueFormat::Text => {
+                let hex = text_hex_decode_input(value)?;
+                if hex.len() != N * 2 {
+                    return Err(format!("expected {} bytes, got {}", N, hex.len() / 2).into());
+                }
+                hex::decode_to_slice(hex, &mut bytes)?
             }
-            PgValueFormat::Text => hex::decode_to_slice(text_hex_decode_input(value)?, &mut bytes)?,
         };
         Ok(bytes)
     }
@@ -84,6 +84,7 @@ impl<'r> Decode<'r, Postgres> for NaiveDateTime {
     }
 }
 
+// TIMESTAMPTZ is stored in UTC, so the time zone is only used to find the UTC instant
 impl<Tz: TimeZone> Encode<'_, Postgres> for DateTime<Tz> {
     fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
         Encode::<Postgres>::encode(self.naive_utc(), buf)
@@ -108,6 +109,7 @@ impl<'r> Decode<'r, Postgres> for DateTime<Utc> {
     }
 }
 
+// the offset of the session's `TimeZone` is not sent in the binary format, so this is always UTC
 impl<'r> Decode<'r, Postgres> for DateTime<FixedOffset> {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
         let naive = <NaiveDateTime as Decode<Postgres>>::decode(value)?;
@@ -1,13 +1,12 @@
-use num_bigint::{BigInt, Sign};
-use rust_decimal::{
-    prelude::{ToPrimitive, Zero},
-    Decimal,
-};
+use std::cmp;
+
+use rust_decimal::{prelude::Zero, Decimal};
 
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::postgres::types::numeric::{PgNumeric, PgNumericSign};
+use crate::postgres::types::PgMoney;
 use crate::postgres::{
     PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
 };
@@ -17,6 +16,10 @@ impl Type<Postgres> for Decimal {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::NUMERIC
     }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::NUMERIC || *ty == PgTypeInfo::MONEY
+    }
 }
 
 impl PgHasArrayType for Decimal {
@@ -29,13 +32,13 @@ impl TryFrom<PgNumeric> for Decimal {
     type Error = BoxDynError;
 
     fn try_from(numeric: PgNumeric) -> Result<Self, BoxDynError> {
-        let (digits, sign, weight) = match numeric {
+        let (digits, sign, scale, weight) = match numeric {
             PgNumeric::Number {
                 digits,
                 sign,
+                scale,
                 weight,
-                ..
-            } => (digits, sign, weight),
+            } => (digits, sign, scale, weight),
 
             PgNumeric::NotANumber => {
                 return Err("Decimal does not support NaN values".into());
@@ -43,39 +46,72 @@ impl TryFrom<PgNumeric> for Decimal {
         };
 
         if digits.is_empty() {
-            // Postgres returns an empty digit array for 0 but BigInt expects at least one zero
-            return Ok(0u64.into());
+            // Postgres returns an empty digit array for 0
+            return Ok(Decimal::new(0, scale.clamp(0, 28) as u32));
         }
 
-        let sign = match sign {
-            PgNumericSign::Positive => Sign::Plus,
-            PgNumericSign::Negative => Sign::Minus,
-        };
+        let out_of_range = || BoxDynError::from("NUMERIC value out of range for Decimal");
+
+        // accumulate the base-10000 digits into what will be the 96-bit mantissa of the
+        // `Decimal`, leaving it to be divided by `10^exp`
+        let mut mantissa: u128 = 0;
+
+        for &digit in &digits {
+            if !(0..10_000).contains(&digit) {
+                return Err("PgNumeric contained an out-of-range digit".into());
+            }
+
+            mantissa = mantissa
+                .checked_mul(10_000)
+                .and_then(|mantissa| mantissa.checked_add(digit as u128))
+                .ok_or_else(out_of_range)?;
+        }
 
         // weight is 0 if the decimal point falls after the first base-10000 digit
-        let scale = (digits.len() as i64 - weight as i64 - 1) * 4;
+        let mut exp = (digits.len() as i64 - weight as i64 - 1) * 4;
+
+        // the last digit comes before the decimal point, so there is nothing on the right and
+        // we must add zeroes to the left
+        while exp < 0 {
+            mantissa = mantissa.checked_mul(10).ok_or_else(out_of_range)?;
+            exp += 1;
+        }
 
-        // no optimized algorithm for base-10 so use base-100 for faster processing
-        let mut cents = Vec::with_capacity(digits.len() * 2);
-        for digit in &digits {
-            cents.push((digit / 100) as u8);
-            cents.push((digit % 100) as u8);
+        let scale = i64::from(cmp::max(scale, 0));
+
+        // the last digit may end in zeroes past the display scale, which are dropped so that
+        // the scale is the same as when the value is decoded from text
+        while exp > scale && mantissa % 10 == 0 {
+            mantissa /= 10;
+            exp -= 1;
+        }
+
+        // Postgres does not send the digits that are all zeroes at the end, so zeroes may be
+        // missing up to the display scale instead; add as many as `Decimal` has room for
+        while exp < cmp::min(scale, 28) && mantissa * 10 < 1 << 96 {
+            mantissa *= 10;
+            exp += 1;
         }
 
-        let bigint = BigInt::from_radix_be(sign, &cents, 100)
-            .ok_or("PgNumeric contained an out-of-range digit")?;
-
-        match (bigint.to_i128(), scale) {
-            // A negative scale, meaning we have nothing on the right and must
-            // add zeroes to the left.
-            (Some(num), scale) if scale < 0 => Ok(Decimal::from_i128_with_scale(
-                num * 10i128.pow(scale.abs() as u32),
-                0,
-            )),
-            // A positive scale, so we have decimals on the right.
-            (Some(num), _) => Ok(Decimal::from_i128_with_scale(num, scale as u32)),
-            (None, _) => Err("Decimal's integer part out of range.".into()),
+        if exp > 28 {
+            return Err(format!(
+                "NUMERIC value with {} digits after the decimal point out of range for Decimal, \
+                 which has at most 28",
+                exp
+            )
+            .into());
         }
+
+        if mantissa >= 1 << 96 {
+            return Err(out_of_range());
+        }
+
+        let mantissa = match sign {
+            PgNumericSign::Positive => mantissa as i128,
+            PgNumericSign::Negative => -(mantissa as i128),
+        };
+
+        Ok(Decimal::from_i128_with_scale(mantissa, exp as u32))
     }
 }
 
@@ -87,7 +123,7 @@ impl TryFrom<&'_ Decimal> for PgNumeric {
         if Zero::is_zero(decimal) {
             return Ok(PgNumeric::Number {
                 sign: PgNumericSign::Positive,
-                scale: 0,
+                scale: decimal.scale() as i16,
                 weight: 0,
                 digits: vec![],
             });
@@ -162,8 +198,14 @@ impl Encode<'_, Postgres> for Decimal {
     }
 }
 
+/// `MONEY` values are decoded with two fractional digits, which is what most locales use;
+/// decode to [`PgMoney`] instead for a locale with a different `frac_digits`.
 impl Decode<'_, Postgres> for Decimal {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        if value.type_info == PgTypeInfo::MONEY {
+            return Ok(PgMoney::decode(value)?.to_decimal(2));
+        }
+
         match value.format() {
             PgValueFormat::Binary => PgNumeric::decode(value.as_bytes()?)?.try_into(),
             PgValueFormat::Text => Ok(value.as_str()?.parse::<Decimal>()?),
@@ -406,3 +448,109 @@ mod decimal_to_pgnumeric {
         );
     }
 }
+
+#[cfg(test)]
+mod pgnumeric_to_decimal {
+    use super::{Decimal, PgNumeric, PgNumericSign};
+    use std::convert::TryFrom;
+
+    fn round_trip(decimal: Decimal) -> Decimal {
+        Decimal::try_from(PgNumeric::try_from(&decimal).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn it_keeps_the_display_scale() {
+        // `1.50`, sent as the digits `1` and `5000` with a display scale of 2
+        let numeric = PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            scale: 2,
+            weight: 0,
+            digits: vec![1, 5000],
+        };
+
+        assert_eq!(Decimal::try_from(numeric).unwrap().to_string(), "1.50");
+    }
+
+    #[test]
+    fn it_adds_zeroes_up_to_the_display_scale() {
+        // `1.50000`, sent as the digits `1` and `5000` with a display scale of 5
+        let numeric = PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            scale: 5,
+            weight: 0,
+            digits: vec![1, 5000],
+        };
+
+        assert_eq!(Decimal::try_from(numeric).unwrap().to_string(), "1.50000");
+
+        // `12.0`, sent as the digit `12` with a display scale of 1
+        let numeric = PgNumeric::Number {
+            sign: PgNumericSign::Negative,
+            scale: 1,
+            weight: 0,
+            digits: vec![12],
+        };
+
+        assert_eq!(Decimal::try_from(numeric).unwrap().to_string(), "-12.0");
+    }
+
+    #[test]
+    fn it_adds_zeroes_before_the_point() {
+        // `-120000000`, sent as the digits `1` and `2000` with a weight of 2
+        let numeric = PgNumeric::Number {
+            sign: PgNumericSign::Negative,
+            scale: 0,
+            weight: 2,
+            digits: vec![1, 2000],
+        };
+
+        assert_eq!(
+            Decimal::try_from(numeric).unwrap().to_string(),
+            "-120000000"
+        );
+    }
+
+    #[test]
+    fn it_round_trips_the_range_of_decimal() {
+        for value in [
+            Decimal::MAX,
+            Decimal::MIN,
+            "0.0000000000000000000000000001".parse().unwrap(),
+            "-0.0000000000000000000000000001".parse().unwrap(),
+            "7.9228162514264337593543950335".parse().unwrap(),
+            "79228162514264.337593543950335".parse().unwrap(),
+            "10000".parse().unwrap(),
+            "0.000".parse().unwrap(),
+            "0.5".parse().unwrap(),
+            "-1234.56789".parse().unwrap(),
+        ] {
+            let decoded = round_trip(value);
+
+            assert_eq!(decoded, value);
+            assert_eq!(decoded.to_string(), value.to_string());
+        }
+    }
+
+    #[test]
+    fn it_rejects_values_out_of_range() {
+        // 10^29, one more digit than `Decimal::MAX`
+        let too_large = PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            scale: 0,
+            weight: 7,
+            digits: vec![10],
+        };
+
+        assert!(Decimal::try_from(too_large).is_err());
+
+        // 10^-32
+        let too_small = PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            scale: 32,
+            weight: -8,
+            digits: vec![1],
+        };
+
+        assert!(Decimal::try_from(too_small).is_err());
+    }
+}
@@ -0,0 +1,269 @@
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{
+    PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
+use crate::types::Type;
+
+// https://www.postgresql.org/docs/current/datatype-geometric.html
+//
+// In the binary format, each of these types is sent as its coordinates, one `float8` after
+// the other. In the text format, the coordinates are separated by punctuation which tells
+// the types apart, e.g. `(1,2)` for a point or `<(1,2),3>` for a circle.
+
+/// A point on a plane, the Postgres `point` type.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// An infinite line, the Postgres `line` type.
+///
+/// The line is described by the equation `a*x + b*y + c = 0`, where `a` and `b` are not
+/// both zero.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgLine {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+/// A rectangular box, the Postgres `box` type.
+///
+/// Postgres reorders the corners of a box so that the first one is the upper right and the
+/// second one the lower left, whichever opposite corners it was given. Use [`PgBox::new`] to
+/// do the same, so that a box compares equal to itself once sent to the database and back.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgBox {
+    pub upper_right: PgPoint,
+    pub lower_left: PgPoint,
+}
+
+/// A circle, the Postgres `circle` type.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgCircle {
+    pub center: PgPoint,
+    pub radius: f64,
+}
+
+impl PgPoint {
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+impl PgLine {
+    pub fn new(a: f64, b: f64, c: f64) -> Self {
+        Self { a, b, c }
+    }
+}
+
+impl PgBox {
+    /// The box with the given opposite corners, in any order.
+    pub fn new(corner: PgPoint, opposite: PgPoint) -> Self {
+        Self {
+            upper_right: PgPoint::new(corner.x.max(opposite.x), corner.y.max(opposite.y)),
+            lower_left: PgPoint::new(corner.x.min(opposite.x), corner.y.min(opposite.y)),
+        }
+    }
+}
+
+impl PgCircle {
+    pub fn new(center: PgPoint, radius: f64) -> Self {
+        Self { center, radius }
+    }
+}
+
+macro_rules! impl_type {
+    ($ty:ty, $type_info:ident, $array_type_info:ident) => {
+        impl Type<Postgres> for $ty {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::$type_info
+            }
+        }
+
+        impl PgHasArrayType for $ty {
+            fn array_type_info() -> PgTypeInfo {
+                PgTypeInfo::$array_type_info
+            }
+        }
+    };
+}
+
+impl_type!(PgPoint, POINT, POINT_ARRAY);
+impl_type!(PgLine, LINE, LINE_ARRAY);
+impl_type!(PgBox, BOX, BOX_ARRAY);
+impl_type!(PgCircle, CIRCLE, CIRCLE_ARRAY);
+
+impl Encode<'_, Postgres> for PgPoint {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        encode_coordinates(buf, &[self.x, self.y])
+    }
+
+    fn size_hint(&self) -> usize {
+        2 * 8
+    }
+}
+
+impl Encode<'_, Postgres> for PgLine {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        encode_coordinates(buf, &[self.a, self.b, self.c])
+    }
+
+    fn size_hint(&self) -> usize {
+        3 * 8
+    }
+}
+
+impl Encode<'_, Postgres> for PgBox {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        encode_coordinates(
+            buf,
+            &[
+                self.upper_right.x,
+                self.upper_right.y,
+                self.lower_left.x,
+                self.lower_left.y,
+            ],
+        )
+    }
+
+    fn size_hint(&self) -> usize {
+        4 * 8
+    }
+}
+
+impl Encode<'_, Postgres> for PgCircle {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        encode_coordinates(buf, &[self.center.x, self.center.y, self.radius])
+    }
+
+    fn size_hint(&self) -> usize {
+        3 * 8
+    }
+}
+
+impl Decode<'_, Postgres> for PgPoint {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let [x, y] = decode_coordinates(value, "POINT")?;
+
+        Ok(PgPoint::new(x, y))
+    }
+}
+
+impl Decode<'_, Postgres> for PgLine {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let [a, b, c] = decode_coordinates(value, "LINE")?;
+
+        Ok(PgLine::new(a, b, c))
+    }
+}
+
+impl Decode<'_, Postgres> for PgBox {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        // the corners are already in order
+        let [x1, y1, x2, y2] = decode_coordinates(value, "BOX")?;
+
+        Ok(PgBox {
+            upper_right: PgPoint::new(x1, y1),
+            lower_left: PgPoint::new(x2, y2),
+        })
+    }
+}
+
+impl Decode<'_, Postgres> for PgCircle {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let [x, y, radius] = decode_coordinates(value, "CIRCLE")?;
+
+        Ok(PgCircle::new(PgPoint::new(x, y), radius))
+    }
+}
+
+fn encode_coordinates(buf: &mut PgArgumentBuffer, coordinates: &[f64]) -> IsNull {
+    for coordinate in coordinates {
+        buf.extend(&coordinate.to_be_bytes());
+    }
+
+    IsNull::No
+}
+
+fn decode_coordinates<const N: usize>(
+    value: PgValueRef<'_>,
+    name: &str,
+) -> Result<[f64; N], BoxDynError> {
+    let mut coordinates = [0.0; N];
+
+    match value.format() {
+        PgValueFormat::Binary => {
+            let buf = value.as_bytes()?;
+
+            if buf.len() != N * 8 {
+                return Err(
+                    format!("expected {} bytes for {}, got {}", N * 8, name, buf.len()).into(),
+                );
+            }
+
+            for (coordinate, bytes) in coordinates.iter_mut().zip(buf.chunks_exact(8)) {
+                *coordinate = BigEndian::read_f64(bytes);
+            }
+        }
+
+        PgValueFormat::Text => parse_coordinates(value.as_str()?, name, &mut coordinates)?,
+    }
+
+    Ok(coordinates)
+}
+
+fn parse_coordinates(s: &str, name: &str, coordinates: &mut [f64]) -> Result<(), BoxDynError> {
+    let mut parts = s
+        .split(|c| matches!(c, '(' | ')' | '[' | ']' | '<' | '>' | '{' | '}' | ','))
+        .map(str::trim)
+        .filter(|part| !part.is_empty());
+
+    for coordinate in coordinates.iter_mut() {
+        *coordinate = parts
+            .next()
+            .ok_or_else(|| format!("too few coordinates for {}: {:?}", name, s))?
+            .parse()?;
+    }
+
+    if parts.next().is_some() {
+        return Err(format!("too many coordinates for {}: {:?}", name, s).into());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_coordinates() {
+    let mut point = [0.0; 2];
+    parse_coordinates("(1.5,-2)", "POINT", &mut point).unwrap();
+    assert_eq!(point, [1.5, -2.0]);
+
+    let mut line = [0.0; 3];
+    parse_coordinates("{1,-1,0}", "LINE", &mut line).unwrap();
+    assert_eq!(line, [1.0, -1.0, 0.0]);
+
+    let mut r#box = [0.0; 4];
+    parse_coordinates("(3,4),(1,2)", "BOX", &mut r#box).unwrap();
+    assert_eq!(r#box, [3.0, 4.0, 1.0, 2.0]);
+
+    let mut circle = [0.0; 3];
+    parse_coordinates("<(1,2),Infinity>", "CIRCLE", &mut circle).unwrap();
+    assert_eq!(circle, [1.0, 2.0, f64::INFINITY]);
+
+    assert!(parse_coordinates("(1,2)", "LINE", &mut line).is_err());
+    assert!(parse_coordinates("(1,2,3)", "POINT", &mut point).is_err());
+}
+
+#[test]
+fn test_box_new_orders_the_corners() {
+    let r#box = PgBox::new(PgPoint::new(1.0, 4.0), PgPoint::new(3.0, 2.0));
+
+    assert_eq!(r#box.upper_right, PgPoint::new(3.0, 4.0));
+    assert_eq!(r#box.lower_left, PgPoint::new(1.0, 2.0));
+}
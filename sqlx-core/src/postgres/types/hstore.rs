@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+use std::str::Chars;
+
+use bytes::Buf;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{
+    PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
+use crate::types::Type;
+
+// `hstore` is a map of text keys to nullable text values
+// https://www.postgresql.org/docs/current/hstore.html
+//
+// Since `hstore` is enabled by an extension, it does not have a stable OID and is
+// resolved by name when the connection first sees it.
+
+impl Type<Postgres> for HashMap<String, Option<String>> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("hstore")
+    }
+}
+
+impl PgHasArrayType for HashMap<String, Option<String>> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_hstore")
+    }
+}
+
+impl Encode<'_, Postgres> for HashMap<String, Option<String>> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.extend(&(self.len() as i32).to_be_bytes());
+
+        for (key, value) in self {
+            buf.extend(&(key.len() as i32).to_be_bytes());
+            buf.extend(key.as_bytes());
+
+            match value {
+                Some(value) => {
+                    buf.extend(&(value.len() as i32).to_be_bytes());
+                    buf.extend(value.as_bytes());
+                }
+
+                None => {
+                    buf.extend(&(-1_i32).to_be_bytes());
+                }
+            }
+        }
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        4 + self
+            .iter()
+            .map(|(key, value)| 8 + key.len() + value.as_ref().map_or(0, String::len))
+            .sum::<usize>()
+    }
+}
+
+impl Decode<'_, Postgres> for HashMap<String, Option<String>> {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => decode_binary(value.as_bytes()?),
+            PgValueFormat::Text => decode_text(value.as_str()?),
+        }
+    }
+}
+
+fn decode_binary(mut buf: &[u8]) -> Result<HashMap<String, Option<String>>, BoxDynError> {
+    let count = read_len(&mut buf)?.ok_or("hstore: negative number of pairs")?;
+    let mut map = HashMap::with_capacity(count);
+
+    for _ in 0..count {
+        let key = read_str(&mut buf)?.ok_or("hstore: unexpected NULL key")?;
+        let value = read_str(&mut buf)?;
+
+        map.insert(key, value);
+    }
+
+    Ok(map)
+}
+
+fn read_len(buf: &mut &[u8]) -> Result<Option<usize>, BoxDynError> {
+    if buf.remaining() < 4 {
+        return Err("hstore: unexpected end of input".into());
+    }
+
+    let len = buf.get_i32();
+
+    Ok(if len < 0 { None } else { Some(len as usize) })
+}
+
+fn read_str(buf: &mut &[u8]) -> Result<Option<String>, BoxDynError> {
+    let len = match read_len(buf)? {
+        Some(len) => len,
+        None => return Ok(None),
+    };
+
+    if buf.remaining() < len {
+        return Err("hstore: unexpected end of input".into());
+    }
+
+    let s = std::str::from_utf8(&buf[..len])?.to_owned();
+    buf.advance(len);
+
+    Ok(Some(s))
+}
+
+// "key"=>"value", "other key"=>NULL
+fn decode_text(s: &str) -> Result<HashMap<String, Option<String>>, BoxDynError> {
+    let mut map = HashMap::new();
+    let mut chars = s.chars();
+
+    loop {
+        skip_whitespace(&mut chars);
+
+        if chars.as_str().is_empty() {
+            break;
+        }
+
+        let (key, _) = read_token(&mut chars)?;
+
+        skip_whitespace(&mut chars);
+
+        if !chars.as_str().starts_with("=>") {
+            return Err(format!("hstore: expected `=>` after key {:?}", key).into());
+        }
+
+        chars.nth(1);
+        skip_whitespace(&mut chars);
+
+        let value = match read_token(&mut chars)? {
+            (value, false) if value.eq_ignore_ascii_case("NULL") => None,
+            (value, _) => Some(value),
+        };
+
+        map.insert(key, value);
+
+        skip_whitespace(&mut chars);
+
+        match chars.next() {
+            Some(',') | None => {}
+            Some(c) => return Err(format!("hstore: unexpected character `{}`", c).into()),
+        }
+    }
+
+    Ok(map)
+}
+
+fn skip_whitespace(chars: &mut Chars<'_>) {
+    *chars = chars.as_str().trim_start().chars();
+}
+
+// returns the token and whether it was quoted
+fn read_token(chars: &mut Chars<'_>) -> Result<(String, bool), BoxDynError> {
+    let mut token = String::new();
+
+    if chars.as_str().starts_with('"') {
+        chars.next();
+
+        loop {
+            match chars.next() {
+                Some('"') => return Ok((token, true)),
+                Some('\\') => token.push(chars.next().ok_or("hstore: unexpected end of input")?),
+                Some(c) => token.push(c),
+                None => return Err("hstore: unterminated quoted string".into()),
+            }
+        }
+    }
+
+    while let Some(c) = chars.clone().next() {
+        if c.is_whitespace() || c == ',' || chars.as_str().starts_with("=>") {
+            break;
+        }
+
+        token.push(c);
+        chars.next();
+    }
+
+    if token.is_empty() {
+        return Err("hstore: expected a key or value".into());
+    }
+
+    Ok((token, false))
+}
+
+#[test]
+fn test_decode_text_hstore() {
+    let map = decode_text(r#""a"=>"1", "b"=>NULL, "c"=>"NULL""#).unwrap();
+
+    assert_eq!(map.len(), 3);
+    assert_eq!(map["a"].as_deref(), Some("1"));
+    assert_eq!(map["b"], None);
+    assert_eq!(map["c"].as_deref(), Some("NULL"));
+
+    let map = decode_text(r#""k=>\"e,y\\"=>"v, \"w\"", plain => value"#).unwrap();
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[r#"k=>"e,y\"#].as_deref(), Some(r#"v, "w""#));
+    assert_eq!(map["plain"].as_deref(), Some("value"));
+
+    assert!(decode_text("").unwrap().is_empty());
+    assert!(decode_text(r#""a"=>"1" "b""#).is_err());
+    assert!(decode_text(r#""a"=>"1"#).is_err());
+}
+
+#[test]
+fn test_encode_decode_binary_hstore() {
+    let mut map = HashMap::new();
+    map.insert("a\"=>,".to_owned(), Some("".to_owned()));
+    map.insert("".to_owned(), None);
+
+    let mut buf = PgArgumentBuffer::default();
+    let _ = Encode::<Postgres>::encode_by_ref(&map, &mut buf);
+
+    assert_eq!(decode_binary(&buf).unwrap(), map);
+    assert!(decode_binary(&buf[..buf.len() - 1]).is_err());
+    assert!(decode_binary(&0_i32.to_be_bytes()).unwrap().is_empty());
+}
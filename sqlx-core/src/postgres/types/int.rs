@@ -30,8 +30,39 @@ impl Encode<'_, Postgres> for i8 {
 
 impl Decode<'_, Postgres> for i8 {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
-        // note: in the TEXT encoding, a value of "0" here is encoded as an empty string
-        Ok(value.as_bytes()?.get(0).copied().unwrap_or_default() as i8)
+        Ok(match value.format() {
+            PgValueFormat::Binary => match value.as_bytes()? {
+                [byte] => *byte as i8,
+                bytes => {
+                    return Err(format!("expected 1 byte for \"char\", got {}", bytes.len()).into())
+                }
+            },
+            PgValueFormat::Text => decode_char_text(value.as_bytes()?)? as i8,
+        })
+    }
+}
+
+// https://github.com/postgres/postgres/blob/REL_15_0/src/backend/utils/adt/char.c#L63
+fn decode_char_text(text: &[u8]) -> Result<u8, BoxDynError> {
+    match text {
+        // a value of 0 is encoded as an empty string
+        [] => Ok(0),
+
+        [byte] => Ok(*byte),
+
+        // since Postgres 15, a backslash is escaped and bytes outside of ASCII are encoded as
+        // an octal escape
+        [b'\\', b'\\'] => Ok(b'\\'),
+
+        [b'\\', digits @ ..] if digits.len() == 3 => {
+            Ok(u8::from_str_radix(std::str::from_utf8(digits)?, 8)?)
+        }
+
+        _ => Err(format!(
+            "invalid \"char\" value: {:?}",
+            String::from_utf8_lossy(text)
+        )
+        .into()),
     }
 }
 
@@ -121,3 +152,13 @@ impl Decode<'_, Postgres> for i64 {
         })
     }
 }
+
+#[test]
+fn it_decodes_char_text() {
+    assert_eq!(decode_char_text(b"").unwrap(), 0);
+    assert_eq!(decode_char_text(b"r").unwrap(), b'r');
+    assert_eq!(decode_char_text(b"\\\\").unwrap(), b'\\');
+    assert_eq!(decode_char_text(b"\\310").unwrap(), 200);
+    assert!(decode_char_text(b"ab").is_err());
+    assert!(decode_char_text(b"\\400").is_err());
+}
@@ -19,6 +19,25 @@ pub struct PgInterval {
     pub microseconds: i64,
 }
 
+impl PgInterval {
+    // the length of the interval in microseconds, which is only fixed if it has no months;
+    // days are taken to be 24 hours long, and may have a different sign than the microseconds
+    fn fixed_microseconds(&self, target: &str) -> Result<i64, BoxDynError> {
+        if self.months != 0 {
+            return Err(format!(
+                "`{}` cannot represent a PostgreSQL `INTERVAL` with months",
+                target
+            )
+            .into());
+        }
+
+        i64::from(self.days)
+            .checked_mul(24 * 60 * 60 * 1_000_000)
+            .and_then(|days| days.checked_add(self.microseconds))
+            .ok_or_else(|| "Overflow has occurred for PostgreSQL `INTERVAL`".into())
+    }
+}
+
 impl Type<Postgres> for PgInterval {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::INTERVAL
@@ -71,6 +90,9 @@ impl Encode<'_, Postgres> for PgInterval {
 
 // We then implement Encode + Type for std Duration, chrono Duration, and time Duration
 // This is to enable ease-of-use for encoding when its simple
+//
+// `std::time::Duration` can also be decoded as long as the interval has no months and is not
+// negative
 
 impl Type<Postgres> for std::time::Duration {
     fn type_info() -> PgTypeInfo {
@@ -96,6 +118,30 @@ impl Encode<'_, Postgres> for std::time::Duration {
     }
 }
 
+impl<'de> Decode<'de, Postgres> for std::time::Duration {
+    fn decode(value: PgValueRef<'de>) -> Result<Self, BoxDynError> {
+        PgInterval::decode(value)?.try_into()
+    }
+}
+
+impl TryFrom<PgInterval> for std::time::Duration {
+    type Error = BoxDynError;
+
+    /// Convert a `PgInterval` to a `std::time::Duration`.
+    ///
+    /// This returns an error if the interval contains months, as they have no fixed length, or
+    /// if it is negative. Days are taken to be 24 hours long.
+    fn try_from(value: PgInterval) -> Result<Self, BoxDynError> {
+        let microseconds = value.fixed_microseconds("std::time::Duration")?;
+
+        let microseconds = u64::try_from(microseconds).map_err(|_| {
+            "`std::time::Duration` cannot represent a negative PostgreSQL `INTERVAL`"
+        })?;
+
+        Ok(std::time::Duration::from_micros(microseconds))
+    }
+}
+
 impl TryFrom<std::time::Duration> for PgInterval {
     type Error = BoxDynError;
 
@@ -142,6 +188,28 @@ impl Encode<'_, Postgres> for chrono::Duration {
     }
 }
 
+#[cfg(feature = "chrono")]
+impl<'de> Decode<'de, Postgres> for chrono::Duration {
+    fn decode(value: PgValueRef<'de>) -> Result<Self, BoxDynError> {
+        PgInterval::decode(value)?.try_into()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<PgInterval> for chrono::Duration {
+    type Error = BoxDynError;
+
+    /// Convert a `PgInterval` to a `chrono::Duration`.
+    ///
+    /// This returns an error if the interval contains months, as they have no fixed length.
+    /// Days are taken to be 24 hours long.
+    fn try_from(value: PgInterval) -> Result<Self, BoxDynError> {
+        Ok(chrono::Duration::microseconds(
+            value.fixed_microseconds("chrono::Duration")?,
+        ))
+    }
+}
+
 #[cfg(feature = "chrono")]
 impl TryFrom<chrono::Duration> for PgInterval {
     type Error = BoxDynError;
@@ -204,6 +272,28 @@ impl Encode<'_, Postgres> for time::Duration {
     }
 }
 
+#[cfg(feature = "time")]
+impl<'de> Decode<'de, Postgres> for time::Duration {
+    fn decode(value: PgValueRef<'de>) -> Result<Self, BoxDynError> {
+        PgInterval::decode(value)?.try_into()
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<PgInterval> for time::Duration {
+    type Error = BoxDynError;
+
+    /// Convert a `PgInterval` to a `time::Duration`.
+    ///
+    /// This returns an error if the interval contains months, as they have no fixed length.
+    /// Days are taken to be 24 hours long.
+    fn try_from(value: PgInterval) -> Result<Self, BoxDynError> {
+        Ok(time::Duration::microseconds(
+            value.fixed_microseconds("time::Duration")?,
+        ))
+    }
+}
+
 #[cfg(feature = "time")]
 impl TryFrom<time::Duration> for PgInterval {
     type Error = BoxDynError;
@@ -325,6 +415,47 @@ fn test_pginterval_std() {
     assert!(PgInterval::try_from(std::time::Duration::from_secs(20_000_000_000_000)).is_err());
 }
 
+#[test]
+fn test_std_from_pginterval() {
+    // Case for sub-second precision
+    let interval = PgInterval {
+        days: 0,
+        months: 0,
+        microseconds: 1_500_000,
+    };
+    assert_eq!(
+        std::time::Duration::try_from(interval).unwrap(),
+        std::time::Duration::from_millis(1500)
+    );
+
+    // Case for days, which are 24 hours long
+    let interval = PgInterval {
+        days: 2,
+        months: 0,
+        microseconds: -3_600_000_000,
+    };
+    assert_eq!(
+        std::time::Duration::try_from(interval).unwrap(),
+        std::time::Duration::from_secs(47 * 3600)
+    );
+
+    // Case for negative duration
+    let interval = PgInterval {
+        days: 0,
+        months: 0,
+        microseconds: -1,
+    };
+    assert!(std::time::Duration::try_from(interval).is_err());
+
+    // Case when the interval has months
+    let interval = PgInterval {
+        days: 0,
+        months: 1,
+        microseconds: 0,
+    };
+    assert!(std::time::Duration::try_from(interval).is_err());
+}
+
 #[test]
 #[cfg(feature = "chrono")]
 fn test_pginterval_chrono() {
@@ -359,6 +490,39 @@ fn test_pginterval_chrono() {
     assert!(PgInterval::try_from(chrono::Duration::seconds(-10_000_000_000)).is_err());
 }
 
+#[test]
+#[cfg(feature = "chrono")]
+fn test_chrono_from_pginterval() {
+    // Case for days and microseconds of different signs
+    let interval = PgInterval {
+        days: 2,
+        months: 0,
+        microseconds: -3_600_000_000,
+    };
+    assert_eq!(
+        chrono::Duration::try_from(interval).unwrap(),
+        chrono::Duration::hours(47)
+    );
+
+    let interval = PgInterval {
+        days: -1,
+        months: 0,
+        microseconds: 3_600_000_000,
+    };
+    assert_eq!(
+        chrono::Duration::try_from(interval).unwrap(),
+        chrono::Duration::hours(-23)
+    );
+
+    // Case when the interval has months
+    let interval = PgInterval {
+        days: 0,
+        months: -1,
+        microseconds: 0,
+    };
+    assert!(chrono::Duration::try_from(interval).is_err());
+}
+
 #[test]
 #[cfg(feature = "time")]
 fn test_pginterval_time() {
@@ -392,3 +556,26 @@ fn test_pginterval_time() {
     assert!(PgInterval::try_from(time::Duration::seconds(10_000_000_000_000)).is_err());
     assert!(PgInterval::try_from(time::Duration::seconds(-10_000_000_000_000)).is_err());
 }
+
+#[test]
+#[cfg(feature = "time")]
+fn test_time_from_pginterval() {
+    // Case for days and microseconds of different signs
+    let interval = PgInterval {
+        days: -1,
+        months: 0,
+        microseconds: 3_600_000_000,
+    };
+    assert_eq!(
+        time::Duration::try_from(interval).unwrap(),
+        time::Duration::hours(-23)
+    );
+
+    // Case when the interval has months
+    let interval = PgInterval {
+        days: 0,
+        months: 1,
+        microseconds: 0,
+    };
+    assert!(time::Duration::try_from(interval).is_err());
+}
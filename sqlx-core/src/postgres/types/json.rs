@@ -16,6 +16,8 @@ use serde_json::Value as JsonValue;
 // unless there are quite specialized needs, such as legacy assumptions
 // about ordering of object keys.
 
+const JSONB_VERSION: u8 = 1;
+
 impl<T> Type<Postgres> for Json<T> {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::JSONB
@@ -70,7 +72,7 @@ where
         });
 
         // JSONB version (as of 2020-03-20)
-        buf.push(1);
+        buf.push(JSONB_VERSION);
 
         // the JSON data written to the buffer is the same regardless of parameter type
         serde_json::to_writer(&mut **buf, &self.0)
@@ -87,16 +89,52 @@ where
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
         let mut buf = value.as_bytes()?;
 
+        // the binary format of JSONB is its text prefixed by a version number, which is the
+        // only part that may change in a future version of the format
         if value.format() == PgValueFormat::Binary && value.type_info == PgTypeInfo::JSONB {
-            assert_eq!(
-                buf[0], 1,
-                "unsupported JSONB format version {}; please open an issue",
-                buf[0]
-            );
-
-            buf = &buf[1..];
+            buf = match buf.split_first() {
+                Some((&JSONB_VERSION, text)) => text,
+
+                Some((version, _)) => {
+                    return Err(format!(
+                        "unsupported JSONB format version {}; please open an issue",
+                        version
+                    )
+                    .into());
+                }
+
+                None => return Err("invalid JSONB value: missing format version".into()),
+            };
         }
 
         serde_json::from_slice(buf).map(Json).map_err(Into::into)
     }
 }
+
+#[test]
+fn it_decodes_jsonb_in_binary_format() {
+    let decode = |bytes: &'static [u8]| {
+        <JsonValue as Decode<Postgres>>::decode(PgValueRef {
+            value: Some(bytes),
+            row: None,
+            type_info: PgTypeInfo::JSONB,
+            format: PgValueFormat::Binary,
+        })
+    };
+
+    assert_eq!(
+        decode(b"\x01{\"hello\": [1, 2]}").unwrap(),
+        serde_json::json!({ "hello": [1, 2] })
+    );
+
+    let error = decode(b"\x02{\"hello\": [1, 2]}").unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("unsupported JSONB format version 2"),
+        "{}",
+        error
+    );
+
+    assert!(decode(b"").is_err());
+}
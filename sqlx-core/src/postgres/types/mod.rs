@@ -9,28 +9,39 @@
 //! | `i16`                                 | SMALLINT, SMALLSERIAL, INT2                          |
 //! | `i32`                                 | INT, SERIAL, INT4                                    |
 //! | `i64`                                 | BIGINT, BIGSERIAL, INT8                              |
+//! | `u32`, [`Oid`]                        | OID, REGCLASS, REGTYPE                               |
 //! | `f32`                                 | REAL, FLOAT4                                         |
 //! | `f64`                                 | DOUBLE PRECISION, FLOAT8                             |
-//! | `&str`, [`String`]                    | VARCHAR, CHAR(N), TEXT, NAME                         |
+//! | `&str`, [`String`]                    | VARCHAR, CHAR(N), TEXT, NAME, REGCLASS¹, REGTYPE¹    |
 //! | `&[u8]`, `Vec<u8>`                    | BYTEA                                                |
 //! | [`PgInterval`]                        | INTERVAL                                             |
+//! | `std::time::Duration`                 | INTERVAL                                             |
 //! | [`PgRange<T>`](PgRange)               | INT8RANGE, INT4RANGE, TSRANGE, TSTZRANGE, DATERANGE, NUMRANGE |
 //! | [`PgMoney`]                           | MONEY                                                |
+//! | [`PgPoint`]                           | POINT                                                |
+//! | [`PgLine`]                            | LINE                                                 |
+//! | [`PgBox`]                             | BOX                                                  |
+//! | [`PgCircle`]                          | CIRCLE                                               |
+//! | [`PgTsVector`]                        | TSVECTOR²                                            |
 //!
+//! ¹ Only in the text format, as returned by simple queries. In the binary format, a REGCLASS or
+//! REGTYPE is sent as its OID; cast it with `::text` for its name.
+//!
+//! ² Can only be decoded.
 //!
 //! ### [`bigdecimal`](https://crates.io/crates/bigdecimal)
 //! Requires the `bigdecimal` Cargo feature flag.
 //!
 //! | Rust type                             | Postgres type(s)                                        |
 //! |---------------------------------------|------------------------------------------------------|
-//! | `bigdecimal::BigDecimal`              | NUMERIC                                              |
+//! | `bigdecimal::BigDecimal`              | NUMERIC, MONEY                                       |
 //!
 //! ### [`decimal`](https://crates.io/crates/rust_decimal)
 //! Requires the `decimal` Cargo feature flag.
 //!
 //! | Rust type                             | Postgres type(s)                                        |
 //! |---------------------------------------|------------------------------------------------------|
-//! | `rust_decimal::Decimal`               | NUMERIC                                              |
+//! | `rust_decimal::Decimal`               | NUMERIC, MONEY                                       |
 //!
 //! ### [`chrono`](https://crates.io/crates/chrono)
 //!
@@ -40,10 +51,16 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `chrono::DateTime<Utc>`               | TIMESTAMPTZ                                          |
 //! | `chrono::DateTime<Local>`             | TIMESTAMPTZ                                          |
+//! | `chrono::DateTime<FixedOffset>`       | TIMESTAMPTZ                                          |
 //! | `chrono::NaiveDateTime`               | TIMESTAMP                                            |
 //! | `chrono::NaiveDate`                   | DATE                                                 |
 //! | `chrono::NaiveTime`                   | TIME                                                 |
 //! | [`PgTimeTz`]                          | TIMETZ                                               |
+//! | `chrono::Duration`                    | INTERVAL                                             |
+//!
+//! A `chrono::DateTime` in any time zone can be bound, and is converted to UTC. A column is
+//! decoded in UTC, or in the local time zone as `DateTime<Local>`; a `DateTime<FixedOffset>`
+//! always has an offset of zero.
 //!
 //! ### [`time`](https://crates.io/crates/time)
 //!
@@ -56,6 +73,7 @@
 //! | `time::Date`                          | DATE                                                 |
 //! | `time::Time`                          | TIME                                                 |
 //! | [`PgTimeTz`]                          | TIMETZ                                               |
+//! | `time::Duration`                      | INTERVAL                                             |
 //!
 //! ### [`uuid`](https://crates.io/crates/uuid)
 //!
@@ -96,6 +114,29 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `bit_vec::BitVec`                     | BIT, VARBIT                                          |
 //!
+//! ### [`hstore`](https://www.postgresql.org/docs/current/hstore.html)
+//!
+//! Requires the `hstore` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `HashMap<String, Option<String>>`     | HSTORE                                               |
+//!
+//! The `hstore` extension must be enabled in the database (`CREATE EXTENSION hstore`).
+//!
+//! ### [`postgis`](https://postgis.net/)
+//!
+//! Requires the `postgis` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `geo_types::Point<f64>`               | GEOMETRY                                             |
+//! | `PgGeometry<geo_types::Point<f64>>`   | GEOMETRY                                             |
+//!
+//! The `postgis` extension must be enabled in the database (`CREATE EXTENSION postgis`).
+//! Points are sent with the SRID 4326 (WGS 84), unless they are wrapped in a [`PgGeometry`]
+//! that carries another one.
+//!
 //! ### [`json`](https://crates.io/crates/serde_json)
 //!
 //! Requires the `json` Cargo feature flag.
@@ -173,6 +214,7 @@ mod array;
 mod bool;
 mod bytes;
 mod float;
+mod geometric;
 mod int;
 mod interval;
 mod lquery;
@@ -182,6 +224,7 @@ mod oid;
 mod range;
 mod record;
 mod str;
+mod tsvector;
 mod tuple;
 mod void;
 
@@ -220,7 +263,14 @@ mod mac_address;
 #[cfg(feature = "bit-vec")]
 mod bit_vec;
 
+#[cfg(feature = "hstore")]
+mod hstore;
+
+#[cfg(feature = "postgis")]
+mod postgis;
+
 pub use array::PgHasArrayType;
+pub use geometric::{PgBox, PgCircle, PgLine, PgPoint};
 pub use interval::PgInterval;
 pub use lquery::PgLQuery;
 pub use lquery::PgLQueryLevel;
@@ -232,10 +282,14 @@ pub use ltree::PgLTreeParseError;
 pub use money::PgMoney;
 pub use oid::Oid;
 pub use range::PgRange;
+pub use tsvector::{PgTsLexeme, PgTsPosition, PgTsVector, PgTsWeight};
 
 #[cfg(any(feature = "chrono", feature = "time"))]
 pub use time_tz::PgTimeTz;
 
+#[cfg(feature = "postgis")]
+pub use postgis::{PgGeometry, SRID_WGS84};
+
 // used in derive(Type) for `struct`
 // but the interface is not considered part of the public API
 #[doc(hidden)]
@@ -5,6 +5,7 @@ use serde::{de::Deserializer, ser::Serializer, Deserialize, Serialize};
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
+use crate::postgres::types::array_compatible;
 use crate::postgres::{
     PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
 };
@@ -13,6 +14,10 @@ use crate::types::Type;
 /// The PostgreSQL [`OID`] type stores an object identifier,
 /// used internally by PostgreSQL as primary keys for various system tables.
 ///
+/// It also decodes the `REGCLASS` and `REGTYPE` reference types, which are sent as the OID of
+/// the relation or type they refer to in the binary format. In the text format, as returned
+/// by simple queries, they are sent as the name instead, which decodes as a [`String`].
+///
 /// [`OID`]: https://www.postgresql.org/docs/current/datatype-oid.html
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, Default)]
 pub struct Oid(
@@ -30,12 +35,40 @@ impl Type<Postgres> for Oid {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::OID
     }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        [PgTypeInfo::OID, PgTypeInfo::REGCLASS, PgTypeInfo::REGTYPE].contains(ty)
+    }
 }
 
 impl PgHasArrayType for Oid {
     fn array_type_info() -> PgTypeInfo {
         PgTypeInfo::OID_ARRAY
     }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        array_compatible::<Oid>(ty)
+    }
+}
+
+impl Type<Postgres> for u32 {
+    fn type_info() -> PgTypeInfo {
+        <Oid as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <Oid as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl PgHasArrayType for u32 {
+    fn array_type_info() -> PgTypeInfo {
+        <Oid as PgHasArrayType>::array_type_info()
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        <Oid as PgHasArrayType>::array_compatible(ty)
+    }
 }
 
 impl Encode<'_, Postgres> for Oid {
@@ -46,15 +79,48 @@ impl Encode<'_, Postgres> for Oid {
     }
 }
 
+impl Encode<'_, Postgres> for u32 {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        <Oid as Encode<Postgres>>::encode_by_ref(&Oid(*self), buf)
+    }
+}
+
 impl Decode<'_, Postgres> for Oid {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
         Ok(Self(match value.format() {
             PgValueFormat::Binary => BigEndian::read_u32(value.as_bytes()?),
-            PgValueFormat::Text => value.as_str()?.parse()?,
+
+            PgValueFormat::Text => {
+                let text = value.as_str()?;
+
+                match text.parse() {
+                    Ok(oid) => oid,
+
+                    // a REGCLASS or REGTYPE is sent as its name in the text format
+                    Err(_)
+                        if [PgTypeInfo::REGCLASS, PgTypeInfo::REGTYPE]
+                            .contains(&value.type_info) =>
+                    {
+                        return Err(format!(
+                            "a {} is sent as its name in the text format, which decodes as a String: {:?}",
+                            value.type_info, text
+                        )
+                        .into());
+                    }
+
+                    Err(error) => return Err(error.into()),
+                }
+            }
         }))
     }
 }
 
+impl Decode<'_, Postgres> for u32 {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(<Oid as Decode<Postgres>>::decode(value)?.0)
+    }
+}
+
 #[cfg(feature = "serde")]
 impl Serialize for Oid {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
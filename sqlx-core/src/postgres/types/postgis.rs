@@ -0,0 +1,204 @@
+use geo_types::Point;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{
+    PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
+use crate::types::Type;
+
+// PostGIS sends and receives geometries as EWKB, the well-known binary format extended with
+// an optional SRID
+// https://libgeos.org/specifications/wkb/#extended-wkb
+//
+// Since `geometry` is enabled by an extension, it does not have a stable OID and is
+// resolved by name when the connection first sees it.
+
+const WKB_POINT: u32 = 1;
+
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// The SRID of WGS 84, the coordinate system of GPS, which is assumed for a [`Point`] encoded
+/// without one.
+pub const SRID_WGS84: i32 = 4326;
+
+/// A PostGIS `geometry` along with the identifier of its spatial reference system (SRID).
+///
+/// A bare [`Point`] is encoded with the SRID [`SRID_WGS84`] (4326) and decoded regardless of its
+/// SRID; use `PgGeometry<Point>` to choose or read the SRID. An SRID of `0` stands for a
+/// geometry without one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PgGeometry<T> {
+    pub srid: i32,
+    pub geometry: T,
+}
+
+impl<T> PgGeometry<T> {
+    pub fn new(srid: i32, geometry: T) -> Self {
+        Self { srid, geometry }
+    }
+}
+
+impl Type<Postgres> for PgGeometry<Point<f64>> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("geometry")
+    }
+}
+
+impl PgHasArrayType for PgGeometry<Point<f64>> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_geometry")
+    }
+}
+
+impl Type<Postgres> for Point<f64> {
+    fn type_info() -> PgTypeInfo {
+        <PgGeometry<Point<f64>> as Type<Postgres>>::type_info()
+    }
+}
+
+impl PgHasArrayType for Point<f64> {
+    fn array_type_info() -> PgTypeInfo {
+        <PgGeometry<Point<f64>> as PgHasArrayType>::array_type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for PgGeometry<Point<f64>> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        // little-endian
+        buf.push(1);
+
+        if self.srid == 0 {
+            buf.extend(&WKB_POINT.to_le_bytes());
+        } else {
+            buf.extend(&(WKB_POINT | EWKB_SRID).to_le_bytes());
+            buf.extend(&self.srid.to_le_bytes());
+        }
+
+        buf.extend(&self.geometry.x().to_le_bytes());
+        buf.extend(&self.geometry.y().to_le_bytes());
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        1 + 4 + 4 + 2 * 8
+    }
+}
+
+impl Encode<'_, Postgres> for Point<f64> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        PgGeometry::new(SRID_WGS84, *self).encode_by_ref(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        PgGeometry::new(SRID_WGS84, *self).size_hint()
+    }
+}
+
+impl Decode<'_, Postgres> for PgGeometry<Point<f64>> {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => decode_point(value.as_bytes()?),
+
+            // the text output of a geometry is its EWKB in hex
+            PgValueFormat::Text => decode_point(&hex::decode(value.as_str()?)?),
+        }
+    }
+}
+
+impl Decode<'_, Postgres> for Point<f64> {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(PgGeometry::<Point<f64>>::decode(value)?.geometry)
+    }
+}
+
+fn decode_point(mut buf: &[u8]) -> Result<PgGeometry<Point<f64>>, BoxDynError> {
+    let little_endian = match take::<1>(&mut buf)? {
+        [0] => false,
+        [1] => true,
+        [order] => return Err(format!("invalid EWKB byte order: {}", order).into()),
+    };
+
+    let u32_from = if little_endian {
+        u32::from_le_bytes
+    } else {
+        u32::from_be_bytes
+    };
+
+    let f64_from = if little_endian {
+        f64::from_le_bytes
+    } else {
+        f64::from_be_bytes
+    };
+
+    let ty = u32_from(take(&mut buf)?);
+
+    if ty & !(EWKB_Z | EWKB_M | EWKB_SRID) != WKB_POINT {
+        return Err(format!(
+            "expected a geometry of type POINT (1), got type {}",
+            ty & 0x0fff_ffff
+        )
+        .into());
+    }
+
+    if ty & (EWKB_Z | EWKB_M) != 0 {
+        return Err("points with a Z or M coordinate are not supported".into());
+    }
+
+    let srid = if ty & EWKB_SRID != 0 {
+        u32_from(take(&mut buf)?) as i32
+    } else {
+        0
+    };
+
+    let x = f64_from(take(&mut buf)?);
+    let y = f64_from(take(&mut buf)?);
+
+    if !buf.is_empty() {
+        return Err(format!("{} unexpected bytes after EWKB POINT", buf.len()).into());
+    }
+
+    Ok(PgGeometry::new(srid, Point::new(x, y)))
+}
+
+fn take<const N: usize>(buf: &mut &[u8]) -> Result<[u8; N], BoxDynError> {
+    if buf.len() < N {
+        return Err("unexpected end of EWKB geometry".into());
+    }
+
+    let (bytes, rest) = buf.split_at(N);
+    *buf = rest;
+
+    Ok(bytes.try_into()?)
+}
+
+#[test]
+fn test_decode_point() {
+    // SELECT ST_AsEWKB('SRID=4326;POINT(1.5 -2)'::geometry)
+    let ewkb = hex::decode("0101000020E6100000000000000000F83F00000000000000C0").unwrap();
+
+    assert_eq!(
+        decode_point(&ewkb).unwrap(),
+        PgGeometry::new(4326, Point::new(1.5, -2.0))
+    );
+
+    // SELECT ST_AsBinary('POINT(1.5 -2)'::geometry, 'XDR')
+    let wkb = hex::decode("00000000013FF8000000000000C000000000000000").unwrap();
+
+    assert_eq!(
+        decode_point(&wkb).unwrap(),
+        PgGeometry::new(0, Point::new(1.5, -2.0))
+    );
+
+    // SELECT ST_AsEWKB('LINESTRING(0 0, 1 1)'::geometry)
+    let line = hex::decode(
+        "010200000002000000000000000000000000000000000000000000000000F03F000000000000F03F",
+    )
+    .unwrap();
+
+    assert!(decode_point(&line).is_err());
+}
@@ -28,6 +28,20 @@ bitflags! {
   }
 }
 
+/// A PostgreSQL range, such as `int4range`, `int8range`, `numrange`, `daterange`, `tsrange` or
+/// `tstzrange`.
+///
+/// The bounds are available either through the public fields or through [`RangeBounds`], and
+/// follow the flags PostgreSQL sends with the range: `[` and `]` are [`Bound::Included`], `(` and
+/// `)` are [`Bound::Excluded`] and an infinite endpoint is [`Bound::Unbounded`].
+///
+/// Note that PostgreSQL normalizes discrete ranges, so `'(1,2]'::int4range` decodes as `[2,3)`.
+///
+/// ### Empty ranges
+/// An `empty` range has no bounds, and decodes with both bounds [`Bound::Unbounded`], which
+/// cannot be told apart from the infinite range `(,)`. Use `isempty()` in the query if the
+/// difference matters. To store an empty range, encode a range that contains no values, such
+/// as `1..1`, which PostgreSQL turns into `empty`.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct PgRange<T> {
     pub start: Bound<T>,
@@ -383,6 +397,14 @@ where
 
                 let s = value.as_str()?;
 
+                // an empty range has no bounds, like in the binary format
+                if s == "empty" {
+                    return Ok(PgRange {
+                        start: Bound::Unbounded,
+                        end: Bound::Unbounded,
+                    });
+                }
+
                 // remember the bounds
                 let sb = s.as_bytes();
                 let lower = sb[0] as char;
@@ -395,14 +417,18 @@ where
 
                 let mut element = String::new();
                 let mut done = false;
-                let mut quoted = false;
+                let mut quoted;
                 let mut in_quotes = false;
                 let mut in_escape = false;
-                let mut prev_ch = '\0';
+                let mut prev_ch;
                 let mut count = 0;
 
                 while !done {
+                    // each bound starts afresh, so that the closing quote of the lower one is not
+                    // taken for an escaped quote in the upper one
                     element.clear();
+                    quoted = false;
+                    prev_ch = '\0';
 
                     loop {
                         match chars.next() {
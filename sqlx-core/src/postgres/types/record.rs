@@ -78,8 +78,9 @@ impl<'r> PgRecordDecoder<'r> {
             }
 
             PgValueFormat::Text => {
-                // remove the enclosing `(` .. `)`
-                buf = &buf[1..(buf.len() - 1)];
+                // remove the opening `(`, the closing `)` ends the last field like a `,` ends
+                // the others, so that a NULL last field is still there to be decoded
+                buf = &buf[1..];
             }
         }
 
@@ -109,7 +110,17 @@ impl<'r> PgRecordDecoder<'r> {
                     }
 
                     PgTypeKind::Composite(fields) => {
-                        let ty = fields[self.ind].1.clone();
+                        let ty = match fields.get(self.ind) {
+                            Some((_, ty)) => ty.clone(),
+                            None => {
+                                return Err(format!(
+                                    "composite type has {} fields, but field `{}` was requested",
+                                    fields.len(),
+                                    self.ind
+                                )
+                                .into());
+                            }
+                        };
                         if ty.0.oid() != element_type_oid {
                             return Err("unexpected mismatch of composite type information".into());
                         }
@@ -172,7 +183,7 @@ impl<'r> PgRecordDecoder<'r> {
                             in_escape = true;
                         }
 
-                        ',' if !in_quotes => break,
+                        ',' | ')' if !in_quotes => break,
 
                         _ => {
                             element.push(ch);
@@ -188,6 +199,8 @@ impl<'r> PgRecordDecoder<'r> {
                     Some(element.as_bytes())
                 };
 
+                self.ind += 1;
+
                 // NOTE: we do not call [`accepts`] or give a chance to from a user as
                 //       TEXT sequences are not strongly typed
 
@@ -2,7 +2,9 @@ use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::postgres::types::array_compatible;
-use crate::postgres::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef, Postgres};
+use crate::postgres::{
+    PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
 use crate::types::Type;
 use std::borrow::Cow;
 
@@ -18,6 +20,8 @@ impl Type<Postgres> for str {
             PgTypeInfo::BPCHAR,
             PgTypeInfo::VARCHAR,
             PgTypeInfo::UNKNOWN,
+            PgTypeInfo::REGCLASS,
+            PgTypeInfo::REGTYPE,
         ]
         .contains(ty)
     }
@@ -96,20 +100,36 @@ impl Encode<'_, Postgres> for String {
     }
 }
 
+fn decode_str(value: PgValueRef<'_>) -> Result<&'_ str, BoxDynError> {
+    // a REGCLASS or REGTYPE is only sent as its name in the text format
+    if matches!(value.format(), PgValueFormat::Binary)
+        && [PgTypeInfo::REGCLASS, PgTypeInfo::REGTYPE].contains(&value.type_info)
+    {
+        return Err(format!(
+            "a {0} is sent as its OID in the binary format; decode it as an Oid \
+             or cast it with `::text` to get its name",
+            value.type_info
+        )
+        .into());
+    }
+
+    Ok(value.as_str()?)
+}
+
 impl<'r> Decode<'r, Postgres> for &'r str {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
-        Ok(value.as_str()?)
+        decode_str(value)
     }
 }
 
 impl<'r> Decode<'r, Postgres> for Cow<'r, str> {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
-        Ok(Cow::Borrowed(value.as_str()?))
+        Ok(Cow::Borrowed(decode_str(value)?))
     }
 }
 
 impl Decode<'_, Postgres> for String {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
-        Ok(value.as_str()?.to_owned())
+        Ok(decode_str(value)?.to_owned())
     }
 }
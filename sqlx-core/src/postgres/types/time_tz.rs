@@ -33,6 +33,26 @@ pub struct PgTimeTz<Time = DefaultTime, Offset = DefaultOffset> {
     pub offset: Offset,
 }
 
+// TIMETZ is encoded as the microseconds since midnight, followed by the offset in seconds
+// west of UTC
+fn decode_binary(buf: &[u8]) -> Result<(i64, i32), BoxDynError> {
+    let mut buf = Cursor::new(buf);
+
+    let us = buf.read_i64::<BigEndian>()?;
+    let seconds = buf.read_i32::<BigEndian>()?;
+
+    // Postgres accepts `24:00:00`, which is not a time of day either crate can represent
+    if !(0..24 * 60 * 60 * 1_000_000).contains(&us) {
+        return Err(format!(
+            "TIMETZ of {} microseconds since midnight is not a time of day",
+            us
+        )
+        .into());
+    }
+
+    Ok((us, seconds))
+}
+
 impl<Time, Offset> Type<Postgres> for [PgTimeTz<Time, Offset>]
 where
     PgTimeTz<Time, Offset>: Type<Postgres>,
@@ -79,14 +99,9 @@ mod chrono {
         fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
             match value.format() {
                 PgValueFormat::Binary => {
-                    let mut buf = Cursor::new(value.as_bytes()?);
+                    let (us, seconds) = decode_binary(value.as_bytes()?)?;
 
-                    // TIME is encoded as the microseconds since midnight
-                    let us = buf.read_i64::<BigEndian>()?;
                     let time = NaiveTime::default() + Duration::microseconds(us);
-
-                    // OFFSET is encoded as seconds from UTC
-                    let seconds = buf.read_i32::<BigEndian>()?;
                     let offset = FixedOffset::west_opt(seconds).ok_or("invaid time offset")?;
 
                     Ok(PgTimeTz { time, offset })
@@ -130,7 +145,9 @@ mod chrono {
 #[cfg(feature = "time")]
 mod time {
     use super::*;
+    use ::time::macros::format_description;
     use ::time::{Duration, Time, UtcOffset};
+    use std::borrow::Cow;
 
     impl Type<Postgres> for PgTimeTz<Time, UtcOffset> {
         fn type_info() -> PgTypeInfo {
@@ -155,27 +172,100 @@ mod time {
         fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
             match value.format() {
                 PgValueFormat::Binary => {
-                    let mut buf = Cursor::new(value.as_bytes()?);
-
-                    // TIME is encoded as the microseconds since midnight
-                    let us = buf.read_i64::<BigEndian>()?;
-                    let time = Time::MIDNIGHT + Duration::microseconds(us);
-
-                    // OFFSET is encoded as seconds from UTC
-                    let seconds = buf.read_i32::<BigEndian>()?;
+                    let (us, seconds) = decode_binary(value.as_bytes()?)?;
 
                     Ok(PgTimeTz {
-                        time,
+                        time: Time::MIDNIGHT + Duration::microseconds(us),
                         offset: -UtcOffset::from_whole_seconds(seconds)?,
                     })
                 }
 
                 PgValueFormat::Text => {
-                    // the `time` crate has a limited ability to parse and can't parse the
-                    // timezone format
-                    Err("reading a `TIMETZ` value in text format is not supported.".into())
+                    let s = value.as_str()?;
+
+                    // the offset is the only part of the value with a sign
+                    let (time, offset) = s
+                        .rfind(&['+', '-'][..])
+                        .map(|i| s.split_at(i))
+                        .ok_or("TIMETZ without an offset")?;
+
+                    // If there is no decimal point we need to add one.
+                    let time = if time.contains('.') {
+                        Cow::Borrowed(time)
+                    } else {
+                        Cow::Owned(format!("{}.0", time))
+                    };
+
+                    Ok(PgTimeTz {
+                        time: Time::parse(
+                            &*time,
+                            &format_description!("[hour]:[minute]:[second].[subsecond]"),
+                        )?,
+                        offset: parse_offset(offset)?,
+                    })
                 }
             }
         }
     }
+
+    // the `time` crate can parse an offset of `+HH:MM`, but Postgres leaves out the minutes
+    // when there are none, and adds seconds when there are some
+    fn parse_offset(s: &str) -> Result<UtcOffset, BoxDynError> {
+        let (sign, s) = match s.as_bytes().first() {
+            Some(b'+') => (1, &s[1..]),
+            Some(b'-') => (-1, &s[1..]),
+            _ => return Err(format!("invalid TIMETZ offset: {:?}", s).into()),
+        };
+
+        let mut parts = s.split(':').map(str::parse::<i8>);
+
+        let hours = parts.next().transpose()?.unwrap_or(0);
+        let minutes = parts.next().transpose()?.unwrap_or(0);
+        let seconds = parts.next().transpose()?.unwrap_or(0);
+
+        if parts.next().is_some() {
+            return Err(format!("invalid TIMETZ offset: {:?}", s).into());
+        }
+
+        // every component of the offset has its sign
+        Ok(UtcOffset::from_hms(
+            sign * hours,
+            sign * minutes,
+            sign * seconds,
+        )?)
+    }
+
+    #[test]
+    fn it_parses_offsets() {
+        assert_eq!(
+            parse_offset("+05:30").unwrap(),
+            UtcOffset::from_hms(5, 30, 0).unwrap()
+        );
+        assert_eq!(
+            parse_offset("-03").unwrap(),
+            UtcOffset::from_hms(-3, 0, 0).unwrap()
+        );
+        assert_eq!(
+            parse_offset("-00:30:15").unwrap(),
+            UtcOffset::from_hms(0, -30, -15).unwrap()
+        );
+
+        assert!(parse_offset("05:30").is_err());
+        assert!(parse_offset("+05:30:15:00").is_err());
+    }
+}
+
+#[test]
+fn it_rejects_times_beyond_a_day() {
+    // TIMETZ '24:00:00+00'
+    let mut buf = 86_400_000_000_i64.to_be_bytes().to_vec();
+    buf.extend_from_slice(&0_i32.to_be_bytes());
+
+    assert!(decode_binary(&buf).is_err());
+
+    // TIMETZ '23:59:59.999999-05:30'
+    let mut buf = 86_399_999_999_i64.to_be_bytes().to_vec();
+    buf.extend_from_slice(&19_800_i32.to_be_bytes());
+
+    assert_eq!(decode_binary(&buf).unwrap(), (86_399_999_999, 19_800));
 }
@@ -0,0 +1,267 @@
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::decode::Decode;
+use crate::error::BoxDynError;
+use crate::postgres::{PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+// https://www.postgresql.org/docs/current/datatype-textsearch.html#DATATYPE-TSVECTOR
+// https://github.com/postgres/postgres/blob/REL_15_0/src/backend/utils/adt/tsvector.c
+
+/// A document prepared for full text search, the Postgres `tsvector` type.
+///
+/// Postgres sorts the lexemes and removes duplicates, so they are decoded in that order.
+/// This type can only be decoded, e.g. to inspect the result of `to_tsvector`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PgTsVector {
+    pub lexemes: Vec<PgTsLexeme>,
+}
+
+/// A lexeme of a [`PgTsVector`], along with the positions it appears at in the document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgTsLexeme {
+    pub text: String,
+
+    /// The positions of the lexeme, which are empty if the document was written without them,
+    /// e.g. as `'a fat cat'::tsvector`.
+    pub positions: Vec<PgTsPosition>,
+}
+
+/// A position of a [`PgTsLexeme`] in a document, counted in words from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PgTsPosition {
+    pub position: u16,
+    pub weight: PgTsWeight,
+}
+
+/// The weight of a [`PgTsPosition`], usually marking the part of the document it is from,
+/// such as the title or the body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PgTsWeight {
+    A,
+    B,
+    C,
+    /// The weight of a position that was not given one.
+    D,
+}
+
+impl PgTsVector {
+    /// The text of each lexeme, in order.
+    pub fn lexeme_texts(&self) -> impl Iterator<Item = &str> + '_ {
+        self.lexemes.iter().map(|lexeme| &*lexeme.text)
+    }
+}
+
+impl PgTsWeight {
+    // the weight is stored in the two highest bits of a position
+    fn from_bits(bits: u16) -> Self {
+        match bits {
+            3 => PgTsWeight::A,
+            2 => PgTsWeight::B,
+            1 => PgTsWeight::C,
+            _ => PgTsWeight::D,
+        }
+    }
+}
+
+impl Type<Postgres> for PgTsVector {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TS_VECTOR
+    }
+}
+
+impl PgHasArrayType for PgTsVector {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::TS_VECTOR_ARRAY
+    }
+}
+
+impl Decode<'_, Postgres> for PgTsVector {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => decode_binary(value.as_bytes()?),
+            PgValueFormat::Text => parse_text(value.as_str()?),
+        }
+    }
+}
+
+fn decode_binary(mut buf: &[u8]) -> Result<PgTsVector, BoxDynError> {
+    fn take<'a>(buf: &mut &'a [u8], len: usize) -> Result<&'a [u8], BoxDynError> {
+        if buf.len() < len {
+            return Err("unexpected end of TSVECTOR".into());
+        }
+
+        let (taken, rest) = buf.split_at(len);
+        *buf = rest;
+
+        Ok(taken)
+    }
+
+    let count = BigEndian::read_i32(take(&mut buf, 4)?);
+    let mut lexemes = Vec::with_capacity(count.clamp(0, 1024) as usize);
+
+    for _ in 0..count {
+        // the lexeme is terminated with a nul
+        let len = buf
+            .iter()
+            .position(|&b| b == 0)
+            .ok_or("unterminated lexeme in TSVECTOR")?;
+
+        let text = std::str::from_utf8(take(&mut buf, len)?)?.to_owned();
+        take(&mut buf, 1)?;
+
+        let npos = BigEndian::read_u16(take(&mut buf, 2)?);
+        let mut positions = Vec::with_capacity(npos.into());
+
+        for _ in 0..npos {
+            let bits = BigEndian::read_u16(take(&mut buf, 2)?);
+
+            positions.push(PgTsPosition {
+                position: bits & 0x3fff,
+                weight: PgTsWeight::from_bits(bits >> 14),
+            });
+        }
+
+        lexemes.push(PgTsLexeme { text, positions });
+    }
+
+    if !buf.is_empty() {
+        return Err(format!("{} unexpected trailing bytes in TSVECTOR", buf.len()).into());
+    }
+
+    Ok(PgTsVector { lexemes })
+}
+
+// e.g. `'a':1A 'cat':5 'fat':2B,4C 'it''s':3`
+fn parse_text(s: &str) -> Result<PgTsVector, BoxDynError> {
+    let mut lexemes = Vec::new();
+    let mut chars = s.chars().peekable();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+        let first = match chars.next() {
+            Some(c) => c,
+            None => break,
+        };
+
+        let mut text = String::new();
+
+        if first == '\'' {
+            // a quote is doubled within a quoted lexeme, and a backslash escapes any character
+            loop {
+                match chars.next() {
+                    Some('\'') if chars.next_if_eq(&'\'').is_some() => text.push('\''),
+                    Some('\'') => break,
+                    Some('\\') => text.push(chars.next().ok_or("unterminated TSVECTOR escape")?),
+                    Some(c) => text.push(c),
+                    None => return Err(format!("unterminated TSVECTOR lexeme: {:?}", s).into()),
+                }
+            }
+        } else {
+            text.push(first);
+
+            while let Some(c) = chars.next_if(|&c| !c.is_whitespace() && c != ':') {
+                text.push(c);
+            }
+        }
+
+        let mut positions = Vec::new();
+
+        if chars.next_if_eq(&':').is_some() {
+            loop {
+                let mut digits = String::new();
+
+                while let Some(c) = chars.next_if(char::is_ascii_digit) {
+                    digits.push(c);
+                }
+
+                let position = digits
+                    .parse()
+                    .map_err(|_| format!("invalid position in TSVECTOR: {:?}", s))?;
+
+                let weight = match chars.next_if(|&c| matches!(c, 'A'..='D' | 'a'..='d')) {
+                    Some('A' | 'a') => PgTsWeight::A,
+                    Some('B' | 'b') => PgTsWeight::B,
+                    Some('C' | 'c') => PgTsWeight::C,
+                    _ => PgTsWeight::D,
+                };
+
+                positions.push(PgTsPosition { position, weight });
+
+                if chars.next_if_eq(&',').is_none() {
+                    break;
+                }
+            }
+        }
+
+        lexemes.push(PgTsLexeme { text, positions });
+    }
+
+    Ok(PgTsVector { lexemes })
+}
+
+#[test]
+fn test_decode_binary() {
+    let mut buf = Vec::new();
+
+    buf.extend(&2_i32.to_be_bytes());
+
+    buf.extend(b"cat\0");
+    buf.extend(&0_u16.to_be_bytes());
+
+    buf.extend(b"fat\0");
+    buf.extend(&2_u16.to_be_bytes());
+    buf.extend(&((3_u16 << 14) | 2).to_be_bytes());
+    buf.extend(&4_u16.to_be_bytes());
+
+    let vector = decode_binary(&buf).unwrap();
+
+    assert_eq!(vector.lexeme_texts().collect::<Vec<_>>(), ["cat", "fat"]);
+    assert!(vector.lexemes[0].positions.is_empty());
+    assert_eq!(
+        vector.lexemes[1].positions,
+        [
+            PgTsPosition {
+                position: 2,
+                weight: PgTsWeight::A
+            },
+            PgTsPosition {
+                position: 4,
+                weight: PgTsWeight::D
+            },
+        ]
+    );
+
+    assert!(decode_binary(&buf[..buf.len() - 1]).is_err());
+}
+
+#[test]
+fn test_parse_text() {
+    let vector = parse_text("'a':1A 'cat':5 'fat':2B,4C 'it''s':3 'back\\\\slash' plain").unwrap();
+
+    assert_eq!(
+        vector.lexeme_texts().collect::<Vec<_>>(),
+        ["a", "cat", "fat", "it's", "back\\slash", "plain"]
+    );
+
+    assert_eq!(
+        vector.lexemes[2].positions,
+        [
+            PgTsPosition {
+                position: 2,
+                weight: PgTsWeight::B
+            },
+            PgTsPosition {
+                position: 4,
+                weight: PgTsWeight::C
+            },
+        ]
+    );
+    assert_eq!(vector.lexemes[1].positions[0].weight, PgTsWeight::D);
+    assert!(vector.lexemes[4].positions.is_empty());
+
+    assert_eq!(parse_text("").unwrap(), PgTsVector::default());
+    assert!(parse_text("'unterminated").is_err());
+    assert!(parse_text("'a':x").is_err());
+}
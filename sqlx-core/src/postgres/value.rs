@@ -50,10 +50,15 @@ impl<'r> PgValueRef<'r> {
         }
     }
 
+    /// The format the value was sent in by the server, which is the binary format
+    /// for prepared queries and the text format for simple queries.
     pub fn format(&self) -> PgValueFormat {
         self.format
     }
 
+    /// The undecoded bytes of the value, in its [`format`][Self::format].
+    ///
+    /// Returns an error if the value is `NULL`.
     pub fn as_bytes(&self) -> Result<&'r [u8], BoxDynError> {
         match &self.value {
             Some(v) => Ok(v),
@@ -61,6 +66,9 @@ impl<'r> PgValueRef<'r> {
         }
     }
 
+    /// The undecoded bytes of the value, as a string.
+    ///
+    /// Returns an error if the value is `NULL` or not valid UTF-8.
     pub fn as_str(&self) -> Result<&'r str, BoxDynError> {
         Ok(from_utf8(self.as_bytes()?)?)
     }
@@ -43,10 +43,7 @@ where
 {
     #[inline]
     fn sql(&self) -> &'q str {
-        match self.statement {
-            Either::Right(ref statement) => statement.sql(),
-            Either::Left(sql) => sql,
-        }
+        Query::sql(self)
     }
 
     fn statement(&self) -> Option<&<DB as HasStatement<'q>>::Statement> {
@@ -74,8 +71,18 @@ impl<'q, DB: Database> Query<'q, DB, <DB as HasArguments<'q>>::Arguments> {
     /// appear in the query (`?` for most SQL flavors, `$1 .. $N` for Postgres) then an error
     /// will be returned when this query is executed.
     ///
+    /// This is only checked for Postgres and MySQL. SQLite uses `NULL` for the parameters that
+    /// were not bound, and MSSQL reports those as a database error; both ignore extra values.
+    ///
+    /// The placeholders are counted by the database as it prepares the query, not by SQLx, so
+    /// the error is only returned after the statement is prepared, which takes a round trip to
+    /// the server unless it is cached. A query that fails to prepare returns that error instead.
+    ///
     /// There is no validation that the value is of the type expected by the query. Most SQL
     /// flavors will perform type coercion (Postgres will return a database error).
+    ///
+    /// To bind `NULL`, bind `None::<T>` with the type `T` the parameter should have; this type is
+    /// sent along where the database supports it. See [`types`][crate::types#nullable].
     pub fn bind<T: 'q + Send + Encode<'q, DB> + Type<DB>>(mut self, value: T) -> Self {
         if let Some(arguments) = &mut self.arguments {
             arguments.add(value);
@@ -83,6 +90,57 @@ impl<'q, DB: Database> Query<'q, DB, <DB as HasArguments<'q>>::Arguments> {
 
         self
     }
+
+    /// Bind each value of a sequence, in order, for use with this SQL query.
+    ///
+    /// This is the same as calling [`bind`][Self::bind] once per value, and is useful for binding
+    /// a list of parameters whose length is only known at runtime. Binding an empty sequence
+    /// leaves the query unchanged.
+    ///
+    /// ```rust,ignore
+    /// let ids = vec![1, 2, 3];
+    /// let placeholders = vec!["?"; ids.len()].join(", ");
+    ///
+    /// let rows = sqlx::query(&format!("SELECT * FROM users WHERE id IN ({})", placeholders))
+    ///     .bind_all(ids)
+    ///     .fetch_all(&mut conn)
+    ///     .await?;
+    /// ```
+    pub fn bind_all<T, I>(mut self, values: I) -> Self
+    where
+        T: 'q + Send + Encode<'q, DB> + Type<DB>,
+        I: IntoIterator<Item = T>,
+    {
+        if let Some(arguments) = &mut self.arguments {
+            for value in values {
+                arguments.add(value);
+            }
+        }
+
+        self
+    }
+
+    /// Bind each value of a borrowed sequence, in order, for use with this SQL query.
+    ///
+    /// Like [`bind_all`][Self::bind_all], but the values are encoded by reference
+    /// instead of being moved or cloned into the query.
+    pub fn bind_iter<T, I>(self, values: I) -> Self
+    where
+        T: 'q + Sync + Encode<'q, DB> + Type<DB>,
+        I: IntoIterator<Item = &'q T>,
+    {
+        self.bind_all(values)
+    }
+}
+
+impl<'q, DB: Database, A> Query<'q, DB, A> {
+    /// The SQL of this query, as it will be sent to the database.
+    pub fn sql(&self) -> &'q str {
+        match self.statement {
+            Either::Right(ref statement) => statement.sql(),
+            Either::Left(sql) => sql,
+        }
+    }
 }
 
 impl<'q, DB, A> Query<'q, DB, A>
@@ -178,6 +236,26 @@ where
         executor.fetch(self)
     }
 
+    /// Execute the query and return the generated results as a stream, asking the database
+    /// for at most `size` rows at a time.
+    ///
+    /// See [`Executor::fetch_many_with_size`] for which drivers support this.
+    pub fn fetch_with_size<'e, 'c: 'e, E>(
+        self,
+        executor: E,
+        size: usize,
+    ) -> BoxStream<'e, Result<DB::Row, Error>>
+    where
+        'q: 'e,
+        A: 'e,
+        E: Executor<'c, Database = DB>,
+    {
+        executor
+            .fetch_many_with_size(self, size)
+            .try_filter_map(|step| async move { Ok(step.right()) })
+            .boxed()
+    }
+
     /// Execute multiple queries and return the generated results as a stream
     /// from each query, in a stream.
     #[inline]
@@ -204,6 +282,33 @@ where
         executor.fetch_all(self).await
     }
 
+    /// Execute the query and collect all the generated results into `buf`, which is cleared
+    /// first.
+    ///
+    /// Unlike [`fetch_all`][Self::fetch_all], this keeps the capacity of `buf`, so a buffer that
+    /// is reused across queries is only reallocated when a result does not fit. If an error is
+    /// returned, `buf` holds the results generated before it.
+    pub async fn fetch_all_into<'e, 'c: 'e, E>(
+        self,
+        executor: E,
+        buf: &mut Vec<DB::Row>,
+    ) -> Result<(), Error>
+    where
+        'q: 'e,
+        A: 'e,
+        E: Executor<'c, Database = DB>,
+    {
+        buf.clear();
+
+        let mut s = self.fetch(executor);
+
+        while let Some(v) = s.try_next().await? {
+            buf.push(v);
+        }
+
+        Ok(())
+    }
+
     /// Execute the query and returns exactly one row.
     #[inline]
     pub async fn fetch_one<'e, 'c: 'e, E>(self, executor: E) -> Result<DB::Row, Error>
@@ -253,6 +358,13 @@ where
     }
 }
 
+impl<'q, DB: Database, F, A> Map<'q, DB, F, A> {
+    /// The SQL of this query, as it will be sent to the database.
+    pub fn sql(&self) -> &'q str {
+        self.inner.sql()
+    }
+}
+
 impl<'q, DB, F, O, A> Map<'q, DB, F, A>
 where
     DB: Database,
@@ -317,6 +429,35 @@ where
             .boxed()
     }
 
+    /// Execute the query and return the generated results as a stream, asking the database
+    /// for at most `size` rows at a time.
+    ///
+    /// See [`Executor::fetch_many_with_size`] for which drivers support this.
+    pub fn fetch_with_size<'e, 'c: 'e, E>(
+        mut self,
+        executor: E,
+        size: usize,
+    ) -> BoxStream<'e, Result<O, Error>>
+    where
+        'q: 'e,
+        E: 'e + Executor<'c, Database = DB>,
+        DB: 'e,
+        F: 'e,
+        O: 'e,
+    {
+        Box::pin(try_stream! {
+            let mut s = executor.fetch_many_with_size(self.inner, size);
+
+            while let Some(v) = s.try_next().await? {
+                if let Either::Right(row) = v {
+                    r#yield!((self.mapper)(row)?);
+                }
+            }
+
+            Ok(())
+        })
+    }
+
     /// Execute multiple queries and return the generated results as a stream
     /// from each query, in a stream.
     pub fn fetch_many<'e, 'c: 'e, E>(
@@ -358,6 +499,33 @@ where
         self.fetch(executor).try_collect().await
     }
 
+    /// Execute the query and collect all the generated results into `buf`, which is cleared
+    /// first but keeps its capacity.
+    ///
+    /// See [`Query::fetch_all_into`] for details.
+    pub async fn fetch_all_into<'e, 'c: 'e, E>(
+        self,
+        executor: E,
+        buf: &mut Vec<O>,
+    ) -> Result<(), Error>
+    where
+        'q: 'e,
+        E: 'e + Executor<'c, Database = DB>,
+        DB: 'e,
+        F: 'e,
+        O: 'e,
+    {
+        buf.clear();
+
+        let mut s = self.fetch(executor);
+
+        while let Some(v) = s.try_next().await? {
+            buf.push(v);
+        }
+
+        Ok(())
+    }
+
     /// Execute the query and returns exactly one row.
     pub async fn fetch_one<'e, 'c: 'e, E>(self, executor: E) -> Result<O, Error>
     where
@@ -376,6 +544,9 @@ where
     }
 
     /// Execute the query and returns at most one row.
+    ///
+    /// `Ok(None)` is only returned if the query returned no rows; if the first row fails to
+    /// decode, the error is returned instead.
     pub async fn fetch_optional<'e, 'c: 'e, E>(mut self, executor: E) -> Result<Option<O>, Error>
     where
         'q: 'e,
@@ -55,6 +55,37 @@ impl<'q, DB: Database, O> QueryAs<'q, DB, O, <DB as HasArguments<'q>>::Arguments
         self.inner = self.inner.bind(value);
         self
     }
+
+    /// Bind each value of a sequence, in order, for use with this SQL query.
+    ///
+    /// See [`Query::bind_all`](Query::bind_all).
+    pub fn bind_all<T, I>(mut self, values: I) -> Self
+    where
+        T: 'q + Send + Encode<'q, DB> + Type<DB>,
+        I: IntoIterator<Item = T>,
+    {
+        self.inner = self.inner.bind_all(values);
+        self
+    }
+
+    /// Bind each value of a borrowed sequence, in order, for use with this SQL query.
+    ///
+    /// See [`Query::bind_iter`](Query::bind_iter).
+    pub fn bind_iter<T, I>(mut self, values: I) -> Self
+    where
+        T: 'q + Sync + Encode<'q, DB> + Type<DB>,
+        I: IntoIterator<Item = &'q T>,
+    {
+        self.inner = self.inner.bind_iter(values);
+        self
+    }
+}
+
+impl<'q, DB: Database, O, A> QueryAs<'q, DB, O, A> {
+    /// The SQL of this query, as it will be sent to the database.
+    pub fn sql(&self) -> &'q str {
+        self.inner.sql()
+    }
 }
 
 impl<'q, DB, O, A> QueryAs<'q, DB, O, A>
@@ -97,6 +128,33 @@ where
             .boxed()
     }
 
+    /// Execute the query and return the generated results as a stream, asking the database
+    /// for at most `size` rows at a time.
+    ///
+    /// See [`Executor::fetch_many_with_size`] for which drivers support this.
+    pub fn fetch_with_size<'e, 'c: 'e, E>(
+        self,
+        executor: E,
+        size: usize,
+    ) -> BoxStream<'e, Result<O, Error>>
+    where
+        'q: 'e,
+        E: 'e + Executor<'c, Database = DB>,
+        DB: 'e,
+        O: 'e,
+        A: 'e,
+    {
+        executor
+            .fetch_many_with_size(self.inner, size)
+            .try_filter_map(|step| async move {
+                Ok(match step {
+                    Either::Left(_) => None,
+                    Either::Right(row) => Some(O::from_row(&row)?),
+                })
+            })
+            .boxed()
+    }
+
     /// Execute multiple queries and return the generated results as a stream
     /// from each query, in a stream.
     pub fn fetch_many<'e, 'c: 'e, E>(
@@ -133,6 +191,28 @@ where
         self.fetch(executor).try_collect().await
     }
 
+    /// Execute the query and collect all the generated results into `buf`, which is cleared
+    /// first but keeps its capacity.
+    ///
+    /// See [`Query::fetch_all_into`] for details.
+    pub async fn fetch_all_into<'e, 'c: 'e, E>(
+        self,
+        executor: E,
+        buf: &mut Vec<O>,
+    ) -> Result<(), Error>
+    where
+        'q: 'e,
+        E: 'e + Executor<'c, Database = DB>,
+        DB: 'e,
+        O: 'e,
+        A: 'e,
+    {
+        self.inner
+            .try_map(|row| O::from_row(&row))
+            .fetch_all_into(executor, buf)
+            .await
+    }
+
     /// Execute the query and returns exactly one row.
     pub async fn fetch_one<'e, 'c: 'e, E>(self, executor: E) -> Result<O, Error>
     where
@@ -148,6 +228,9 @@ where
     }
 
     /// Execute the query and returns at most one row.
+    ///
+    /// `Ok(None)` is only returned if the query returned no rows; if the first row fails to
+    /// decode, the error is returned instead.
     pub async fn fetch_optional<'e, 'c: 'e, E>(self, executor: E) -> Result<Option<O>, Error>
     where
         'q: 'e,
@@ -17,7 +17,7 @@ use crate::types::Type;
 /// Returned from [`query_scalar`].
 #[must_use = "query must be executed to affect database"]
 pub struct QueryScalar<'q, DB: Database, O, A> {
-    inner: QueryAs<'q, DB, (O,), A>,
+    pub(crate) inner: QueryAs<'q, DB, (O,), A>,
 }
 
 impl<'q, DB: Database, O: Send, A: Send> Execute<'q, DB> for QueryScalar<'q, DB, O, A>
@@ -52,6 +52,37 @@ impl<'q, DB: Database, O> QueryScalar<'q, DB, O, <DB as HasArguments<'q>>::Argum
         self.inner = self.inner.bind(value);
         self
     }
+
+    /// Bind each value of a sequence, in order, for use with this SQL query.
+    ///
+    /// See [`Query::bind_all`](crate::query::Query::bind_all).
+    pub fn bind_all<T, I>(mut self, values: I) -> Self
+    where
+        T: 'q + Send + Encode<'q, DB> + Type<DB>,
+        I: IntoIterator<Item = T>,
+    {
+        self.inner = self.inner.bind_all(values);
+        self
+    }
+
+    /// Bind each value of a borrowed sequence, in order, for use with this SQL query.
+    ///
+    /// See [`Query::bind_iter`](crate::query::Query::bind_iter).
+    pub fn bind_iter<T, I>(mut self, values: I) -> Self
+    where
+        T: 'q + Sync + Encode<'q, DB> + Type<DB>,
+        I: IntoIterator<Item = &'q T>,
+    {
+        self.inner = self.inner.bind_iter(values);
+        self
+    }
+}
+
+impl<'q, DB: Database, O, A> QueryScalar<'q, DB, O, A> {
+    /// The SQL of this query, as it will be sent to the database.
+    pub fn sql(&self) -> &'q str {
+        self.inner.sql()
+    }
 }
 
 impl<'q, DB, O, A> QueryScalar<'q, DB, O, A>
@@ -94,6 +125,29 @@ where
         self.inner.fetch(executor).map_ok(|it| it.0).boxed()
     }
 
+    /// Execute the query and return the generated results as a stream, asking the database
+    /// for at most `size` rows at a time.
+    ///
+    /// See [`Executor::fetch_many_with_size`] for which drivers support this.
+    #[inline]
+    pub fn fetch_with_size<'e, 'c: 'e, E>(
+        self,
+        executor: E,
+        size: usize,
+    ) -> BoxStream<'e, Result<O, Error>>
+    where
+        'q: 'e,
+        E: 'e + Executor<'c, Database = DB>,
+        DB: 'e,
+        A: 'e,
+        O: 'e,
+    {
+        self.inner
+            .fetch_with_size(executor, size)
+            .map_ok(|it| it.0)
+            .boxed()
+    }
+
     /// Execute multiple queries and return the generated results as a stream
     /// from each query, in a stream.
     #[inline]
@@ -131,6 +185,29 @@ where
             .await
     }
 
+    /// Execute the query and collect all the generated results into `buf`, which is cleared
+    /// first but keeps its capacity.
+    ///
+    /// See [`Query::fetch_all_into`][crate::query::Query::fetch_all_into] for details.
+    pub async fn fetch_all_into<'e, 'c: 'e, E>(
+        self,
+        executor: E,
+        buf: &mut Vec<O>,
+    ) -> Result<(), Error>
+    where
+        'q: 'e,
+        E: 'e + Executor<'c, Database = DB>,
+        DB: 'e,
+        A: 'e,
+        O: 'e,
+    {
+        self.inner
+            .inner
+            .try_map(|row| <(O,)>::from_row(&row).map(|it| it.0))
+            .fetch_all_into(executor, buf)
+            .await
+    }
+
     /// Execute the query and returns exactly one row.
     #[inline]
     pub async fn fetch_one<'e, 'c: 'e, E>(self, executor: E) -> Result<O, Error>
@@ -145,6 +222,9 @@ where
     }
 
     /// Execute the query and returns at most one row.
+    ///
+    /// `Ok(None)` is only returned if the query returned no rows; if the first row fails to
+    /// decode, the error is returned instead.
     #[inline]
     pub async fn fetch_optional<'e, 'c: 'e, E>(self, executor: E) -> Result<Option<O>, Error>
     where
@@ -160,6 +240,9 @@ where
 
 /// Make a SQL query that is mapped to a single concrete type
 /// using [`FromRow`].
+///
+/// The value is decoded from the first column of each row; if the query returns no columns,
+/// [`Error::ColumnIndexOutOfBounds`] is returned.
 #[inline]
 pub fn query_scalar<'q, DB, O>(
     sql: &'q str,
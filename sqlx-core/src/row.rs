@@ -3,6 +3,8 @@ use crate::database::{Database, HasValueRef};
 use crate::decode::Decode;
 use crate::error::{mismatched_types, Error};
 use crate::type_info::TypeInfo;
+#[cfg(feature = "json")]
+use crate::types::Json;
 use crate::types::Type;
 use crate::value::ValueRef;
 
@@ -59,6 +61,10 @@ pub trait Row: private_row::Sealed + Unpin + Send + Sync + 'static {
     /// A string index can be used to access a column by name and a `usize` index
     /// can be used to access a column by position.
     ///
+    /// Names are matched exactly, including their case, and looked up in a map that is built
+    /// once per result set and shared by its rows. If several columns have the same name, the
+    /// last one is used; index by position to access the others.
+    ///
     /// # Panics
     ///
     /// Panics if the column does not exist or its value cannot be decoded into the requested type.
@@ -162,7 +168,125 @@ pub trait Row: private_row::Sealed + Unpin + Send + Sync + 'static {
         })
     }
 
-    /// Index into the database row and decode a single value.
+    /// Index into the database row and decode a JSON value into `T`.
+    ///
+    /// This is a shorthand for `row.get::<Json<T>, _>(index).0`, which also accepts columns of
+    /// a text type that contain JSON.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column does not exist, is `NULL` or its value cannot be decoded into the
+    /// requested type. See [`try_get_json`](Self::try_get_json) for a non-panicking version.
+    ///
+    #[cfg(feature = "json")]
+    #[inline]
+    fn get_json<'r, T, I>(&'r self, index: I) -> T
+    where
+        I: ColumnIndex<Self>,
+        Json<T>: Decode<'r, Self::Database> + Type<Self::Database>,
+        String: Type<Self::Database>,
+    {
+        self.try_get_json::<T, I>(index).unwrap()
+    }
+
+    /// Index into the database row and decode a JSON value into `T`.
+    ///
+    /// This is a shorthand for `row.try_get::<Json<T>, _>(index)?.0`, which also accepts
+    /// columns of a text type that contain JSON.
+    ///
+    /// ```rust,ignore
+    /// use sqlx::Row;
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Settings {
+    ///     theme: String,
+    /// }
+    ///
+    /// let row = sqlx::query("SELECT settings FROM users").fetch_one(&mut conn).await?;
+    /// let settings: Settings = row.try_get_json("settings")?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    ///  * [`ColumnNotFound`] if the column by the given name was not found.
+    ///  * [`ColumnIndexOutOfBounds`] if the `usize` index was greater than the number of columns in the row.
+    ///  * [`ColumnDecode`] if the value is `NULL` or could not be decoded into the requested
+    ///    type; see [`try_get_json_opt`](Self::try_get_json_opt) to accept `NULL`.
+    ///
+    /// [`ColumnDecode`]: Error::ColumnDecode
+    /// [`ColumnNotFound`]: Error::ColumnNotFound
+    /// [`ColumnIndexOutOfBounds`]: Error::ColumnIndexOutOfBounds
+    ///
+    #[cfg(feature = "json")]
+    fn try_get_json<'r, T, I>(&'r self, index: I) -> Result<T, Error>
+    where
+        I: ColumnIndex<Self>,
+        Json<T>: Decode<'r, Self::Database> + Type<Self::Database>,
+        String: Type<Self::Database>,
+    {
+        let value = self.try_get_raw(&index)?;
+
+        if !value.is_null() {
+            let ty = value.type_info();
+
+            if !ty.is_null() && !Json::<T>::compatible(&ty) && !String::compatible(&ty) {
+                return Err(Error::ColumnDecode {
+                    index: format!("{:?}", index),
+                    source: mismatched_types::<Self::Database, Json<T>>(&ty),
+                });
+            }
+        }
+
+        match Json::<T>::decode(value) {
+            Ok(Json(value)) => Ok(value),
+
+            Err(source) => Err(Error::ColumnDecode {
+                index: format!("{:?}", index),
+                source,
+            }),
+        }
+    }
+
+    /// Index into the database row and decode a JSON value into `T`, or `None` if it is `NULL`.
+    ///
+    /// Note that this is a SQL `NULL`, rather than the JSON `null`, which is decoded into `T`.
+    ///
+    /// # Errors
+    ///
+    /// See [`try_get_json`](Self::try_get_json).
+    ///
+    #[cfg(feature = "json")]
+    fn try_get_json_opt<'r, T, I>(&'r self, index: I) -> Result<Option<T>, Error>
+    where
+        I: ColumnIndex<Self>,
+        Json<T>: Decode<'r, Self::Database> + Type<Self::Database>,
+        String: Type<Self::Database>,
+    {
+        if self.try_get_raw(&index)?.is_null() {
+            return Ok(None);
+        }
+
+        self.try_get_json(index).map(Some)
+    }
+
+    /// Index into the database row and return the value without decoding it.
+    ///
+    /// Use [`ValueRef::is_null`][crate::value::ValueRef::is_null] to check for `NULL`. The
+    /// value reference of each driver gives access to the undecoded data, e.g.
+    /// [`PgValueRef::as_bytes`] and [`PgValueRef::format`] for PostgreSQL, which tell whether
+    /// the bytes are in the text or the binary format of the type.
+    ///
+    /// ```rust,ignore
+    /// use sqlx::postgres::PgValueFormat;
+    /// use sqlx::{Row, ValueRef};
+    ///
+    /// let row = sqlx::query("SELECT 1::int4").fetch_one(&mut conn).await?;
+    /// let raw = row.try_get_raw(0)?;
+    ///
+    /// assert!(!raw.is_null());
+    /// assert_eq!(raw.format(), PgValueFormat::Binary);
+    /// assert_eq!(raw.as_bytes()?, &1_i32.to_be_bytes());
+    /// ```
     ///
     /// # Errors
     ///
@@ -171,6 +295,8 @@ pub trait Row: private_row::Sealed + Unpin + Send + Sync + 'static {
     ///
     /// [`ColumnNotFound`]: Error::ColumnNotFound
     /// [`ColumnIndexOutOfBounds`]: Error::ColumnIndexOutOfBounds
+    /// [`PgValueRef::as_bytes`]: crate::postgres::PgValueRef::as_bytes
+    /// [`PgValueRef::format`]: crate::postgres::PgValueRef::format
     ///
     fn try_get_raw<I>(
         &self,
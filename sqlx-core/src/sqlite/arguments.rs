@@ -1,6 +1,7 @@
 use crate::arguments::Arguments;
 use crate::encode::{Encode, IsNull};
 use crate::error::Error;
+use crate::logger::LoggedParam;
 use crate::sqlite::statement::StatementHandle;
 use crate::sqlite::Sqlite;
 use atoi::atoi;
@@ -41,6 +42,17 @@ impl<'q> SqliteArguments<'q> {
                 .collect(),
         }
     }
+
+    pub(crate) fn logged_values(&self) -> impl Iterator<Item = LoggedParam<'_>> {
+        self.values.iter().map(|value| match value {
+            SqliteArgumentValue::Null => LoggedParam::Null,
+            SqliteArgumentValue::Text(text) => LoggedParam::Text(text),
+            SqliteArgumentValue::Blob(blob) => LoggedParam::Bytes(blob),
+            SqliteArgumentValue::Double(value) => LoggedParam::Other(value.to_string()),
+            SqliteArgumentValue::Int(value) => LoggedParam::Other(value.to_string()),
+            SqliteArgumentValue::Int64(value) => LoggedParam::Other(value.to_string()),
+        })
+    }
 }
 
 impl<'q> Arguments<'q> for SqliteArguments<'q> {
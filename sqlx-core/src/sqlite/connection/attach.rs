@@ -0,0 +1,75 @@
+use std::io;
+use std::path::Path;
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+
+use crate::error::Error;
+use crate::executor::Executor;
+use crate::query::query;
+use crate::sqlite::SqliteConnection;
+
+// https://www.sqlite.org/lang_attach.html
+// https://www.sqlite.org/uri.html
+
+// characters that would otherwise end the path of a URI filename, or be decoded in it
+const URI_PATH: &AsciiSet = &CONTROLS.add(b'?').add(b'#').add(b'%');
+
+fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+impl SqliteConnection {
+    /// Attach the database file at `path` to this connection as `schema`, so that its tables
+    /// can be queried as `schema.table` alongside those of the main database.
+    ///
+    /// The file is opened with the same flags as the main database; for instance, it is only
+    /// created if it does not exist when the connection was opened with
+    /// [`create_if_missing`][crate::sqlite::SqliteConnectOptions::create_if_missing]. This also
+    /// holds for an in-memory main database, though the attached database is still stored in
+    /// the file.
+    ///
+    /// An attachment only lasts as long as the connection. To attach a database to every
+    /// connection of a pool, use [`SqliteConnectOptions::attach()`] instead.
+    ///
+    /// Returns an error if `schema` is already in use or if a transaction is open.
+    ///
+    /// [`SqliteConnectOptions::attach()`]: crate::sqlite::SqliteConnectOptions::attach
+    pub async fn attach(&mut self, path: impl AsRef<Path>, schema: &str) -> Result<(), Error> {
+        let path = path.as_ref().to_str().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "filename passed to SQLite must be valid UTF-8",
+            )
+        })?;
+
+        // an attachment of an in-memory database would be in memory as well, unless the
+        // filename is a URI with an explicit `mode`
+        let filename = match self.attach_mode {
+            Some(mode) => format!("file:{}?mode={}", utf8_percent_encode(path, URI_PATH), mode),
+            None => path.to_owned(),
+        };
+
+        let sql = format!("ATTACH DATABASE ?1 AS {}", quote_identifier(schema));
+
+        query(&sql).bind(filename).execute(self).await?;
+
+        Ok(())
+    }
+
+    /// Detach the database previously attached as `schema` by [`.attach()`][Self::attach].
+    ///
+    /// Returns an error if no database is attached as `schema` or if a transaction is open.
+    pub async fn detach(&mut self, schema: &str) -> Result<(), Error> {
+        let sql = format!("DETACH DATABASE {}", quote_identifier(schema));
+
+        self.execute(&*sql).await?;
+
+        Ok(())
+    }
+}
+
+#[test]
+fn it_quotes_schema_names() {
+    assert_eq!(quote_identifier("other"), "\"other\"");
+    assert_eq!(quote_identifier("a\"b"), "\"a\"\"b\"");
+}
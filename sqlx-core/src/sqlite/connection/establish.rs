@@ -45,6 +45,7 @@ pub struct EstablishParams {
     extensions: IndexMap<CString, Option<CString>>,
     pub(crate) thread_name: String,
     pub(crate) command_channel_size: usize,
+    pub(crate) attach_mode: Option<&'static str>,
 }
 
 impl EstablishParams {
@@ -79,7 +80,8 @@ impl EstablishParams {
         };
 
         if options.in_memory {
-            flags |= SQLITE_OPEN_MEMORY;
+            // URI filenames have to be enabled for one to be given to `SqliteConnection::attach()`
+            flags |= SQLITE_OPEN_MEMORY | libsqlite3_sys::SQLITE_OPEN_URI;
         }
 
         flags |= if options.shared_cache {
@@ -137,6 +139,18 @@ impl EstablishParams {
             })
             .collect::<Result<IndexMap<CString, Option<CString>>, io::Error>>()?;
 
+        // databases are attached with the same flags as the main database, so if that is in
+        // memory they are too, unless given as a URI filename with an explicit `mode`
+        let attach_mode = options.in_memory.then(|| {
+            if options.read_only {
+                "ro"
+            } else if options.create_if_missing {
+                "rwc"
+            } else {
+                "rw"
+            }
+        });
+
         Ok(Self {
             filename,
             open_flags: flags,
@@ -146,6 +160,7 @@ impl EstablishParams {
             extensions,
             thread_name: (options.thread_name)(THREAD_ID.fetch_add(1, Ordering::AcqRel)),
             command_channel_size: options.command_channel_size,
+            attach_mode,
         })
     }
 
@@ -15,6 +15,10 @@ pub struct ExecuteIter<'a> {
     /// this keeps track of the number of arguments so far
     args_used: usize,
 
+    /// the value of `sqlite3_total_changes()` before the current statement was stepped;
+    /// `sqlite3_changes()` is not reset by statements that do not modify rows (e.g., DDL)
+    total_changes_before: u64,
+
     goto_next: bool,
 }
 
@@ -27,7 +31,12 @@ pub(crate) fn iter<'a>(
     // fetch the cached statement or allocate a new one
     let statement = conn.statements.get(query, persistent)?;
 
-    let logger = QueryLogger::new(query, conn.log_settings.clone());
+    let mut logger = QueryLogger::new(query, "sqlite", conn.log_settings.clone());
+
+    if let Some(args) = &args {
+        logger.record_params(args);
+        logger.record_param_values(args.logged_values());
+    }
 
     Ok(ExecuteIter {
         handle: &mut conn.handle,
@@ -35,6 +44,7 @@ pub(crate) fn iter<'a>(
         logger,
         args,
         args_used: 0,
+        total_changes_before: 0,
         goto_next: true,
     })
 }
@@ -67,6 +77,8 @@ impl Iterator for ExecuteIter<'_> {
     type Item = Result<Either<SqliteQueryResult, SqliteRow>, Error>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        let _entered = self.logger.span().entered();
+
         let statement = if self.goto_next {
             let mut statement = match self.statement.prepare_next(self.handle) {
                 Ok(Some(statement)) => statement,
@@ -88,6 +100,8 @@ impl Iterator for ExecuteIter<'_> {
                 Err(e) => return Some(Err(e)),
             }
 
+            self.total_changes_before = self.handle.total_changes();
+
             statement
         } else {
             self.statement.current()?
@@ -106,7 +120,11 @@ impl Iterator for ExecuteIter<'_> {
             Ok(false) => {
                 let last_insert_rowid = self.handle.last_insert_rowid();
 
-                let changes = statement.handle.changes();
+                let changes = if self.handle.total_changes() == self.total_changes_before {
+                    0
+                } else {
+                    statement.handle.changes()
+                };
                 self.logger.increase_rows_affected(changes);
 
                 let done = SqliteQueryResult {
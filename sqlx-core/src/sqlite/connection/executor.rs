@@ -26,7 +26,13 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
 
         Box::pin(
             self.worker
-                .execute(sql, arguments, self.row_channel_size, persistent)
+                .execute(
+                    sql,
+                    arguments,
+                    self.row_channel_size,
+                    persistent,
+                    self.log_settings.clone(),
+                )
                 .map_ok(flume::Receiver::into_stream)
                 .try_flatten_stream(),
         )
@@ -47,7 +53,13 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
         Box::pin(async move {
             let stream = self
                 .worker
-                .execute(sql, arguments, self.row_channel_size, persistent)
+                .execute(
+                    sql,
+                    arguments,
+                    self.row_channel_size,
+                    persistent,
+                    self.log_settings.clone(),
+                )
                 .map_ok(flume::Receiver::into_stream)
                 .try_flatten_stream();
 
@@ -86,6 +98,6 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
     where
         'c: 'e,
     {
-        Box::pin(self.worker.describe(sql))
+        Box::pin(self.worker.describe(sql, self.log_settings.clone()))
     }
 }
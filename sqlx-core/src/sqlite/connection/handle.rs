@@ -4,8 +4,8 @@ use std::ptr::NonNull;
 
 use crate::error::Error;
 use libsqlite3_sys::{
-    sqlite3, sqlite3_close, sqlite3_exec, sqlite3_last_insert_rowid, SQLITE_LOCKED_SHAREDCACHE,
-    SQLITE_OK,
+    sqlite3, sqlite3_close, sqlite3_exec, sqlite3_last_insert_rowid, sqlite3_total_changes,
+    SQLITE_LOCKED_SHAREDCACHE, SQLITE_OK,
 };
 
 use crate::sqlite::{statement::unlock_notify, SqliteError};
@@ -58,6 +58,11 @@ impl ConnectionHandle {
         unsafe { sqlite3_last_insert_rowid(self.as_ptr()) }
     }
 
+    pub(crate) fn total_changes(&mut self) -> u64 {
+        // SAFETY: we have exclusive access to the database handle
+        unsafe { sqlite3_total_changes(self.as_ptr()) as u64 }
+    }
+
     pub(crate) fn exec(&mut self, query: impl Into<String>) -> Result<(), Error> {
         let query = query.into();
         let query = CString::new(query).map_err(|_| err_protocol!("query contains nul bytes"))?;
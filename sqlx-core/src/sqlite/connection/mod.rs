@@ -16,8 +16,9 @@ use crate::sqlite::connection::establish::EstablishParams;
 use crate::sqlite::connection::worker::ConnectionWorker;
 use crate::sqlite::statement::VirtualStatement;
 use crate::sqlite::{Sqlite, SqliteConnectOptions};
-use crate::transaction::Transaction;
+use crate::transaction::{Transaction, TransactionOptions};
 
+mod attach;
 pub(crate) mod collation;
 pub(crate) mod describe;
 pub(crate) mod establish;
@@ -25,6 +26,7 @@ pub(crate) mod execute;
 mod executor;
 mod explain;
 mod handle;
+mod serialize;
 
 mod worker;
 
@@ -41,6 +43,10 @@ mod worker;
 pub struct SqliteConnection {
     pub(crate) worker: ConnectionWorker,
     pub(crate) row_channel_size: usize,
+    // sent to the worker thread along with each statement
+    log_settings: LogSettings,
+    // the `mode` to attach databases with, if the main database is in memory
+    attach_mode: Option<&'static str>,
 }
 
 pub struct LockedSqliteHandle<'a> {
@@ -68,13 +74,33 @@ pub(crate) struct Statements {
 impl SqliteConnection {
     pub(crate) async fn establish(options: &SqliteConnectOptions) -> Result<Self, Error> {
         let params = EstablishParams::from_options(options)?;
+        let attach_mode = params.attach_mode;
         let worker = ConnectionWorker::establish(params).await?;
         Ok(Self {
             worker,
             row_channel_size: options.row_channel_size,
+            log_settings: options.log_settings.clone(),
+            attach_mode,
         })
     }
 
+    /// The settings used to log the statements executed on this connection.
+    pub fn log_settings(&self) -> &LogSettings {
+        &self.log_settings
+    }
+
+    /// Change how the statements executed on this connection are logged,
+    /// starting with the next statement.
+    ///
+    /// This overrides the settings from [`ConnectOptions::log_statements`] and
+    /// [`ConnectOptions::log_slow_statements`] for this connection only.
+    ///
+    /// [`ConnectOptions::log_statements`]: crate::connection::ConnectOptions::log_statements
+    /// [`ConnectOptions::log_slow_statements`]: crate::connection::ConnectOptions::log_slow_statements
+    pub fn set_log_settings(&mut self, settings: LogSettings) {
+        self.log_settings = settings;
+    }
+
     /// Returns the underlying sqlite3* connection handle.
     ///
     /// ### Note
@@ -128,6 +154,34 @@ impl SqliteConnection {
 
         Ok(LockedSqliteHandle { guard })
     }
+
+    /// Serialize the contents of a database to bytes, as they would be stored on disk.
+    ///
+    /// `schema` is the name of the database to serialize, e.g. the name given to an
+    /// `ATTACH`ed database; `None` serializes the main database.
+    ///
+    /// This works for both in-memory and on-disk databases; the result can be restored with
+    /// [`.deserialize()`][Self::deserialize], or written to a file and opened as a database.
+    ///
+    /// See [`sqlite3_serialize()`](https://www.sqlite.org/c3ref/serialize.html) for details.
+    pub async fn serialize(&mut self, schema: Option<&str>) -> Result<Vec<u8>, Error> {
+        self.lock_handle().await?.serialize(schema)
+    }
+
+    /// Replace the contents of a database with the serialized bytes of another,
+    /// as produced by [`.serialize()`][Self::serialize].
+    ///
+    /// `schema` is the name of the database to replace, e.g. the name given to an
+    /// `ATTACH`ed database; `None` replaces the main database.
+    ///
+    /// The database becomes an in-memory database holding a copy of `data`, even if it was
+    /// opened from a file; the file itself is left untouched. The serialized database must not
+    /// be in WAL mode.
+    ///
+    /// See [`sqlite3_deserialize()`](https://www.sqlite.org/c3ref/deserialize.html) for details.
+    pub async fn deserialize(&mut self, schema: Option<&str>, data: &[u8]) -> Result<(), Error> {
+        self.lock_handle().await?.deserialize(schema, data)
+    }
 }
 
 impl Debug for SqliteConnection {
@@ -174,6 +228,16 @@ impl Connection for SqliteConnection {
         Transaction::begin(self)
     }
 
+    fn begin_with_options(
+        &mut self,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<Transaction<'_, Self::Database>, Error>>
+    where
+        Self: Sized,
+    {
+        Transaction::begin_with_options(self, options)
+    }
+
     fn cached_statements_size(&self) -> usize {
         self.worker
             .shared
@@ -222,6 +286,20 @@ impl LockedSqliteHandle<'_> {
     ) -> Result<(), Error> {
         collation::create_collation(&mut self.guard.handle, name, compare)
     }
+
+    /// Serialize the contents of a database to bytes.
+    ///
+    /// See [`SqliteConnection::serialize()`] for details.
+    pub fn serialize(&mut self, schema: Option<&str>) -> Result<Vec<u8>, Error> {
+        serialize::serialize(&mut self.guard.handle, schema)
+    }
+
+    /// Replace the contents of a database with serialized bytes.
+    ///
+    /// See [`SqliteConnection::deserialize()`] for details.
+    pub fn deserialize(&mut self, schema: Option<&str>, data: &[u8]) -> Result<(), Error> {
+        serialize::deserialize(&mut self.guard.handle, schema, data)
+    }
 }
 
 impl Drop for ConnectionState {
@@ -0,0 +1,94 @@
+use std::ffi::CString;
+use std::io;
+use std::os::raw::c_void;
+use std::ptr;
+
+use libsqlite3_sys::{
+    sqlite3_deserialize, sqlite3_free, sqlite3_int64, sqlite3_malloc64, sqlite3_serialize,
+    SQLITE_DESERIALIZE_FREEONCLOSE, SQLITE_DESERIALIZE_RESIZEABLE, SQLITE_OK,
+};
+
+use crate::error::Error;
+use crate::sqlite::connection::handle::ConnectionHandle;
+use crate::sqlite::SqliteError;
+
+// https://www.sqlite.org/c3ref/serialize.html
+// https://www.sqlite.org/c3ref/deserialize.html
+
+fn schema_name(schema: Option<&str>) -> Result<CString, Error> {
+    let schema = schema.unwrap_or("main");
+
+    CString::new(schema).map_err(|_| err_protocol!("invalid schema name: {:?}", schema))
+}
+
+pub(crate) fn serialize(
+    handle: &mut ConnectionHandle,
+    schema: Option<&str>,
+) -> Result<Vec<u8>, Error> {
+    let c_schema = schema_name(schema)?;
+    let mut size: sqlite3_int64 = 0;
+
+    let data = unsafe { sqlite3_serialize(handle.as_ptr(), c_schema.as_ptr(), &mut size, 0) };
+
+    if data.is_null() {
+        // an empty database serializes to zero bytes, for which no memory is allocated
+        if size == 0 {
+            return Ok(Vec::new());
+        }
+
+        // `size` is left at -1 if the schema does not exist
+        return Err(if size < 0 {
+            err_protocol!("no such database schema: {:?}", schema.unwrap_or("main"))
+        } else {
+            io::Error::from(io::ErrorKind::OutOfMemory).into()
+        });
+    }
+
+    let bytes = unsafe { std::slice::from_raw_parts(data, size as usize) }.to_vec();
+
+    unsafe { sqlite3_free(data as *mut c_void) };
+
+    Ok(bytes)
+}
+
+pub(crate) fn deserialize(
+    handle: &mut ConnectionHandle,
+    schema: Option<&str>,
+    data: &[u8],
+) -> Result<(), Error> {
+    let c_schema = schema_name(schema)?;
+    let size = data.len() as sqlite3_int64;
+
+    // SQLite takes ownership of the buffer, so it must be allocated by SQLite as well
+    let buf = if data.is_empty() {
+        ptr::null_mut()
+    } else {
+        let buf = unsafe { sqlite3_malloc64(data.len() as u64) } as *mut u8;
+
+        if buf.is_null() {
+            return Err(io::Error::from(io::ErrorKind::OutOfMemory).into());
+        }
+
+        unsafe { ptr::copy_nonoverlapping(data.as_ptr(), buf, data.len()) };
+
+        buf
+    };
+
+    // the buffer is freed by SQLite even if this fails
+    let status = unsafe {
+        sqlite3_deserialize(
+            handle.as_ptr(),
+            c_schema.as_ptr(),
+            buf,
+            size,
+            size,
+            (SQLITE_DESERIALIZE_FREEONCLOSE | SQLITE_DESERIALIZE_RESIZEABLE) as u32,
+        )
+    };
+
+    if status == SQLITE_OK {
+        Ok(())
+    } else {
+        Err(Error::Database(Box::new(SqliteError::new(handle.as_ptr()))))
+    }
+}
@@ -8,6 +8,7 @@ use either::Either;
 use futures_channel::oneshot;
 use futures_intrusive::sync::{Mutex, MutexGuard};
 
+use crate::connection::LogSettings;
 use crate::describe::Describe;
 use crate::error::Error;
 use crate::sqlite::connection::collation::create_collation;
@@ -44,14 +45,18 @@ enum Command {
         query: Box<str>,
         tx: oneshot::Sender<Result<SqliteStatement<'static>, Error>>,
     },
+    // the commands that log statements carry the current settings of the connection, which
+    // can be changed at any time
     Describe {
         query: Box<str>,
+        log_settings: LogSettings,
         tx: oneshot::Sender<Result<Describe<Sqlite>, Error>>,
     },
     Execute {
         query: Box<str>,
         arguments: Option<SqliteArguments<'static>>,
         persistent: bool,
+        log_settings: LogSettings,
         tx: flume::Sender<Result<Either<SqliteQueryResult, SqliteRow>, Error>>,
     },
     Begin {
@@ -133,15 +138,23 @@ impl ConnectionWorker {
                             }))
                             .ok();
                         }
-                        Command::Describe { query, tx } => {
+                        Command::Describe {
+                            query,
+                            log_settings,
+                            tx,
+                        } => {
+                            conn.log_settings = log_settings;
                             tx.send(describe(&mut conn, &query)).ok();
                         }
                         Command::Execute {
                             query,
                             arguments,
                             persistent,
+                            log_settings,
                             tx,
                         } => {
+                            conn.log_settings = log_settings;
+
                             let iter = match execute::iter(&mut conn, &query, arguments, persistent)
                             {
                                 Ok(iter) => iter,
@@ -280,9 +293,14 @@ impl ConnectionWorker {
         .await?
     }
 
-    pub(crate) async fn describe(&mut self, query: &str) -> Result<Describe<Sqlite>, Error> {
+    pub(crate) async fn describe(
+        &mut self,
+        query: &str,
+        log_settings: LogSettings,
+    ) -> Result<Describe<Sqlite>, Error> {
         self.oneshot_cmd(|tx| Command::Describe {
             query: query.into(),
+            log_settings,
             tx,
         })
         .await?
@@ -294,6 +312,7 @@ impl ConnectionWorker {
         args: Option<SqliteArguments<'_>>,
         chan_size: usize,
         persistent: bool,
+        log_settings: LogSettings,
     ) -> Result<flume::Receiver<Result<Either<SqliteQueryResult, SqliteRow>, Error>>, Error> {
         let (tx, rx) = flume::bounded(chan_size);
 
@@ -302,6 +321,7 @@ impl ConnectionWorker {
                 query: query.into(),
                 arguments: args.map(SqliteArguments::into_static),
                 persistent,
+                log_settings,
                 tx,
             })
             .await
@@ -20,6 +20,10 @@ impl ConnectOptions for SqliteConnectOptions {
             // Execute PRAGMAs
             conn.execute(&*self.pragma_string()).await?;
 
+            for (schema, path) in &self.attachments {
+                conn.attach(path, schema).await?;
+            }
+
             if !self.collations.is_empty() {
                 let mut locked = conn.lock_handle().await?;
 
@@ -41,6 +45,25 @@ impl ConnectOptions for SqliteConnectOptions {
         self.log_settings.log_slow_statements(level, duration);
         self
     }
+
+    fn log_parameters(&mut self, enabled: bool) -> &mut Self {
+        self.log_settings.log_parameters(enabled);
+        self
+    }
+
+    fn redact_parameters(
+        &mut self,
+        redact: impl Fn(usize) -> bool + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.log_settings.redact_parameters(redact);
+        self
+    }
+
+    #[cfg(feature = "tracing")]
+    fn trace_params(&mut self, enabled: bool) -> &mut Self {
+        self.log_settings.trace_params(enabled);
+        self
+    }
 }
 
 impl SqliteConnectOptions {
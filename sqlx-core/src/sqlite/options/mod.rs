@@ -72,6 +72,9 @@ pub struct SqliteConnectOptions {
     /// <https://www.sqlite.org/loadext.html#loading_an_extension>
     pub(crate) extensions: IndexMap<Cow<'static, str>, Option<Cow<'static, str>>>,
 
+    /// Databases to attach on connect, as a map of <Schema Name : Filename>.
+    pub(crate) attachments: IndexMap<Cow<'static, str>, Cow<'static, Path>>,
+
     pub(crate) command_channel_size: usize,
     pub(crate) row_channel_size: usize,
 
@@ -180,6 +183,7 @@ impl SqliteConnectOptions {
             vfs: None,
             pragmas,
             extensions: Default::default(),
+            attachments: Default::default(),
             collations: Default::default(),
             serialized: false,
             thread_name: Arc::new(DebugFn(|id| format!("sqlx-sqlite-worker-{}", id))),
@@ -458,4 +462,31 @@ impl SqliteConnectOptions {
             .insert(extension_name.into(), Some(entry_point.into()));
         self
     }
+
+    /// Attach the database file at `path` as `schema` whenever a connection is established,
+    /// so its tables can be queried as `schema.table`.
+    ///
+    /// This is re-applied to every new connection, unlike
+    /// [`SqliteConnection::attach()`][crate::sqlite::SqliteConnection::attach] which only attaches
+    /// to a single connection. Attaching another file as the same `schema` replaces the previous
+    /// one.
+    ///
+    /// The file is opened with the same flags as the main database, even if that is in memory:
+    /// ```rust,no_run
+    /// # use sqlx_core::error::Error;
+    /// use std::str::FromStr;
+    /// use sqlx::sqlite::SqliteConnectOptions;
+    /// # fn options() -> Result<SqliteConnectOptions, Error> {
+    /// // `other.t` is stored in `other.db`, while `main` only lives in memory
+    /// let options = SqliteConnectOptions::from_str("sqlite::memory:")?
+    ///     .create_if_missing(true)
+    ///     .attach("other.db", "other");
+    /// # Ok(options)
+    /// # }
+    /// ```
+    pub fn attach(mut self, path: impl AsRef<Path>, schema: impl Into<Cow<'static, str>>) -> Self {
+        self.attachments
+            .insert(schema.into(), Cow::Owned(path.as_ref().to_owned()));
+        self
+    }
 }
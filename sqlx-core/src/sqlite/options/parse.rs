@@ -16,10 +16,10 @@ impl FromStr for SqliteConnectOptions {
     fn from_str(mut url: &str) -> Result<Self, Self::Err> {
         let mut options = Self::new();
 
-        // remove scheme from the URL
-        url = url
-            .trim_start_matches("sqlite://")
-            .trim_start_matches("sqlite:");
+        // remove scheme from the URL, which is not case-sensitive
+        url = strip_prefix_ignore_case(url, "sqlite://")
+            .or_else(|| strip_prefix_ignore_case(url, "sqlite:"))
+            .unwrap_or(url);
 
         let mut database_and_params = url.splitn(2, '?');
 
@@ -127,6 +127,12 @@ impl FromStr for SqliteConnectOptions {
     }
 }
 
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    s.get(..prefix.len())
+        .filter(|start| start.eq_ignore_ascii_case(prefix))
+        .map(|_| &s[prefix.len()..])
+}
+
 #[test]
 fn test_parse_in_memory() -> Result<(), Error> {
     let options: SqliteConnectOptions = "sqlite::memory:".parse()?;
@@ -165,3 +171,14 @@ fn test_parse_shared_in_memory() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn test_parse_scheme_ignoring_case() -> Result<(), Error> {
+    let options: SqliteConnectOptions = "SQLITE://data.db".parse()?;
+    assert_eq!(&*options.filename.to_string_lossy(), "data.db");
+
+    let options: SqliteConnectOptions = "SQLite::memory:".parse()?;
+    assert!(options.in_memory);
+
+    Ok(())
+}
@@ -1,8 +1,12 @@
 use futures_core::future::BoxFuture;
 
 use crate::error::Error;
+use crate::executor::Executor;
 use crate::sqlite::{Sqlite, SqliteConnection};
-use crate::transaction::TransactionManager;
+use crate::transaction::{
+    release_savepoint_ansi_sql, rollback_to_savepoint_ansi_sql, savepoint_ansi_sql,
+    TransactionManager, TransactionOptions,
+};
 
 /// Implementation of [`TransactionManager`] for SQLite.
 pub struct SqliteTransactionManager;
@@ -14,6 +18,28 @@ impl TransactionManager for SqliteTransactionManager {
         Box::pin(conn.worker.begin())
     }
 
+    fn begin_with_options(
+        conn: &mut SqliteConnection,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        // transactions in SQLite are always serializable, which satisfies any isolation level
+        Box::pin(async move {
+            if conn.lock_handle().await?.guard.transaction_depth > 0 {
+                return Err(Error::Savepoint(
+                    "the options of a transaction cannot be applied to a savepoint".into(),
+                ));
+            }
+
+            if options.read_only {
+                return Err(Error::Configuration(
+                    "SQLite does not support read-only transactions".into(),
+                ));
+            }
+
+            conn.worker.begin().await
+        })
+    }
+
     fn commit(conn: &mut SqliteConnection) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(conn.worker.commit())
     }
@@ -25,4 +51,39 @@ impl TransactionManager for SqliteTransactionManager {
     fn start_rollback(conn: &mut SqliteConnection) {
         conn.worker.start_rollback().ok();
     }
+
+    fn savepoint<'c>(
+        conn: &'c mut SqliteConnection,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move {
+            conn.execute(&*savepoint_ansi_sql(name, '"')).await?;
+
+            Ok(())
+        })
+    }
+
+    fn rollback_to_savepoint<'c>(
+        conn: &'c mut SqliteConnection,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move {
+            conn.execute(&*rollback_to_savepoint_ansi_sql(name, '"'))
+                .await?;
+
+            Ok(())
+        })
+    }
+
+    fn release_savepoint<'c>(
+        conn: &'c mut SqliteConnection,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move {
+            conn.execute(&*release_savepoint_ansi_sql(name, '"'))
+                .await?;
+
+            Ok(())
+        })
+    }
 }
@@ -20,11 +20,39 @@ pub trait TransactionManager {
         conn: &mut <Self::Database as Database>::Connection,
     ) -> BoxFuture<'_, Result<(), Error>>;
 
+    /// Begin a new transaction with the given isolation level and access mode.
+    ///
+    /// Returns an error if a transaction is already active, as the options of a transaction
+    /// cannot be changed once it has started.
+    ///
+    /// By default, only the default options are supported, with which this is the same as
+    /// [`begin`][Self::begin].
+    fn begin_with_options(
+        conn: &mut <Self::Database as Database>::Connection,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        if options != TransactionOptions::default() {
+            return Box::pin(async move { Err(transaction_options_unsupported()) });
+        }
+
+        Self::begin(conn)
+    }
+
     /// Commit the active transaction or release the most recent savepoint.
     fn commit(
         conn: &mut <Self::Database as Database>::Connection,
     ) -> BoxFuture<'_, Result<(), Error>>;
 
+    /// Release the most recent savepoint along with the next statement that begins, commits or
+    /// aborts a transaction or savepoint, rather than right away.
+    ///
+    /// By default, this is the same as [`commit`][Self::commit].
+    fn commit_with_savepoint_release(
+        conn: &mut <Self::Database as Database>::Connection,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        Self::commit(conn)
+    }
+
     /// Abort the active transaction or restore from the most recent savepoint.
     fn rollback(
         conn: &mut <Self::Database as Database>::Connection,
@@ -32,6 +60,122 @@ pub trait TransactionManager {
 
     /// Starts to abort the active transaction or restore from the most recent snapshot.
     fn start_rollback(conn: &mut <Self::Database as Database>::Connection);
+
+    /// Establish a named savepoint within the active transaction.
+    ///
+    /// By default, named savepoints are not supported and this returns an error.
+    fn savepoint<'c>(
+        _conn: &'c mut <Self::Database as Database>::Connection,
+        _name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move { Err(named_savepoints_unsupported()) })
+    }
+
+    /// Restore from the named savepoint and destroy it.
+    ///
+    /// By default, named savepoints are not supported and this returns an error.
+    fn rollback_to_savepoint<'c>(
+        _conn: &'c mut <Self::Database as Database>::Connection,
+        _name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move { Err(named_savepoints_unsupported()) })
+    }
+
+    /// Destroy the named savepoint, keeping the effects of the commands executed after it.
+    ///
+    /// By default, named savepoints are not supported and this returns an error.
+    fn release_savepoint<'c>(
+        _conn: &'c mut <Self::Database as Database>::Connection,
+        _name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move { Err(named_savepoints_unsupported()) })
+    }
+}
+
+pub(crate) fn transaction_options_unsupported() -> Error {
+    Error::Configuration("transaction options are not supported by this database".into())
+}
+
+fn named_savepoints_unsupported() -> Error {
+    Error::Savepoint("named savepoints are not supported by this database".into())
+}
+
+/// The isolation level of a transaction, which determines which changes made by concurrent
+/// transactions it can see.
+///
+/// See [`TransactionOptions::isolation_level`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl IsolationLevel {
+    pub(crate) fn as_sql(self) -> &'static str {
+        match self {
+            IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+            IsolationLevel::ReadCommitted => "READ COMMITTED",
+            IsolationLevel::RepeatableRead => "REPEATABLE READ",
+            IsolationLevel::Serializable => "SERIALIZABLE",
+        }
+    }
+}
+
+/// Options for beginning a transaction with [`Pool::begin_with_options`] or
+/// [`Connection::begin_with_options`].
+///
+/// Options which are left unset use the defaults of the database or the session.
+///
+/// ```rust
+/// # use sqlx_core::transaction::{IsolationLevel, TransactionOptions};
+/// let options = TransactionOptions::new()
+///     .isolation_level(IsolationLevel::RepeatableRead)
+///     .read_only(true);
+/// ```
+///
+/// [`Pool::begin_with_options`]: crate::pool::Pool::begin_with_options()
+/// [`Connection::begin_with_options`]: crate::connection::Connection::begin_with_options()
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct TransactionOptions {
+    pub(crate) isolation_level: Option<IsolationLevel>,
+    pub(crate) read_only: bool,
+    pub(crate) deferrable: bool,
+}
+
+impl TransactionOptions {
+    /// Creates a set of options which begins a transaction with the defaults of the database.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the isolation level of the transaction.
+    ///
+    /// SQLite transactions are always `SERIALIZABLE`, which satisfies any isolation level.
+    pub fn isolation_level(mut self, level: IsolationLevel) -> Self {
+        self.isolation_level = Some(level);
+        self
+    }
+
+    /// Sets whether the transaction is read-only, in which case the database rejects any
+    /// statements that write to tables.
+    ///
+    /// This is supported by PostgreSQL and MySQL; beginning a read-only transaction with another
+    /// database returns an error.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Sets whether the transaction is deferrable, which only has an effect on a `SERIALIZABLE`,
+    /// read-only transaction in PostgreSQL: it then waits to begin until it can run without
+    /// ever failing because of other transactions, and can be used for long-running reports
+    /// and backups. Other databases ignore this option.
+    pub fn deferrable(mut self, deferrable: bool) -> Self {
+        self.deferrable = deferrable;
+        self
+    }
 }
 
 /// An in-progress database transaction or savepoint.
@@ -46,16 +190,21 @@ pub trait TransactionManager {
 /// executed after it was established to be rolled back, restoring the transaction state to
 /// what it was at the time of the savepoint.
 ///
+/// Named savepoints can also be established explicitly with [`savepoint`].
+///
 /// [`Connection::begin`]: crate::connection::Connection::begin()
 /// [`Pool::begin`]: crate::pool::Pool::begin()
 /// [`commit`]: Self::commit()
 /// [`rollback`]: Self::rollback()
+/// [`savepoint`]: Self::savepoint()
 pub struct Transaction<'c, DB>
 where
     DB: Database,
 {
     connection: MaybePoolConnection<'c, DB>,
     open: bool,
+    // named savepoints established in this transaction, oldest first
+    savepoints: Vec<String>,
 }
 
 impl<'c, DB> Transaction<'c, DB>
@@ -73,6 +222,24 @@ where
             Ok(Self {
                 connection: conn,
                 open: true,
+                savepoints: Vec::new(),
+            })
+        })
+    }
+
+    pub(crate) fn begin_with_options(
+        conn: impl Into<MaybePoolConnection<'c, DB>>,
+        options: TransactionOptions,
+    ) -> BoxFuture<'c, Result<Self, Error>> {
+        let mut conn = conn.into();
+
+        Box::pin(async move {
+            DB::TransactionManager::begin_with_options(&mut conn, options).await?;
+
+            Ok(Self {
+                connection: conn,
+                open: true,
+                savepoints: Vec::new(),
             })
         })
     }
@@ -85,6 +252,37 @@ where
         Ok(())
     }
 
+    /// Commits this savepoint without waiting for the server to release it.
+    ///
+    /// With PostgreSQL and MySQL, the savepoint is instead released along with the next
+    /// statement that begins, commits or aborts a transaction or savepoint on the connection,
+    /// so that committing many nested transactions takes a single round trip. Releasing a
+    /// savepoint releases those established after it, and ending the transaction releases all
+    /// of them. If the enclosing transaction is rolled back, so are the changes of this one.
+    ///
+    /// As the savepoint is not released right away, an error releasing it is reported by that
+    /// later statement, if at all. A transaction that is not a savepoint, and any transaction
+    /// with the other databases, is committed as with [`commit`][Self::commit].
+    ///
+    /// ```rust,ignore
+    /// let mut tx = conn.begin().await?;
+    /// let mut tx1 = tx.begin().await?;
+    /// let mut tx2 = tx1.begin().await?;
+    ///
+    /// // neither of these waits for the server
+    /// tx2.commit_with_savepoint_release().await?;
+    /// tx1.commit_with_savepoint_release().await?;
+    ///
+    /// // a single `COMMIT` ends the transaction along with both savepoints
+    /// tx.commit().await?;
+    /// ```
+    pub async fn commit_with_savepoint_release(mut self) -> Result<(), Error> {
+        DB::TransactionManager::commit_with_savepoint_release(&mut self.connection).await?;
+        self.open = false;
+
+        Ok(())
+    }
+
     /// Aborts this transaction or savepoint.
     pub async fn rollback(mut self) -> Result<(), Error> {
         DB::TransactionManager::rollback(&mut self.connection).await?;
@@ -92,6 +290,170 @@ where
 
         Ok(())
     }
+
+    /// Establishes a named savepoint within this transaction.
+    ///
+    /// The transaction can later be restored to the state it was in at this point with
+    /// [`rollback_to`], or the savepoint discarded with [`release`]; the returned guard offers
+    /// both as a shorthand. Dropping the guard leaves the savepoint in place.
+    ///
+    /// The name must be a plain identifier (ASCII letters, digits and underscores) and must not
+    /// already be in use by another savepoint of this transaction, ignoring case. It is quoted
+    /// where the database would otherwise fold its case, so it must be given with the same case
+    /// to [`rollback_to`] and [`release`].
+    ///
+    /// ```rust,ignore
+    /// let mut tx = conn.begin().await?;
+    ///
+    /// sqlx::query("INSERT INTO users (name) VALUES ('alice')").execute(&mut tx).await?;
+    ///
+    /// tx.savepoint("before_bob").await?;
+    /// sqlx::query("INSERT INTO users (name) VALUES ('bob')").execute(&mut tx).await?;
+    /// tx.rollback_to("before_bob").await?;
+    ///
+    /// // only `alice` is inserted
+    /// tx.commit().await?;
+    /// ```
+    ///
+    /// [`rollback_to`]: Self::rollback_to()
+    /// [`release`]: Self::release()
+    pub async fn savepoint(&mut self, name: &str) -> Result<Savepoint<'_, 'c, DB>, Error> {
+        if name.is_empty()
+            || name.starts_with(|c: char| c.is_ascii_digit())
+            || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            return Err(Error::Savepoint(format!(
+                "`{}` is not a valid savepoint name",
+                name
+            )));
+        }
+
+        // some databases compare the names of savepoints case-insensitively
+        if self
+            .savepoints
+            .iter()
+            .any(|savepoint| savepoint.eq_ignore_ascii_case(name))
+        {
+            return Err(Error::Savepoint(format!(
+                "savepoint `{}` already exists in this transaction",
+                name
+            )));
+        }
+
+        DB::TransactionManager::savepoint(&mut self.connection, name).await?;
+        self.savepoints.push(name.to_owned());
+
+        Ok(Savepoint {
+            transaction: self,
+            name: name.to_owned(),
+        })
+    }
+
+    /// Restores this transaction to the state it was in when the named savepoint was established.
+    ///
+    /// The savepoint, and any savepoints established after it, are destroyed.
+    pub async fn rollback_to(&mut self, name: &str) -> Result<(), Error> {
+        let index = self.savepoint_index(name)?;
+
+        DB::TransactionManager::rollback_to_savepoint(&mut self.connection, name).await?;
+        self.savepoints.truncate(index);
+
+        Ok(())
+    }
+
+    /// Destroys the named savepoint, keeping the effects of the commands executed after it.
+    ///
+    /// Any savepoints established after it are destroyed as well.
+    pub async fn release(&mut self, name: &str) -> Result<(), Error> {
+        let index = self.savepoint_index(name)?;
+
+        DB::TransactionManager::release_savepoint(&mut self.connection, name).await?;
+        self.savepoints.truncate(index);
+
+        Ok(())
+    }
+
+    fn savepoint_index(&self, name: &str) -> Result<usize, Error> {
+        self.savepoints
+            .iter()
+            .position(|savepoint| savepoint == name)
+            .ok_or_else(|| {
+                Error::Savepoint(format!(
+                    "savepoint `{}` does not exist in this transaction; \
+                     it may have already been rolled back or released",
+                    name
+                ))
+            })
+    }
+}
+
+/// A named savepoint established with [`Transaction::savepoint`].
+///
+/// The guard dereferences to the transaction it was established in. Dropping it leaves
+/// the savepoint in place.
+pub struct Savepoint<'t, 'c, DB>
+where
+    DB: Database,
+{
+    transaction: &'t mut Transaction<'c, DB>,
+    name: String,
+}
+
+impl<'t, 'c, DB> Savepoint<'t, 'c, DB>
+where
+    DB: Database,
+{
+    /// The name of this savepoint.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Restores the transaction to the state it was in when this savepoint was established.
+    ///
+    /// See [`Transaction::rollback_to`].
+    pub async fn rollback(self) -> Result<(), Error> {
+        self.transaction.rollback_to(&self.name).await
+    }
+
+    /// Destroys this savepoint, keeping the effects of the commands executed after it.
+    ///
+    /// See [`Transaction::release`].
+    pub async fn release(self) -> Result<(), Error> {
+        self.transaction.release(&self.name).await
+    }
+}
+
+impl<'t, 'c, DB> Debug for Savepoint<'t, 'c, DB>
+where
+    DB: Database,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Savepoint")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+impl<'t, 'c, DB> Deref for Savepoint<'t, 'c, DB>
+where
+    DB: Database,
+{
+    type Target = Transaction<'c, DB>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.transaction
+    }
+}
+
+impl<'t, 'c, DB> DerefMut for Savepoint<'t, 'c, DB>
+where
+    DB: Database,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.transaction
+    }
 }
 
 // NOTE: required due to lack of lazy normalization
@@ -120,6 +482,24 @@ macro_rules! impl_executor_for_transaction {
                 (&mut **self).fetch_many(query)
             }
 
+            fn fetch_many_with_size<'e, 'q: 'e, E: 'q>(
+                self,
+                query: E,
+                size: usize,
+            ) -> futures_core::stream::BoxStream<
+                'e,
+                Result<
+                    either::Either<<$DB as crate::database::Database>::QueryResult, $Row>,
+                    crate::error::Error,
+                >,
+            >
+            where
+                't: 'e,
+                E: crate::executor::Execute<'q, Self::Database>,
+            {
+                (&mut **self).fetch_many_with_size(query, size)
+            }
+
             fn fetch_optional<'e, 'q: 'e, E: 'q>(
                 self,
                 query: E,
@@ -214,6 +594,29 @@ where
     }
 }
 
+#[allow(dead_code)]
+pub(crate) fn begin_ansi_transaction_with_options_sql(options: TransactionOptions) -> String {
+    let mut modes = Vec::new();
+
+    if let Some(level) = options.isolation_level {
+        modes.push(format!("ISOLATION LEVEL {}", level.as_sql()));
+    }
+
+    if options.read_only {
+        modes.push("READ ONLY".to_owned());
+    }
+
+    if options.deferrable {
+        modes.push("DEFERRABLE".to_owned());
+    }
+
+    if modes.is_empty() {
+        "BEGIN".to_owned()
+    } else {
+        format!("BEGIN {}", modes.join(", "))
+    }
+}
+
 #[allow(dead_code)]
 pub(crate) fn begin_ansi_transaction_sql(depth: usize) -> Cow<'static, str> {
     if depth == 0 {
@@ -232,6 +635,45 @@ pub(crate) fn commit_ansi_transaction_sql(depth: usize) -> Cow<'static, str> {
     }
 }
 
+// the names of savepoints are validated to be plain identifiers, so they only need to be quoted
+// to keep their case; `quote` is `"` in standard SQL and a backtick in MySQL
+#[allow(dead_code)]
+pub(crate) fn savepoint_ansi_sql(name: &str, quote: char) -> String {
+    format!("SAVEPOINT {1}{0}{1}", name, quote)
+}
+
+#[allow(dead_code)]
+pub(crate) fn rollback_to_savepoint_ansi_sql(name: &str, quote: char) -> String {
+    // `ROLLBACK TO` keeps the savepoint around, release it too so it can't be used again
+    format!(
+        "ROLLBACK TO SAVEPOINT {1}{0}{1}; RELEASE SAVEPOINT {1}{0}{1}",
+        name, quote
+    )
+}
+
+#[allow(dead_code)]
+pub(crate) fn release_savepoint_ansi_sql(name: &str, quote: char) -> String {
+    format!("RELEASE SAVEPOINT {1}{0}{1}", name, quote)
+}
+
+// a savepoint committed with `commit_with_savepoint_release` is only released along with the
+// next statement that establishes, restores or releases a savepoint: releasing a savepoint also
+// releases those established after it, and the end of the transaction releases all of them, so
+// the savepoints of many nested transactions are released in at most one round trip
+#[allow(dead_code)]
+pub(crate) fn release_deferred_savepoint_sql<'a>(
+    deferred: &mut Option<usize>,
+    sql: &'a str,
+) -> Cow<'a, str> {
+    match deferred.take() {
+        Some(index) => Cow::Owned(format!(
+            "RELEASE SAVEPOINT _sqlx_savepoint_{}; {}",
+            index, sql
+        )),
+        None => Cow::Borrowed(sql),
+    }
+}
+
 #[allow(dead_code)]
 pub(crate) fn rollback_ansi_transaction_sql(depth: usize) -> Cow<'static, str> {
     if depth == 1 {
@@ -243,3 +685,34 @@ pub(crate) fn rollback_ansi_transaction_sql(depth: usize) -> Cow<'static, str> {
         ))
     }
 }
+
+#[test]
+fn test_begin_ansi_transaction_with_options_sql() {
+    let options = TransactionOptions::new();
+    assert_eq!(begin_ansi_transaction_with_options_sql(options), "BEGIN");
+
+    let options = options
+        .isolation_level(IsolationLevel::Serializable)
+        .read_only(true)
+        .deferrable(true);
+    assert_eq!(
+        begin_ansi_transaction_with_options_sql(options),
+        "BEGIN ISOLATION LEVEL SERIALIZABLE, READ ONLY, DEFERRABLE"
+    );
+}
+
+#[test]
+fn test_release_deferred_savepoint_sql() {
+    let mut deferred = None;
+    assert_eq!(
+        release_deferred_savepoint_sql(&mut deferred, "SAVEPOINT _sqlx_savepoint_1"),
+        "SAVEPOINT _sqlx_savepoint_1"
+    );
+
+    let mut deferred = Some(1);
+    assert_eq!(
+        release_deferred_savepoint_sql(&mut deferred, "SAVEPOINT _sqlx_savepoint_1"),
+        "RELEASE SAVEPOINT _sqlx_savepoint_1; SAVEPOINT _sqlx_savepoint_1"
+    );
+    assert_eq!(deferred, None);
+}
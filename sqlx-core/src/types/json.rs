@@ -53,6 +53,21 @@ use crate::types::Type;
 ///   dewey_decimal: sqlx::types::Json<HashMap<String, Book>>
 /// }
 /// ```
+///
+/// # `NULL` and JSON `null`
+///
+/// SQL `NULL` and the JSON literal `null` are different values, and can be told apart by
+/// nesting `Option`s:
+///
+/// | Column value    | `Option<Json<Option<T>>>` |
+/// |-----------------|---------------------------|
+/// | SQL `NULL`      | `None`                    |
+/// | JSON `null`     | `Some(Json(None))`        |
+/// | any other JSON  | `Some(Json(Some(value)))` |
+///
+/// The same holds for encoding: `None::<Json<T>>` binds SQL `NULL`, while `Json(None::<T>)`
+/// binds the JSON `null` literal. Decoding JSON `null` into `Option<Json<T>>` fails unless `T`
+/// itself can be deserialized from `null`.
 #[derive(
     Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
 )]
@@ -17,6 +17,23 @@
 //! To represent nullable SQL types, `Option<T>` is supported where `T` implements `Type`.
 //! An `Option<T>` represents a potentially `NULL` value from SQL.
 //!
+//! A `NULL` bound as `None::<T>` is still sent with the type of `T` where the database allows it,
+//! so it can be used where the server would otherwise fail to infer the type of the parameter,
+//! such as to pick an overload of a function in PostgreSQL:
+//!
+//! ```rust,ignore
+//! // calls `lower(text)` rather than failing with "could not determine data type"
+//! sqlx::query("SELECT lower($1)").bind(None::<String>)
+//! ```
+//!
+//! The types are sent when the statement is prepared, so a statement that the connection has
+//! already cached keeps the parameter types it was first prepared with.
+//!
+//! # Text
+//!
+//! To store a value in a text column as its string form, such as a UUID in a `varchar(36)`,
+//! wrap it in [`Text<T>`], which encodes it with `Display` and decodes it with `FromStr`.
+//!
 
 use crate::database::Database;
 
@@ -32,6 +49,8 @@ pub mod git2;
 #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
 mod json;
 
+mod text;
+
 #[cfg(feature = "uuid")]
 #[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
 #[doc(no_inline)]
@@ -42,7 +61,7 @@ pub use uuid::{self, Uuid};
 pub mod chrono {
     #[doc(no_inline)]
     pub use chrono::{
-        DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+        DateTime, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
     };
 }
 
@@ -55,7 +74,7 @@ pub use bit_vec::BitVec;
 #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
 pub mod time {
     #[doc(no_inline)]
-    pub use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+    pub use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
 }
 
 #[cfg(feature = "bigdecimal")]
@@ -82,9 +101,18 @@ pub mod mac_address {
     pub use mac_address::MacAddress;
 }
 
+#[cfg(feature = "postgis")]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgis")))]
+pub mod geo_types {
+    #[doc(no_inline)]
+    pub use geo_types::Point;
+}
+
 #[cfg(feature = "json")]
 pub use json::{Json, JsonRawValue, JsonValue};
 
+pub use text::Text;
+
 /// Indicates that a SQL type is supported for a database.
 ///
 /// ## Compile-time verification
@@ -141,6 +169,17 @@ pub use json::{Json, JsonRawValue, JsonValue};
 /// enum Color { Red = 1, Green = 2, Blue = 3 }
 /// ```
 ///
+/// The integer type can also be given as `#[sqlx(repr = "<integer type>")]`, leaving the
+/// in-memory representation of the enum to the compiler. Each discriminant must fit in that type,
+/// or the derive fails to compile. Decoding an integer that is not a discriminant of any variant
+/// returns an error.
+///
+/// ```rust,ignore
+/// #[derive(sqlx::Type)]
+/// #[sqlx(repr = "i16")]
+/// enum Status { Active = 1, Suspended = 2 }
+/// ```
+///
 /// ```rust,ignore
 /// #[derive(sqlx::Type)]
 /// #[sqlx(type_name = "color")] // only for PostgreSQL to match a type definition
@@ -148,6 +187,9 @@ pub use json::{Json, JsonRawValue, JsonValue};
 /// enum Color { Red, Green, Blue }
 /// ```
 ///
+/// For PostgreSQL, `#[sqlx(pg_array)]` also generates an implementation of `PgHasArrayType` for
+/// the latter, so `Vec<Color>` can be bound and decoded as `color[]`, as with records.
+///
 /// ### Records
 ///
 /// User-defined composite types are supported through deriving a `struct`.
@@ -164,6 +206,11 @@ pub use json::{Json, JsonRawValue, JsonValue};
 /// }
 /// ```
 ///
+/// With `#[sqlx(pg_array)]`, an implementation of `PgHasArrayType` is generated as well, so
+/// `Vec<InterfaceType>` can be bound and decoded as `interface_type[]`. The array type is named
+/// after the type with a leading underscore, as Postgres does, e.g. `schema._name` for a
+/// `type_name` of `schema.name`.
+///
 pub trait Type<DB: Database> {
     /// Returns the canonical SQL type for this Rust type.
     ///
@@ -0,0 +1,124 @@
+use std::any::type_name;
+use std::fmt::{self, Display};
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+
+use crate::database::{Database, HasArguments, HasValueRef};
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+
+/// A value stored in a text column, such as `text` or `varchar`, as its string form.
+///
+/// The value is encoded with its [`Display`] implementation and decoded with its [`FromStr`]
+/// implementation, which makes this useful for types that a schema stores as text rather than
+/// with a native type of the database. This works with any database, as the value is sent and
+/// received as a string.
+///
+/// A value that fails to parse is a decode error, which names the type it was parsed as along
+/// with the error returned by `FromStr`.
+///
+/// # Example
+///
+/// A `Uuid` in a `varchar(36)` column is encoded in its lowercase, hyphenated form, e.g.
+/// `67e55044-10b1-426f-9247-bb680e5fe0c8`. Any form that `Uuid::parse_str` accepts can be
+/// decoded, including uppercase and braced ones such as `{67E55044-10B1-426F-9247-BB680E5FE0C8}`.
+///
+/// ```rust,ignore
+/// use sqlx::types::{Text, Uuid};
+///
+/// sqlx::query("INSERT INTO users (id) VALUES ($1)")
+///     .bind(Text(Uuid::new_v4()))
+///     .execute(&mut conn)
+///     .await?;
+///
+/// let ids: Vec<Text<Uuid>> = sqlx::query_scalar("SELECT id FROM users")
+///     .fetch_all(&mut conn)
+///     .await?;
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Text<T>(pub T);
+
+impl<T> Text<T> {
+    /// The wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Text<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Text<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> AsRef<T> for Text<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsMut<T> for Text<T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Display> Display for Text<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T, DB> Type<DB> for Text<T>
+where
+    String: Type<DB>,
+    DB: Database,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, T, DB> Encode<'q, DB> for Text<T>
+where
+    T: Display,
+    String: Encode<'q, DB>,
+    DB: Database,
+{
+    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+        <String as Encode<'q, DB>>::encode(self.0.to_string(), buf)
+    }
+}
+
+impl<'r, T, DB> Decode<'r, DB> for Text<T>
+where
+    T: FromStr,
+    T::Err: Into<BoxDynError>,
+    // not `&str`, which some databases can not decode without a copy, e.g. MSSQL
+    String: Decode<'r, DB>,
+    DB: Database,
+{
+    fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+        let s = <String as Decode<'r, DB>>::decode(value)?;
+
+        s.parse().map(Text).map_err(|error: T::Err| {
+            let error: BoxDynError = error.into();
+
+            format!("error parsing `{}` from text: {}", type_name::<T>(), error).into()
+        })
+    }
+}
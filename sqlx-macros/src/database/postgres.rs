@@ -4,6 +4,8 @@ impl_database_ext! {
     sqlx::postgres::Postgres {
         (),
         bool,
+        // before `String`, which can only decode a REGCLASS or REGTYPE in the text format
+        sqlx::postgres::types::Oid,
         String | &str,
         i8,
         i16,
@@ -13,12 +15,18 @@ impl_database_ext! {
         f64,
         Vec<u8> | &[u8],
 
-        sqlx::postgres::types::Oid,
-
         sqlx::postgres::types::PgInterval,
 
         sqlx::postgres::types::PgMoney,
 
+        sqlx::postgres::types::PgPoint,
+
+        sqlx::postgres::types::PgLine,
+
+        sqlx::postgres::types::PgBox,
+
+        sqlx::postgres::types::PgCircle,
+
         sqlx::postgres::types::PgLTree,
 
         sqlx::postgres::types::PgLQuery,
@@ -77,6 +85,7 @@ impl_database_ext! {
         // Arrays
 
         Vec<bool> | &[bool],
+        Vec<sqlx::postgres::types::Oid> | &[sqlx::postgres::types::Oid],
         Vec<String> | &[String],
         Vec<Vec<u8>> | &[Vec<u8>],
         Vec<i8> | &[i8],
@@ -85,8 +94,11 @@ impl_database_ext! {
         Vec<i64> | &[i64],
         Vec<f32> | &[f32],
         Vec<f64> | &[f64],
-        Vec<sqlx::postgres::types::Oid> | &[sqlx::postgres::types::Oid],
         Vec<sqlx::postgres::types::PgMoney> | &[sqlx::postgres::types::PgMoney],
+        Vec<sqlx::postgres::types::PgPoint> | &[sqlx::postgres::types::PgPoint],
+        Vec<sqlx::postgres::types::PgLine> | &[sqlx::postgres::types::PgLine],
+        Vec<sqlx::postgres::types::PgBox> | &[sqlx::postgres::types::PgBox],
+        Vec<sqlx::postgres::types::PgCircle> | &[sqlx::postgres::types::PgCircle],
 
         #[cfg(feature = "uuid")]
         Vec<sqlx::types::Uuid> | &[sqlx::types::Uuid],
@@ -65,6 +65,7 @@ pub struct SqlxContainerAttributes {
     pub type_name: Option<TypeName>,
     pub rename_all: Option<RenameAll>,
     pub repr: Option<Ident>,
+    pub pg_array: bool,
 }
 
 pub struct SqlxChildAttributes {
@@ -72,6 +73,7 @@ pub struct SqlxChildAttributes {
     pub default: bool,
     pub flatten: bool,
     pub try_from: Option<Ident>,
+    pub prefix: Option<String>,
 }
 
 pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContainerAttributes> {
@@ -79,6 +81,7 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
     let mut repr = None;
     let mut type_name = None;
     let mut rename_all = None;
+    let mut pg_array = None;
 
     for attr in input
         .iter()
@@ -96,6 +99,10 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
                                 try_set!(transparent, true, value)
                             }
 
+                            Meta::Path(p) if p.is_ident("pg_array") => {
+                                try_set!(pg_array, true, value)
+                            }
+
                             Meta::NameValue(MetaNameValue {
                                 path,
                                 lit: Lit::Str(val),
@@ -131,6 +138,14 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
                                 )
                             }
 
+                            Meta::NameValue(MetaNameValue {
+                                path,
+                                lit: Lit::Str(val),
+                                ..
+                            }) if path.is_ident("repr") => {
+                                try_set!(repr, val.parse()?, value)
+                            }
+
                             Meta::NameValue(MetaNameValue {
                                 path,
                                 lit: Lit::Str(val),
@@ -173,6 +188,7 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
         repr,
         type_name,
         rename_all,
+        pg_array: pg_array.unwrap_or(false),
     })
 }
 
@@ -181,6 +197,7 @@ pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttri
     let mut default = false;
     let mut try_from = None;
     let mut flatten = false;
+    let mut prefix = None;
 
     for attr in input.iter().filter(|a| a.path.is_ident("sqlx")) {
         let meta = attr
@@ -201,6 +218,11 @@ pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttri
                             lit: Lit::Str(val),
                             ..
                         }) if path.is_ident("try_from") => try_set!(try_from, val.parse()?, value),
+                        Meta::NameValue(MetaNameValue {
+                            path,
+                            lit: Lit::Str(val),
+                            ..
+                        }) if path.is_ident("prefix") => try_set!(prefix, val.value(), value),
                         Meta::Path(path) if path.is_ident("default") => default = true,
                         Meta::Path(path) if path.is_ident("flatten") => flatten = true,
                         u => fail!(u, "unexpected attribute"),
@@ -216,6 +238,7 @@ pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttri
         default,
         flatten,
         try_from,
+        prefix,
     })
 }
 
@@ -260,11 +283,15 @@ pub fn check_weak_enum_attributes(
 ) -> syn::Result<SqlxContainerAttributes> {
     let attributes = check_enum_attributes(input)?;
 
-    assert_attribute!(attributes.repr.is_some(), "expected #[repr(..)]", input);
+    assert_attribute!(
+        attributes.repr.is_some(),
+        "expected #[repr(..)] or #[sqlx(repr = \"..\")]",
+        input
+    );
 
     assert_attribute!(
         attributes.rename_all.is_none(),
-        "unexpected #[sqlx(c = ..)]",
+        "unexpected #[sqlx(rename_all = ..)]",
         input
     );
 
@@ -67,6 +67,17 @@ fn expand_derive_from_row_struct(
 
     let container_attributes = parse_container_attributes(&input.attrs)?;
 
+    for field in fields {
+        let attributes = parse_child_attributes(&field.attrs)?;
+
+        if attributes.prefix.is_some() && !attributes.flatten {
+            return Err(syn::Error::new_spanned(
+                field,
+                "#[sqlx(prefix = ..)] requires #[sqlx(flatten)]",
+            ));
+        }
+    }
+
     let reads: Vec<Stmt> = fields
         .iter()
         .filter_map(|field| -> Option<Stmt> {
@@ -74,29 +85,36 @@ fn expand_derive_from_row_struct(
             let attributes = parse_child_attributes(&field.attrs).unwrap();
             let ty = &field.ty;
 
+            // the prefix of the columns of a flattened field is appended to that of this struct,
+            // named so that it can't be shadowed by the fields read before it
+            let prefix: Expr = match &attributes.prefix {
+                Some(field_prefix) => parse_quote!(&[__prefix, #field_prefix].concat()),
+                None => parse_quote!(__prefix),
+            };
+
             let expr: Expr = match (attributes.flatten, attributes.try_from) {
                 (true, None) => {
                     predicates.push(parse_quote!(#ty: ::sqlx::FromRow<#lifetime, R>));
-                    parse_quote!(<#ty as ::sqlx::FromRow<#lifetime, R>>::from_row(row))
+                    parse_quote!(<#ty as ::sqlx::FromRow<#lifetime, R>>::from_row_with_prefix(row, #prefix))
                 }
                 (false, None) => {
                     predicates
                         .push(parse_quote!(#ty: ::sqlx::decode::Decode<#lifetime, R::Database>));
                     predicates.push(parse_quote!(#ty: ::sqlx::types::Type<R::Database>));
 
-                    let id_s = attributes
-                        .rename
-                        .or_else(|| Some(id.to_string().trim_start_matches("r#").to_owned()))
-                        .map(|s| match container_attributes.rename_all {
+                    // an explicit `#[sqlx(rename)]` takes precedence over `rename_all`
+                    let id_s = attributes.rename.unwrap_or_else(|| {
+                        let s = id.to_string().trim_start_matches("r#").to_owned();
+                        match container_attributes.rename_all {
                             Some(pattern) => rename_all(&s, pattern),
                             None => s,
-                        })
-                        .unwrap();
-                    parse_quote!(row.try_get(#id_s))
+                        }
+                    });
+                    parse_quote!(::sqlx::prefixed_column_name(row, __prefix, #id_s).and_then(|name| row.try_get(name)))
                 }
                 (true,Some(try_from)) => {
                     predicates.push(parse_quote!(#try_from: ::sqlx::FromRow<#lifetime, R>));
-                    parse_quote!(<#try_from as ::sqlx::FromRow<#lifetime, R>>::from_row(row).and_then(|v| <#ty as ::std::convert::TryFrom::<#try_from>>::try_from(v).map_err(|e| ::sqlx::Error::ColumnNotFound("FromRow: try_from failed".to_string())))) 
+                    parse_quote!(<#try_from as ::sqlx::FromRow<#lifetime, R>>::from_row_with_prefix(row, #prefix).and_then(|v| <#ty as ::std::convert::TryFrom::<#try_from>>::try_from(v).map_err(|e| ::sqlx::Error::ColumnNotFound("FromRow: try_from failed".to_string())))) 
                 }
                 (false,Some(try_from)) => {
                     let predicate = parse_quote!(#try_from: ::sqlx::decode::Decode<#lifetime, R::Database>);
@@ -104,15 +122,15 @@ fn expand_derive_from_row_struct(
                     let predicate2 = parse_quote!(#try_from: ::sqlx::types::Type<R::Database>);
                     predicates.push(predicate2);
 
-                    let id_s = attributes
-                        .rename
-                        .or_else(|| Some(id.to_string().trim_start_matches("r#").to_owned()))
-                        .map(|s| match container_attributes.rename_all {
+                    // an explicit `#[sqlx(rename)]` takes precedence over `rename_all`
+                    let id_s = attributes.rename.unwrap_or_else(|| {
+                        let s = id.to_string().trim_start_matches("r#").to_owned();
+                        match container_attributes.rename_all {
                             Some(pattern) => rename_all(&s, pattern),
                             None => s,
-                        })
-                        .unwrap();
-                    parse_quote!(row.try_get(#id_s).and_then(|v| <#ty as ::std::convert::TryFrom::<#try_from>>::try_from(v).map_err(|e| ::sqlx::Error::ColumnNotFound("FromRow: try_from failed".to_string()))))
+                        }
+                    });
+                    parse_quote!(::sqlx::prefixed_column_name(row, __prefix, #id_s).and_then(|name| row.try_get(name)).and_then(|v| <#ty as ::std::convert::TryFrom::<#try_from>>::try_from(v).map_err(|e| ::sqlx::Error::ColumnNotFound("FromRow: try_from failed".to_string()))))
                 }
             };
 
@@ -139,6 +157,13 @@ fn expand_derive_from_row_struct(
         #[automatically_derived]
         impl #impl_generics ::sqlx::FromRow<#lifetime, R> for #ident #ty_generics #where_clause {
             fn from_row(row: &#lifetime R) -> ::sqlx::Result<Self> {
+                Self::from_row_with_prefix(row, "")
+            }
+
+            fn from_row_with_prefix(
+                row: &#lifetime R,
+                __prefix: &::std::primitive::str,
+            ) -> ::sqlx::Result<Self> {
                 #(#reads)*
 
                 ::std::result::Result::Ok(#ident {
@@ -124,6 +124,20 @@ fn expand_derive_has_sql_type_weak_enum(
     let attr = check_weak_enum_attributes(input, variants)?;
     let repr = attr.repr.unwrap();
     let ident = &input.ident;
+
+    // with `#[sqlx(repr = ..)]` the discriminants are not checked by the compiler, and one that
+    // does not fit would be truncated into the discriminant of another variant
+    let checks = variants.iter().map(|v| {
+        let id = &v.ident;
+        let message = format!("discriminant of {}::{} does not fit in {}", ident, id, repr);
+
+        quote!(
+            if (#ident::#id as #repr) as i128 != #ident::#id as i128 {
+                ::std::panic!(#message);
+            }
+        )
+    });
+
     let ts = quote!(
         #[automatically_derived]
         impl<DB: ::sqlx::Database> ::sqlx::Type<DB> for #ident
@@ -138,6 +152,10 @@ fn expand_derive_has_sql_type_weak_enum(
                 <#repr as ::sqlx::Type<DB>>::compatible(ty)
             }
         }
+
+        const _: () = {
+            #(#checks)*
+        };
     );
 
     Ok(ts)
@@ -161,7 +179,7 @@ fn expand_derive_has_sql_type_strong_enum(
                 }
 
                 fn compatible(ty: &::sqlx::mysql::MySqlTypeInfo) -> ::std::primitive::bool {
-                    *ty == ::sqlx::mysql::MySqlTypeInfo::__enum()
+                    ty.__enum_compatible()
                 }
             }
         ));
@@ -178,6 +196,19 @@ fn expand_derive_has_sql_type_strong_enum(
                 }
             }
         ));
+
+        if attributes.pg_array {
+            let array_ty_name = array_type_name(ident, attributes.type_name.as_ref());
+
+            tts.extend(quote!(
+                #[automatically_derived]
+                impl ::sqlx::postgres::PgHasArrayType for #ident {
+                    fn array_type_info() -> ::sqlx::postgres::PgTypeInfo {
+                        ::sqlx::postgres::PgTypeInfo::with_name(#array_ty_name)
+                    }
+                }
+            ));
+        }
     }
 
     if cfg!(feature = "sqlite") {
@@ -218,11 +249,38 @@ fn expand_derive_has_sql_type_struct(
                 }
             }
         ));
+
+        if attributes.pg_array {
+            let array_ty_name = array_type_name(ident, attributes.type_name.as_ref());
+
+            tts.extend(quote!(
+                #[automatically_derived]
+                impl ::sqlx::postgres::PgHasArrayType for #ident {
+                    fn array_type_info() -> ::sqlx::postgres::PgTypeInfo {
+                        ::sqlx::postgres::PgTypeInfo::with_name(#array_ty_name)
+                    }
+                }
+            ));
+        }
     }
 
     Ok(tts)
 }
 
+// Postgres names the array type of a user-defined type by prefixing it with an underscore,
+// which goes after the schema if the name is qualified with one
+fn array_type_name(ident: &Ident, explicit_name: Option<&TypeName>) -> String {
+    let name = match explicit_name {
+        Some(tn) => tn.val.clone(),
+        None => ident.to_string(),
+    };
+
+    match name.rsplit_once('.') {
+        Some((schema, name)) => format!("{}._{}", schema, name),
+        None => format!("_{}", name),
+    }
+}
+
 fn type_name(ident: &Ident, explicit_name: Option<&TypeName>) -> TokenStream {
     explicit_name.map(|tn| tn.get()).unwrap_or_else(|| {
         let s = ident.to_string();
@@ -1,6 +1,8 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
 use sqlx::pool::PoolOptions;
 use sqlx::{Connection, Database, Pool};
 use std::env;
+use std::sync::{Arc, Mutex, OnceLock};
 
 pub fn setup_if_needed() {
     let _ = dotenvy::dotenv();
@@ -36,6 +38,61 @@ where
     Ok(pool)
 }
 
+// The statements logged to `sqlx::query`, with their level
+pub type LogRecords = Arc<Mutex<Vec<(Level, String)>>>;
+
+struct CaptureLogger(LogRecords);
+
+impl Log for CaptureLogger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target() == "sqlx::query"
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if self.enabled(record.metadata()) {
+            let message = record.args().to_string();
+            self.0.lock().unwrap().push((record.level(), message));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+// Capture the statements logged in this test binary
+// This installs the global logger, so it must be called before [setup_if_needed] sets it to
+// [env_logger]; the logger can only be installed once, so its records are shared by the tests
+pub fn capture_logs() -> LogRecords {
+    static RECORDS: OnceLock<LogRecords> = OnceLock::new();
+
+    let records = RECORDS.get_or_init(|| {
+        let records = LogRecords::default();
+
+        log::set_logger(Box::leak(Box::new(CaptureLogger(Arc::clone(&records)))))
+            .expect("no other logger should be installed in this test binary");
+        log::set_max_level(LevelFilter::Trace);
+
+        records
+    });
+
+    Arc::clone(records)
+}
+
+// The statements ending transactions or establishing, restoring or releasing savepoints that
+// were logged after the first `start` records; as the tests of a binary run concurrently, this
+// is only reliable if the other tests of the binary do not run such statements
+pub fn transaction_statements_since(records: &LogRecords, start: usize) -> Vec<String> {
+    records.lock().unwrap()[start..]
+        .iter()
+        .filter_map(|(_, message)| message.split("; rows affected").next())
+        .filter(|sql| {
+            ["SAVEPOINT", "RELEASE", "ROLLBACK", "COMMIT"]
+                .iter()
+                .any(|k| sql.starts_with(k))
+        })
+        .map(str::to_owned)
+        .collect()
+}
+
 // Test type encoding and decoding
 #[macro_export]
 macro_rules! test_type {
@@ -87,6 +144,18 @@ macro_rules! test_prepared_type {
     };
 }
 
+// Test type decoding only for the prepared query API
+#[macro_export]
+macro_rules! test_prepared_decode_type {
+    ($name:ident<$ty:ty>($db:ident, $($text:literal == $value:expr),+ $(,)?)) => {
+        $crate::__test_prepared_decode_type!($name<$ty>($db, $($text == $value),+));
+    };
+
+    ($name:ident($db:ident, $($text:literal == $value:expr),+ $(,)?)) => {
+        $crate::__test_prepared_decode_type!($name<$name>($db, $($text == $value),+));
+    };
+}
+
 // Test type decoding for the simple (unprepared) query API
 #[macro_export]
 macro_rules! test_unprepared_type {
@@ -195,6 +264,209 @@ macro_rules! __test_prepared_type {
     };
 }
 
+// Test named savepoints, in the temporary table `$table` created by `$create_table`
+#[macro_export]
+macro_rules! test_named_savepoints {
+    ($db:ident, $table:literal, $create_table:literal) => {
+        #[sqlx_macros::test]
+        async fn it_can_roll_back_to_a_named_savepoint() -> anyhow::Result<()> {
+            use sqlx::{Connection, Executor};
+
+            let mut conn = sqlx_test::new::<$db>().await?;
+
+            conn.execute($create_table).await?;
+
+            let mut tx = conn.begin().await?;
+
+            sqlx::query(concat!("INSERT INTO ", $table, " (id) VALUES (1)"))
+                .execute(&mut tx)
+                .await?;
+
+            tx.savepoint("after_first").await?;
+
+            sqlx::query(concat!("INSERT INTO ", $table, " (id) VALUES (2)"))
+                .execute(&mut tx)
+                .await?;
+
+            tx.rollback_to("after_first").await?;
+
+            // the savepoint was destroyed by rolling back to it
+            let err = tx.release("after_first").await.unwrap_err();
+            assert!(matches!(err, sqlx::Error::Savepoint(_)), "{}", err);
+            assert!(err.to_string().contains("after_first"), "{}", err);
+
+            // the guard can be used to run statements and release the savepoint
+            let mut savepoint = tx.savepoint("third").await?;
+
+            sqlx::query(concat!("INSERT INTO ", $table, " (id) VALUES (3)"))
+                .execute(&mut *savepoint)
+                .await?;
+
+            savepoint.release().await?;
+
+            assert!(tx.rollback_to("third").await.is_err());
+            assert!(tx.savepoint("not a name").await.is_err());
+
+            // the case of a name is kept, and a name that only differs in case is taken
+            tx.savepoint("MixedCase").await?;
+            assert!(tx.savepoint("mixedcase").await.is_err());
+
+            sqlx::query(concat!("INSERT INTO ", $table, " (id) VALUES (4)"))
+                .execute(&mut tx)
+                .await?;
+
+            assert!(tx.rollback_to("mixedcase").await.is_err());
+            tx.rollback_to("MixedCase").await?;
+
+            tx.commit().await?;
+
+            let ids: Vec<i32> =
+                sqlx::query_scalar(concat!("SELECT id FROM ", $table, " ORDER BY id"))
+                    .fetch_all(&mut conn)
+                    .await?;
+
+            assert_eq!(ids, vec![1, 3]);
+
+            Ok(())
+        }
+    };
+}
+
+// Test that SQL `NULL` and JSON `null` are told apart by `Json<T>`, in a temporary table with
+// a column of type `$json_type`; `$insert` inserts an `id` and a `friend` into that table
+#[macro_export]
+macro_rules! test_json_nulls {
+    ($db:ident, $json_type:literal, $insert:literal) => {
+        #[sqlx_macros::test]
+        async fn test_json_null_and_sql_null() -> anyhow::Result<()> {
+            use sqlx::types::Json;
+            use sqlx::Executor;
+
+            #[derive(serde::Deserialize, serde::Serialize, Debug, PartialEq)]
+            struct Friend {
+                name: String,
+                age: u32,
+            }
+
+            let mut conn = sqlx_test::new::<$db>().await?;
+
+            conn.execute(concat!(
+                "CREATE TEMPORARY TABLE json_nulls (id INT PRIMARY KEY, friend ",
+                $json_type,
+                ")"
+            ))
+            .await?;
+
+            let friend = Friend {
+                name: "Joe".to_string(),
+                age: 33,
+            };
+
+            sqlx::query($insert)
+                .bind(1)
+                .bind(None::<Json<Friend>>)
+                .execute(&mut conn)
+                .await?;
+
+            sqlx::query($insert)
+                .bind(2)
+                .bind(Json(None::<Friend>))
+                .execute(&mut conn)
+                .await?;
+
+            sqlx::query($insert)
+                .bind(3)
+                .bind(Json(Some(&friend)))
+                .execute(&mut conn)
+                .await?;
+
+            let rows: Vec<(bool, Option<Json<Option<Friend>>>)> =
+                sqlx::query_as("SELECT friend IS NULL, friend FROM json_nulls ORDER BY id")
+                    .fetch_all(&mut conn)
+                    .await?;
+
+            assert_eq!(
+                rows,
+                vec![
+                    (true, None),
+                    (false, Some(Json(None))),
+                    (false, Some(Json(Some(friend)))),
+                ]
+            );
+
+            // JSON `null` is a value, so it can't be decoded as a `Friend`
+            let res = sqlx::query_scalar::<_, Option<Json<Friend>>>(
+                "SELECT friend FROM json_nulls WHERE id = 2",
+            )
+            .fetch_one(&mut conn)
+            .await;
+
+            assert!(
+                matches!(res, Err(sqlx::Error::ColumnDecode { .. })),
+                "{:?}",
+                res
+            );
+
+            Ok(())
+        }
+    };
+}
+
+// Test that the errors of constraints are mapped to their `ErrorKind`. `$setup` creates the
+// tables `error_kind_parents (id)` and `error_kind_children (id, parent_id, name NOT NULL)`,
+// which the cases shared by every database violate, and `$cases` are the other statements run
+// with the kind of their error. As some databases only enforce foreign keys on permanent
+// tables, every `error_kind_` table is named after the process, and `$teardown` drops them.
+#[macro_export]
+macro_rules! test_error_kinds {
+    ($db:ident, $setup:literal, [$(($sql:literal, $kind:ident)),* $(,)?] $(, $teardown:literal)?) => {
+        #[sqlx_macros::test]
+        async fn it_maps_errors_to_their_kind() -> anyhow::Result<()> {
+            use sqlx::error::ErrorKind;
+            use sqlx::Executor;
+
+            let mut conn = sqlx_test::new::<$db>().await?;
+
+            let prefix = format!("error_kind_{}_", std::process::id());
+            let unique = |sql: &str| sql.replace("error_kind_", &prefix);
+
+            conn.execute(&*unique($setup)).await?;
+
+            let cases = [
+                (
+                    "INSERT INTO error_kind_parents (id) VALUES (1), (1)",
+                    ErrorKind::UniqueViolation,
+                ),
+                (
+                    "INSERT INTO error_kind_children (id, parent_id, name) VALUES (1, 2, 'a')",
+                    ErrorKind::ForeignKeyViolation,
+                ),
+                (
+                    "INSERT INTO error_kind_children (id, name) VALUES (1, NULL)",
+                    ErrorKind::NotNullViolation,
+                ),
+                $(($sql, ErrorKind::$kind),)*
+            ];
+
+            let mut kinds = Vec::new();
+
+            for (sql, _) in cases {
+                let error = conn.execute(&*unique(sql)).await.unwrap_err();
+                kinds.push(error.as_database_error().map(|error| error.kind()));
+            }
+
+            // the tables are dropped before asserting so that they are not left behind
+            $(conn.execute(&*unique($teardown)).await?;)?
+
+            for ((sql, kind), actual) in cases.into_iter().zip(kinds) {
+                assert_eq!(actual, Some(kind), "{}", sql);
+            }
+
+            Ok(())
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! MySql_query_for_test_prepared_type {
     () => {
@@ -11,7 +11,7 @@ pub use sqlx_core::acquire::Acquire;
 pub use sqlx_core::arguments::{Arguments, IntoArguments};
 pub use sqlx_core::column::Column;
 pub use sqlx_core::column::ColumnIndex;
-pub use sqlx_core::connection::{ConnectOptions, Connection};
+pub use sqlx_core::connection::{ConnectOptions, Connection, LogSettings};
 pub use sqlx_core::database::{self, Database};
 pub use sqlx_core::describe::Describe;
 pub use sqlx_core::executor::{Execute, Executor};
@@ -23,7 +23,9 @@ pub use sqlx_core::query_builder::{self, QueryBuilder};
 pub use sqlx_core::query_scalar::{query_scalar, query_scalar_with};
 pub use sqlx_core::row::Row;
 pub use sqlx_core::statement::Statement;
-pub use sqlx_core::transaction::{Transaction, TransactionManager};
+pub use sqlx_core::transaction::{
+    IsolationLevel, Savepoint, Transaction, TransactionManager, TransactionOptions,
+};
 pub use sqlx_core::type_info::TypeInfo;
 pub use sqlx_core::types::Type;
 pub use sqlx_core::value::{Value, ValueRef};
@@ -83,6 +85,9 @@ pub use sqlx_core::testing;
 #[doc(hidden)]
 pub use sqlx_core::test_block_on;
 
+#[doc(hidden)]
+pub use sqlx_core::from_row::prefixed_column_name;
+
 #[cfg(feature = "macros")]
 mod macros;
 
@@ -706,6 +706,75 @@ macro_rules! query_file_scalar_unchecked (
     )
 );
 
+/// A query for the [`Any`][crate::Any] driver which is checked by the database at runtime instead
+/// of at compile time.
+///
+/// As the database behind an [`AnyPool`][crate::AnyPool] or [`AnyConnection`][crate::AnyConnection]
+/// is only known once the application is running, there is no database to check the query against
+/// during compilation, and no single placeholder syntax that all of them accept. Instead, this
+/// macro takes the [`AnyKind`][crate::any::AnyKind] of the database the query is going to run on
+/// and a query written with `?` placeholders, which are rewritten into `$1, $2, ...` for PostgreSQL
+/// and `@p1, @p2, ...` for MSSQL (see [`translate_placeholders`][crate::any::translate_placeholders]).
+/// The rewritten query is computed once per call site and database.
+///
+/// The arguments are bound in order and the macro evaluates to a plain [`Query`][crate::query::Query],
+/// so columns are accessed by name (or index) on the returned [`AnyRow`][crate::any::AnyRow]s:
+///
+/// ```rust,ignore
+/// use sqlx::Row;
+///
+/// let row = sqlx::any_query!(pool.any_kind(), "SELECT id, name FROM users WHERE id = ?", 1i32)
+///     .fetch_one(&pool)
+///     .await?;
+///
+/// let name: String = row.try_get("name")?;
+/// ```
+///
+/// As with [`query!`], the columns can instead be decoded into the fields of an anonymous record,
+/// by listing the name and type of each one after a `;`. Each field is decoded from the column of
+/// the same name, and the macro then evaluates to a [`Map`][crate::query::Map] over the records:
+///
+/// ```rust,ignore
+/// let user = sqlx::any_query!(
+///     pool.any_kind(),
+///     "SELECT id, name FROM users WHERE id = ?",
+///     1i32;
+///     id: i32,
+///     name: String,
+/// )
+/// .fetch_one(&pool)
+/// .await?;
+///
+/// println!("{}: {}", user.id, user.name);
+/// ```
+///
+/// The query must be a string literal.
+#[cfg(feature = "any")]
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "macros", feature = "any"))))]
+macro_rules! any_query (
+    ($kind:expr, $query:literal $(, $args:expr)* $(,)? ; $($field:ident : $type:ty),+ $(,)?) => ({
+        #[derive(Debug)]
+        struct Record {
+            $($field: $type,)+
+        }
+
+        $crate::any_query!($kind, $query $(, $args)*).try_map(|row: $crate::any::AnyRow| {
+            use $crate::Row as _;
+
+            Ok(Record {
+                $($field: row.try_get(stringify!($field))?,)+
+            })
+        })
+    });
+    ($kind:expr, $query:literal $(, $args:expr)* $(,)?) => ({
+        static QUERY: $crate::any::AnyQueryCache = $crate::any::AnyQueryCache::new($query);
+
+        $crate::query::<$crate::Any>(QUERY.sql($kind))
+            $(.bind($args))*
+    })
+);
+
 /// Embeds migrations into the binary by expanding to a static instance of [Migrator][crate::migrate::Migrator].
 ///
 /// ```rust,ignore
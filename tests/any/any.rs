@@ -91,6 +91,22 @@ async fn it_has_json() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_has_text() -> anyhow::Result<()> {
+    use sqlx::types::Text;
+    use url::Url;
+
+    assert_eq!(
+        Text(Url::parse("https://example.com/path?q=1")?),
+        get_val::<Text<Url>>("'https://example.com/path?q=1'").await?
+    );
+
+    let error = get_val::<Text<Url>>("'not a url'").await.unwrap_err();
+    assert!(error.to_string().contains("url::Url"), "{}", error);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_pings() -> anyhow::Result<()> {
     let mut conn = new::<Any>().await?;
@@ -199,3 +215,248 @@ async fn it_can_fail_and_recover_with_pool() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg(all(feature = "postgres", feature = "mysql"))]
+#[sqlx_macros::test]
+async fn it_exposes_the_driver_connection() -> anyhow::Result<()> {
+    use sqlx::any::AnyKind;
+
+    let mut conn = new::<Any>().await?;
+
+    match conn.kind() {
+        AnyKind::Postgres => {
+            assert!(conn.try_pg().is_some());
+            assert!(conn.try_mysql().is_none());
+        }
+
+        AnyKind::MySql => {
+            assert!(conn.try_mysql().is_some());
+            assert!(conn.try_pg().is_none());
+        }
+
+        #[allow(unreachable_patterns)]
+        _ => {
+            assert!(conn.try_pg().is_none());
+            assert!(conn.try_mysql().is_none());
+        }
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reports_rows_affected_per_statement() -> anyhow::Result<()> {
+    use futures::TryStreamExt;
+
+    let mut conn = new::<Any>().await?;
+
+    #[allow(unreachable_patterns)]
+    let sql = match conn.kind() {
+        #[cfg(feature = "mssql")]
+        sqlx::any::AnyKind::Mssql => {
+            "CREATE TABLE #t (id INT); INSERT INTO #t VALUES (1), (2); DROP TABLE #t"
+        }
+
+        _ => "CREATE TEMPORARY TABLE t (id INT); INSERT INTO t VALUES (1), (2); DROP TABLE t",
+    };
+
+    let affected: Vec<u64> = conn
+        .execute_many(sql)
+        .map_ok(|res| res.rows_affected())
+        .try_collect()
+        .await?;
+
+    assert_eq!(affected, vec![0, 2, 0]);
+
+    Ok(())
+}
+
+#[cfg(feature = "postgres")]
+#[sqlx_macros::test]
+async fn it_passes_query_parameters_to_the_driver() -> anyhow::Result<()> {
+    use sqlx::any::{AnyConnectOptions, AnyConnection, AnyKind};
+
+    let url = dotenvy::var("DATABASE_URL")?;
+    let options: AnyConnectOptions = url.parse()?;
+
+    if options.kind() != AnyKind::Postgres {
+        return Ok(());
+    }
+
+    let separator = if url.contains('?') { '&' } else { '?' };
+    let url = format!("{}{}application_name=sqlx_any_test", url, separator);
+
+    let mut conn = AnyConnection::connect(&url).await?;
+
+    let application_name: String = sqlx::query_scalar(
+        "SELECT application_name::text FROM pg_stat_activity WHERE pid = pg_backend_pid()",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(application_name, "sqlx_any_test");
+
+    let err = format!("{}&definitely_not_a_parameter=1", url)
+        .parse::<AnyConnectOptions>()
+        .unwrap_err();
+
+    assert!(err.to_string().contains("`definitely_not_a_parameter`"));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_exposes_column_metadata() -> anyhow::Result<()> {
+    use sqlx::{Column, TypeInfo};
+
+    let mut conn = new::<Any>().await?;
+
+    let row = conn.fetch_one("SELECT 1 AS a, 'x' AS b").await?;
+    let columns = row.columns();
+
+    assert_eq!(columns.len(), 2);
+    assert_eq!(columns[0].name(), "a");
+    assert_eq!(columns[0].ordinal(), 0);
+    assert_eq!(columns[1].name(), "b");
+    assert_eq!(columns[1].ordinal(), 1);
+
+    let a = columns[0].type_info().name();
+    let b = columns[1].type_info().name();
+
+    // SQLite has no declared type for an expression so it reports `NULL`
+    assert!(
+        ["INT4", "BIGINT", "INT", "NULL"].contains(&a),
+        "unexpected type for `a`: {}",
+        a
+    );
+    assert!(
+        ["TEXT", "VARCHAR", "NULL"].contains(&b),
+        "unexpected type for `b`: {}",
+        b
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fetches_a_scalar() -> anyhow::Result<()> {
+    let mut conn = new::<Any>().await?;
+
+    let n = sqlx::query_scalar::<_, i64>("SELECT CAST(42 AS BIGINT)")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(n, 42);
+
+    let s: String = sqlx::query_scalar("SELECT 'hello'")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(s, "hello");
+
+    Ok(())
+}
+
+#[cfg(feature = "macros")]
+#[sqlx_macros::test]
+async fn it_translates_placeholders_in_any_query() -> anyhow::Result<()> {
+    let mut conn = new::<Any>().await?;
+
+    let row = sqlx::any_query!(
+        conn.kind(),
+        "SELECT ? AS id, ? AS name, '?' AS literal",
+        7_i64,
+        "seven"
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(row.try_get::<i64, _>("id")?, 7);
+    assert_eq!(row.try_get::<String, _>("name")?, "seven");
+    assert_eq!(row.try_get::<String, _>("literal")?, "?");
+
+    Ok(())
+}
+
+#[cfg(feature = "macros")]
+#[sqlx_macros::test]
+async fn it_decodes_any_query_into_named_fields() -> anyhow::Result<()> {
+    let mut conn = new::<Any>().await?;
+
+    let record = sqlx::any_query!(
+        conn.kind(),
+        "SELECT ? AS id, ? AS name",
+        7_i64,
+        "seven";
+        id: i64,
+        name: String,
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(record.id, 7);
+    assert_eq!(record.name, "seven");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_nests_transactions_with_savepoints() -> anyhow::Result<()> {
+    let mut conn = new::<Any>().await?;
+
+    #[allow(unreachable_patterns)]
+    let table = match conn.kind() {
+        #[cfg(feature = "mssql")]
+        sqlx::any::AnyKind::Mssql => {
+            conn.execute("CREATE TABLE #_sqlx_savepoints (id INT)")
+                .await?;
+
+            "#_sqlx_savepoints"
+        }
+
+        _ => {
+            conn.execute("CREATE TEMPORARY TABLE _sqlx_savepoints (id INT)")
+                .await?;
+
+            "_sqlx_savepoints"
+        }
+    };
+
+    let insert = |id: i32| format!("INSERT INTO {} (id) VALUES ({})", table, id);
+    let select = format!("SELECT id FROM {} ORDER BY id", table);
+
+    let mut tx = conn.begin().await?;
+    tx.execute(&*insert(1)).await?;
+
+    // a savepoint that is rolled back leaves the outer transaction alone
+    let mut savepoint = tx.begin().await?;
+    savepoint.execute(&*insert(2)).await?;
+    savepoint.rollback().await?;
+
+    let ids: Vec<i32> = sqlx::query_scalar(&select).fetch_all(&mut tx).await?;
+    assert_eq!(ids, [1]);
+
+    let mut savepoint = tx.begin().await?;
+    savepoint.execute(&*insert(3)).await?;
+    savepoint.commit().await?;
+
+    tx.commit().await?;
+
+    let ids: Vec<i32> = sqlx::query_scalar(&select).fetch_all(&mut conn).await?;
+    assert_eq!(ids, [1, 3]);
+
+    // rolling back the outer transaction also discards the savepoints committed within it
+    let mut tx = conn.begin().await?;
+    tx.execute(&*insert(4)).await?;
+
+    let mut savepoint = tx.begin().await?;
+    savepoint.execute(&*insert(5)).await?;
+    savepoint.commit().await?;
+
+    tx.rollback().await?;
+
+    let ids: Vec<i32> = sqlx::query_scalar(&select).fetch_all(&mut conn).await?;
+    assert_eq!(ids, [1, 3]);
+
+    Ok(())
+}
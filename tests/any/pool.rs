@@ -238,3 +238,277 @@ async fn test_pool_callbacks() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn test_pool_acquire_timeout_override() -> anyhow::Result<()> {
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .acquire_timeout(Duration::from_secs(30))
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let conn = pool.acquire().await?;
+
+    // a zero timeout should try once and give up instead of waiting for the held connection
+    let start = std::time::Instant::now();
+    let res = pool.acquire_timeout(Duration::ZERO).await;
+    assert!(matches!(res, Err(sqlx::Error::PoolTimedOut)), "{:?}", res);
+    assert!(start.elapsed() < Duration::from_secs(1));
+
+    // a short timeout should elapse without consulting the configured 30 seconds
+    let start = std::time::Instant::now();
+    let res = pool.acquire_timeout(Duration::from_millis(100)).await;
+    assert!(matches!(res, Err(sqlx::Error::PoolTimedOut)), "{:?}", res);
+    assert!(start.elapsed() < Duration::from_secs(5));
+
+    drop(conn);
+
+    // once the connection is released, the override should succeed
+    let mut conn = pool.acquire_timeout(Duration::from_secs(5)).await?;
+    conn.execute("SELECT 1").await?;
+    drop(conn);
+
+    pool.close().await;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_pool_close_timeout() -> anyhow::Result<()> {
+    let pool = AnyPoolOptions::new()
+        .max_connections(2)
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let mut held = pool.acquire().await?;
+
+    // `close()` would wait for `held` forever
+    let start = std::time::Instant::now();
+    sqlx_rt::timeout(
+        Duration::from_secs(5),
+        pool.close_timeout(Duration::from_millis(100)),
+    )
+    .await?;
+
+    assert!(start.elapsed() < Duration::from_secs(2));
+    assert!(pool.is_closed());
+    assert!(matches!(pool.acquire().await, Err(sqlx::Error::PoolClosed)));
+
+    // the detached connection remains usable by its owner...
+    held.execute("SELECT 1").await?;
+
+    // ...but is closed instead of being returned to the pool when released
+    drop(held);
+
+    for _ in 0..50 {
+        if pool.size() == 0 {
+            break;
+        }
+
+        sqlx_rt::sleep(Duration::from_millis(10)).await;
+    }
+
+    assert_eq!(pool.size(), 0);
+    assert_eq!(pool.num_idle(), 0);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_pool_min_connections() -> anyhow::Result<()> {
+    let pool = AnyPoolOptions::new()
+        .min_connections(2)
+        .max_connections(4)
+        .idle_timeout(Duration::from_secs(60))
+        .max_lifetime(Duration::from_secs(600))
+        .test_before_acquire(false)
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    // the connections are opened before `connect()` returns, though one of them may
+    // have been left to the reaper, which is also maintaining `min_connections`
+    assert_eq!(pool.size(), 2);
+
+    for _ in 0..50 {
+        if pool.num_idle() == 2 {
+            break;
+        }
+
+        sqlx_rt::sleep(Duration::from_millis(20)).await;
+    }
+
+    assert_eq!(pool.num_idle(), 2);
+
+    pool.close().await;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_pool_max_lifetime_shorter_than_idle_timeout() -> anyhow::Result<()> {
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(Duration::from_secs(60))
+        .max_lifetime(Duration::from_millis(100))
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+    conn.execute("SELECT 1").await?;
+    drop(conn);
+
+    // the reaper runs at the shorter of the two periods, so the idle connection
+    // is closed for exceeding `max_lifetime` long before `idle_timeout`
+    for _ in 0..50 {
+        if pool.size() == 0 {
+            break;
+        }
+
+        sqlx_rt::sleep(Duration::from_millis(20)).await;
+    }
+
+    assert_eq!(pool.size(), 0);
+
+    pool.close().await;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_pool_max_lifetime_jitter() -> anyhow::Result<()> {
+    let lifetimes = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    let pool = AnyPoolOptions::new()
+        .max_connections(8)
+        .max_lifetime(Duration::from_secs(60))
+        .max_lifetime_jitter(Duration::from_secs(6))
+        .after_connect({
+            let lifetimes = lifetimes.clone();
+            move |_conn, meta| {
+                lifetimes.lock().unwrap().push(meta.max_lifetime);
+                Box::pin(async { Ok(()) })
+            }
+        })
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    // hold on to every connection so that each one is newly opened
+    let mut conns = Vec::new();
+
+    for _ in 0..8 {
+        conns.push(pool.acquire().await?);
+    }
+
+    let lifetimes = lifetimes.lock().unwrap().clone();
+    assert_eq!(lifetimes.len(), 8);
+
+    for lifetime in &lifetimes {
+        let lifetime = lifetime.expect("every connection has a lifetime");
+        assert!(lifetime >= Duration::from_secs(54), "{:?}", lifetime);
+        assert!(lifetime <= Duration::from_secs(66), "{:?}", lifetime);
+    }
+
+    // the lifetimes are spread out instead of all being the same
+    assert!(
+        lifetimes.iter().any(|lifetime| *lifetime != lifetimes[0]),
+        "{:?}",
+        lifetimes
+    );
+
+    drop(conns);
+    pool.close().await;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_pool_status() -> anyhow::Result<()> {
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    assert_eq!(pool.max_connections(), 1);
+
+    let conn = pool.acquire().await?;
+
+    let status = pool.status();
+    assert_eq!(status.size, 1);
+    assert_eq!(status.idle, 0);
+    assert_eq!(status.in_use, 1);
+    assert_eq!(status.waiting, 0);
+
+    let (tx, rx) = futures::channel::oneshot::channel();
+
+    sqlx_rt::spawn({
+        let pool = pool.clone();
+
+        async move {
+            let mut conn = pool.acquire().await.unwrap();
+            conn.execute("SELECT 1").await.unwrap();
+            let _ = tx.send(());
+        }
+    });
+
+    for _ in 0..50 {
+        if pool.status().waiting == 1 {
+            break;
+        }
+
+        sqlx_rt::sleep(Duration::from_millis(10)).await;
+    }
+
+    assert_eq!(pool.status().waiting, 1);
+
+    // the waiter gets the connection once it is released
+    drop(conn);
+    sqlx_rt::timeout(Duration::from_secs(5), rx).await??;
+
+    assert_eq!(pool.status().waiting, 0);
+
+    pool.close().await;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_pool_try_acquire_or_connect() -> anyhow::Result<()> {
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .connect_lazy(&dotenvy::var("DATABASE_URL")?)?;
+
+    // `try_acquire` only hands out idle connections, of which a lazy pool has none
+    assert!(pool.try_acquire().is_none());
+
+    let conn = pool
+        .try_acquire_or_connect()
+        .await?
+        .expect("the pool has room for a new connection");
+
+    // the only connection is checked out
+    assert!(pool.try_acquire().is_none());
+    assert!(pool.try_acquire_or_connect().await?.is_none());
+
+    drop(conn);
+
+    // connections are released asynchronously
+    for _ in 0..50 {
+        if pool.num_idle() == 1 {
+            break;
+        }
+
+        sqlx_rt::sleep(Duration::from_millis(10)).await;
+    }
+
+    let mut conn = pool
+        .try_acquire_or_connect()
+        .await?
+        .expect("the connection was returned to the pool");
+
+    conn.execute("SELECT 1").await?;
+
+    drop(conn);
+    pool.close().await;
+
+    Ok(())
+}
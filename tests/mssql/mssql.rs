@@ -17,6 +17,24 @@ async fn it_connects() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_reports_the_server_version() -> anyhow::Result<()> {
+    let mut conn = new::<Mssql>().await?;
+
+    let expected: i32 =
+        sqlx::query_scalar("SELECT CAST(SERVERPROPERTY('ProductMajorVersion') AS INT)")
+            .fetch_one(&mut conn)
+            .await?;
+
+    let (major, _, build) = conn.server_version();
+
+    assert!(major >= 11, "{}", major);
+    assert!(build > 0);
+    assert_eq!(i32::from(major), expected);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_select_expression() -> anyhow::Result<()> {
     let mut conn = new::<Mssql>().await?;
@@ -309,6 +327,60 @@ async fn it_can_work_with_nested_transactions() -> anyhow::Result<()> {
     Ok(())
 }
 
+sqlx_test::test_named_savepoints!(
+    Mssql,
+    "#savepoints",
+    "CREATE TABLE #savepoints (id INTEGER PRIMARY KEY)"
+);
+
+#[sqlx_macros::test]
+async fn it_returns_output_rows_with_affected_rows() -> anyhow::Result<()> {
+    use sqlx::Either;
+
+    let mut conn = new::<Mssql>().await?;
+
+    conn.execute("CREATE TABLE #output (id INT IDENTITY(1, 1) PRIMARY KEY, name NVARCHAR(50))")
+        .await?;
+
+    let (id, name): (i32, String) =
+        sqlx::query_as("INSERT INTO #output (name) OUTPUT INSERTED.id, INSERTED.name VALUES (@p1)")
+            .bind("first")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(id, 1);
+    assert_eq!(name, "first");
+
+    // the rows of the OUTPUT clause and of the trailing SELECT are each followed by the result
+    // of their statement
+    let results: Vec<Either<u64, i32>> = conn
+        .fetch_many(
+            sqlx::query(
+                "UPDATE #output SET name = @p1 OUTPUT DELETED.id WHERE id = 1; \
+                 SELECT COUNT(*) FROM #output",
+            )
+            .bind("renamed"),
+        )
+        .map_ok(|step| match step {
+            Either::Left(result) => Either::Left(result.rows_affected()),
+            Either::Right(row) => Either::Right(row.get(0)),
+        })
+        .try_collect()
+        .await?;
+
+    assert_eq!(
+        results,
+        vec![
+            Either::Right(1),
+            Either::Left(1),
+            Either::Right(1),
+            Either::Left(1)
+        ]
+    );
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
     let mut conn = new::<Mssql>().await?;
@@ -340,6 +412,71 @@ async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_counts_the_parameters_of_a_statement() -> anyhow::Result<()> {
+    use sqlx::Either;
+
+    let mut conn = new::<Mssql>().await?;
+
+    // a parameter used twice is declared and counted once
+    let statement = conn.prepare("SELECT @p1 AS a, @p2 AS b, @p1 AS c").await?;
+
+    assert!(matches!(statement.parameters(), Some(Either::Right(2))));
+    assert_eq!(statement.columns().len(), 3);
+
+    let row = statement
+        .query()
+        .bind(1_i32)
+        .bind(2_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.try_get::<i32, _>("c")?, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_binds_table_valued_parameters() -> anyhow::Result<()> {
+    use sqlx::mssql::types::MssqlTableValue;
+
+    let mut conn = new::<Mssql>().await?;
+
+    let rows = vec![(Some(10_i32), "a"), (None, "b"), (Some(32_i32), "c")];
+    let tvp = MssqlTableValue::new("dbo.sqlx_value_list").rows(rows)?;
+
+    let row = sqlx::query("EXEC dbo.sqlx_sum_value_list @p1")
+        .bind(tvp)
+        .fetch_one(&mut conn)
+        .await?;
+
+    let total: i64 = row.try_get("total")?;
+    let row_count: i32 = row.try_get("row_count")?;
+    let non_null: i32 = row.try_get("non_null")?;
+    let last_label: String = row.try_get("last_label")?;
+
+    assert_eq!(total, 42);
+    assert_eq!(row_count, 3);
+    assert_eq!(non_null, 2);
+    assert_eq!(last_label, "c");
+
+    // an empty table is still a valid argument
+    let tvp = MssqlTableValue::new("dbo.sqlx_value_list").rows(Vec::<(Option<i32>, &str)>::new())?;
+
+    let row = sqlx::query("EXEC dbo.sqlx_sum_value_list @p1")
+        .bind(tvp)
+        .fetch_one(&mut conn)
+        .await?;
+
+    let total: Option<i64> = row.try_get("total")?;
+    let row_count: i32 = row.try_get("row_count")?;
+
+    assert_eq!(total, None);
+    assert_eq!(row_count, 0);
+
+    Ok(())
+}
+
 // MSSQL-specific copy of the test case in `tests/any/pool.rs`
 // because MSSQL has its own bespoke syntax for temporary tables.
 #[sqlx_macros::test]
@@ -471,3 +608,65 @@ async fn test_pool_callbacks() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+// foreign keys are not enforced on temporary tables
+sqlx_test::test_error_kinds!(
+    Mssql,
+    r#"
+DROP TABLE IF EXISTS error_kind_children, error_kind_parents;
+CREATE TABLE error_kind_parents (id INT PRIMARY KEY);
+CREATE TABLE error_kind_children (
+    id INT PRIMARY KEY,
+    parent_id INT REFERENCES error_kind_parents (id),
+    name NVARCHAR(10) NOT NULL,
+    age INT CHECK (age >= 0)
+);
+INSERT INTO error_kind_parents (id) VALUES (3);
+INSERT INTO error_kind_children (id, parent_id, name) VALUES (3, 3, 'c');
+    "#,
+    [
+        (
+            "DELETE FROM error_kind_parents WHERE id = 3",
+            ForeignKeyViolation
+        ),
+        (
+            "INSERT INTO error_kind_children (id, name, age) VALUES (1, 'a', -1)",
+            CheckViolation
+        ),
+        ("SELECT 1 / 0", Other),
+    ],
+    "DROP TABLE error_kind_children, error_kind_parents"
+);
+
+#[sqlx_macros::test]
+async fn it_restores_the_isolation_level_after_a_transaction() -> anyhow::Result<()> {
+    use sqlx::{IsolationLevel, TransactionOptions};
+
+    let mut conn = new::<Mssql>().await?;
+
+    let level_sql =
+        "SELECT transaction_isolation_level FROM sys.dm_exec_sessions WHERE session_id = @@SPID";
+
+    // the level of a session outlives its transactions
+    conn.execute("SET TRANSACTION ISOLATION LEVEL SERIALIZABLE")
+        .await?;
+
+    let options = TransactionOptions::new().isolation_level(IsolationLevel::ReadUncommitted);
+
+    let mut tx = conn.begin_with_options(options).await?;
+    let level: i16 = sqlx::query_scalar(level_sql).fetch_one(&mut tx).await?;
+    assert_eq!(level, 1);
+    tx.commit().await?;
+
+    let level: i16 = sqlx::query_scalar(level_sql).fetch_one(&mut conn).await?;
+    assert_eq!(level, 4);
+
+    let mut tx = conn.begin_with_options(options).await?;
+    tx.execute("SELECT 1").await?;
+    tx.rollback().await?;
+
+    let level: i16 = sqlx::query_scalar(level_sql).fetch_one(&mut conn).await?;
+    assert_eq!(level, 4);
+
+    Ok(())
+}
@@ -18,3 +18,26 @@ IF OBJECT_ID('tweet') IS NULL
         );
     END;
 GO
+
+IF TYPE_ID('sqlx_value_list') IS NULL
+    BEGIN
+        CREATE TYPE sqlx_value_list AS TABLE
+        (
+            value INT           NULL,
+            label NVARCHAR(100) NOT NULL
+        );
+    END;
+GO
+
+IF OBJECT_ID('sqlx_sum_value_list') IS NULL
+    BEGIN
+        EXEC ('
+            CREATE PROCEDURE sqlx_sum_value_list @values sqlx_value_list READONLY AS
+            SELECT CAST(SUM(value) AS BIGINT) AS total,
+                   COUNT(*)                   AS row_count,
+                   COUNT(value)               AS non_null,
+                   MAX(label)                 AS last_label
+            FROM @values;
+        ');
+    END;
+GO
@@ -1,3 +1,5 @@
+extern crate time_ as time;
+
 use sqlx::mssql::Mssql;
 use sqlx_test::test_type;
 
@@ -146,6 +148,56 @@ mod chrono {
     ));
 }
 
+#[cfg(feature = "time")]
+mod time_tests {
+    use super::*;
+    use sqlx::types::time::OffsetDateTime;
+    use sqlx::Connection;
+    use time::macros::{datetime, offset};
+
+    test_type!(time_datetimeoffset<OffsetDateTime>(
+        Mssql,
+        "CAST('2016-10-23 12:45:37.1234567 +05:30' AS DATETIMEOFFSET(7))"
+            == datetime!(2016-10-23 12:45:37.123_456_7 +05:30),
+        "CAST('2016-10-23 12:45:37.1234567 -08:00' AS DATETIMEOFFSET(7))"
+            == datetime!(2016-10-23 12:45:37.123_456_7 -08:00),
+        "CAST('0001-01-01 00:00:00 +00:00' AS DATETIMEOFFSET(7))"
+            == datetime!(0001-01-01 00:00:00 UTC),
+        "CAST('9999-12-31 23:59:59.9999999 +14:00' AS DATETIMEOFFSET(7))"
+            == datetime!(9999-12-31 23:59:59.999_999_9 +14:00),
+    ));
+
+    #[sqlx_macros::test]
+    async fn it_preserves_the_offset_of_a_datetimeoffset() -> anyhow::Result<()> {
+        let mut conn = sqlx_test::new::<Mssql>().await?;
+
+        let value = datetime!(2016-10-23 12:45:37.123_456_7 +05:30);
+
+        let (round_tripped, text): (OffsetDateTime, String) =
+            sqlx::query_as("SELECT @p1, CONVERT(NVARCHAR(40), @p1, 127)")
+                .bind(value)
+                .fetch_one(&mut conn)
+                .await?;
+
+        // `==` compares the instants, ignoring the offsets
+        assert_eq!(round_tripped, value);
+        assert_eq!(round_tripped.offset(), offset!(+05:30));
+        assert_eq!(text, "2016-10-23T12:45:37.1234567+05:30");
+
+        let decoded: OffsetDateTime =
+            sqlx::query_scalar("SELECT CAST('2016-10-23 00:15:00 +05:30' AS DATETIMEOFFSET(2))")
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert_eq!(decoded, datetime!(2016-10-23 00:15:00 +05:30));
+        assert_eq!(decoded.offset(), offset!(+05:30));
+
+        conn.close().await?;
+
+        Ok(())
+    }
+}
+
 #[cfg(feature = "decimal")]
 mod decimal {
     use super::*;
@@ -185,6 +237,35 @@ mod bigdecimal {
     ));
 }
 
+#[cfg(feature = "uuid")]
+mod uuid {
+    use super::*;
+    use sqlx::types::Uuid;
+
+    test_type!(Uuid(
+        Mssql,
+        "CAST('6F9619FF-8B86-D011-B42D-00C04FC964FF' AS UNIQUEIDENTIFIER)"
+            == Uuid::parse_str("6f9619ff-8b86-d011-b42d-00c04fc964ff").unwrap(),
+        "CAST('00000000-0000-0000-0000-000000000000' AS UNIQUEIDENTIFIER)" == Uuid::nil(),
+    ));
+
+    #[sqlx_macros::test]
+    async fn it_binds_uuids_in_the_order_they_are_written() -> anyhow::Result<()> {
+        let mut conn = sqlx_test::new::<Mssql>().await?;
+
+        let uuid = Uuid::parse_str("00112233-4455-6677-8899-aabbccddeeff")?;
+
+        let text: String = sqlx::query_scalar("SELECT CONVERT(NVARCHAR(36), @p1)")
+            .bind(uuid)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(text, "00112233-4455-6677-8899-AABBCCDDEEFF");
+
+        Ok(())
+    }
+}
+
 #[cfg(feature = "json")]
 mod json {
     use super::*;
@@ -0,0 +1,40 @@
+// This lives in its own test binary as it installs the global logger,
+// which `sqlx_test` would otherwise set to `env_logger`.
+
+use sqlx::mysql::MySql;
+use sqlx::{Connection, Executor};
+use sqlx_test::{capture_logs, new, transaction_statements_since};
+
+#[sqlx_macros::test]
+async fn it_releases_nested_savepoints_in_a_single_statement() -> anyhow::Result<()> {
+    let records = capture_logs();
+
+    let mut conn = new::<MySql>().await?;
+
+    let mut tx = conn.begin().await?;
+    let mut tx1 = tx.begin().await?;
+    let mut tx2 = tx1.begin().await?;
+    let mut tx3 = tx2.begin().await?;
+    let mut tx4 = tx3.begin().await?;
+    let mut tx5 = tx4.begin().await?;
+
+    tx5.execute("SELECT 1 AS nested_savepoints").await?;
+
+    let start = records.lock().unwrap().len();
+
+    tx5.commit_with_savepoint_release().await?;
+    tx4.commit_with_savepoint_release().await?;
+    tx3.commit_with_savepoint_release().await?;
+    tx2.commit_with_savepoint_release().await?;
+
+    // releasing the outermost savepoint releases the four committed inside of it
+    tx1.commit().await?;
+    tx.commit().await?;
+
+    assert_eq!(
+        transaction_statements_since(&records, start),
+        ["RELEASE SAVEPOINT _sqlx_savepoint_1", "COMMIT"]
+    );
+
+    Ok(())
+}
@@ -14,6 +14,58 @@ async fn it_connects() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_checks_the_number_of_bound_parameters() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let res = sqlx::query("SELECT ? + ?")
+        .bind(1_i32)
+        .execute(&mut conn)
+        .await;
+
+    match res {
+        Err(sqlx::Error::Protocol(message)) => {
+            assert_eq!(message, "the query has 2 parameters but 1 was bound")
+        }
+        res => panic!("expected a protocol error, got {:?}", res),
+    }
+
+    // each `?` must be bound, even for the same value
+    let sum: i64 = sqlx::query_scalar("SELECT CAST(? AS SIGNED) + CAST(? AS SIGNED)")
+        .bind(2_i32)
+        .bind(2_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(sum, 4);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reports_the_server_version() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let expected: String = sqlx::query_scalar("SELECT VERSION()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let (major, minor, patch) = conn.server_version();
+
+    assert!(major >= 5, "{}", expected);
+    assert!(
+        expected.starts_with(&format!("{}.{}.{}", major, minor, patch)),
+        "{} is not {}.{}.{}",
+        expected,
+        major,
+        minor,
+        patch
+    );
+    assert_eq!(conn.is_mariadb(), expected.contains("MariaDB"));
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_maths() -> anyhow::Result<()> {
     let mut conn = new::<MySql>().await?;
@@ -118,6 +170,46 @@ async fn it_works_with_cache_disabled() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_times_out_statements() -> anyhow::Result<()> {
+    use sqlx::mysql::{MySqlConnectOptions, MySqlDatabaseError};
+    use std::time::Duration;
+
+    setup_if_needed();
+
+    let options: MySqlConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    let mut conn =
+        MySqlConnection::connect_with(&options.statement_timeout(Duration::from_secs(1))).await?;
+
+    conn.execute("CREATE TEMPORARY TABLE timeout_rows (id INT)")
+        .await?;
+    conn.execute("INSERT INTO timeout_rows (id) VALUES (1), (2)")
+        .await?;
+
+    // an interrupted `SLEEP()` returns early without an error; the server only reports the
+    // timeout when it notices the interruption while reading the rows of a table
+    let err = conn
+        .execute("SELECT SLEEP(10) FROM timeout_rows")
+        .await
+        .unwrap_err();
+
+    let err: Box<MySqlDatabaseError> = err.into_database_error().unwrap().downcast();
+
+    // ER_QUERY_TIMEOUT on MySQL, ER_STATEMENT_TIMEOUT on MariaDB
+    assert!(
+        matches!(err.number(), 3024 | 1969),
+        "unexpected error: {}",
+        err
+    );
+
+    // the connection is safe for reuse
+    let val: i64 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+
+    assert_eq!(val, 1);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_drops_results_in_affected_rows() -> anyhow::Result<()> {
     let mut conn = new::<MySql>().await?;
@@ -239,6 +331,32 @@ async fn it_caches_statements() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_reuses_a_cached_statement_after_an_error() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    // the subquery returns more than one row, and so fails, unless `x >= 2`
+    let sql = "SELECT (SELECT x FROM (SELECT 1 AS x UNION ALL SELECT 2) t WHERE x >= ?) AS x \
+               FROM (SELECT 1 UNION ALL SELECT 2) u";
+
+    let res = sqlx::query(sql).bind(1_i32).fetch_all(&mut conn).await;
+
+    assert!(matches!(res, Err(sqlx::Error::Database(_))), "{:?}", res);
+
+    let xs: Vec<i64> = sqlx::query_scalar(sql)
+        .bind(2_i32)
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(xs, [2, 2]);
+    assert_eq!(1, conn.cached_statements_size());
+
+    conn.clear_statement_cache().await?;
+    assert_eq!(0, conn.cached_statements_size());
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_bind_null_and_non_null_issue_540() -> anyhow::Result<()> {
     let mut conn = new::<MySql>().await?;
@@ -393,6 +511,39 @@ async fn test_issue_622() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_returns_the_raw_bytes_of_a_value() -> anyhow::Result<()> {
+    use sqlx::mysql::MySqlValueFormat;
+    use sqlx::ValueRef;
+
+    let mut conn = new::<MySql>().await?;
+
+    // prepared queries receive values in the binary format
+    let row = sqlx::query("SELECT CAST(1234567 AS SIGNED), NULL")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let raw = row.try_get_raw(0)?;
+
+    assert!(!raw.is_null());
+    assert_eq!(raw.format(), MySqlValueFormat::Binary);
+    assert_eq!(
+        i64::from_le_bytes(raw.as_bytes().unwrap().try_into()?),
+        1234567
+    );
+
+    assert!(row.try_get_raw(1)?.is_null());
+
+    // simple queries receive them in the text format
+    let row = conn.fetch_one("SELECT CAST(1234567 AS SIGNED)").await?;
+    let raw = row.try_get_raw(0)?;
+
+    assert_eq!(raw.format(), MySqlValueFormat::Text);
+    assert_eq!(raw.as_str().unwrap(), "1234567");
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_work_with_transactions() -> anyhow::Result<()> {
     let mut conn = new::<MySql>().await?;
@@ -451,3 +602,204 @@ async fn it_can_work_with_transactions() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_returns_the_first_id_of_a_multi_row_insert() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE last_insert_ids (
+    id BIGINT UNSIGNED PRIMARY KEY AUTO_INCREMENT,
+    label TEXT NOT NULL
+);
+
+CREATE TEMPORARY TABLE no_auto_increment (
+    label TEXT NOT NULL
+);
+        "#,
+    )
+    .await?;
+
+    // move the counter away from 1 so a stale value can't pass by accident
+    sqlx::query("INSERT INTO last_insert_ids (label) VALUES ('first')")
+        .execute(&mut conn)
+        .await?;
+
+    let result = sqlx::query("INSERT INTO last_insert_ids (label) VALUES (?), (?), (?)")
+        .bind("a")
+        .bind("b")
+        .bind("c")
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(result.rows_affected(), 3);
+
+    let first_id: u64 = sqlx::query_scalar("SELECT id FROM last_insert_ids WHERE label = 'a'")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(result.last_insert_id(), first_id);
+
+    let result = sqlx::query("INSERT INTO no_auto_increment (label) VALUES ('x')")
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(result.last_insert_id(), 0);
+
+    Ok(())
+}
+
+sqlx_test::test_named_savepoints!(
+    MySql,
+    "_sqlx_savepoints",
+    "CREATE TEMPORARY TABLE _sqlx_savepoints (id INTEGER PRIMARY KEY)"
+);
+
+#[sqlx_macros::test]
+async fn it_releases_the_savepoints_of_nested_transactions() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE _sqlx_savepoints_4209 (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let insert = "INSERT INTO _sqlx_savepoints_4209 (id) VALUES (?)";
+
+    let mut tx = conn.begin().await?;
+    let mut tx1 = tx.begin().await?;
+    let mut tx2 = tx1.begin().await?;
+    let mut tx3 = tx2.begin().await?;
+    let mut tx4 = tx3.begin().await?;
+
+    sqlx::query(insert).bind(1_i32).execute(&mut tx4).await?;
+
+    // the savepoints are released along with the next one, or by the end of the transaction
+    tx4.commit_with_savepoint_release().await?;
+
+    let mut tx4 = tx3.begin().await?;
+    sqlx::query(insert).bind(2_i32).execute(&mut tx4).await?;
+    tx4.commit_with_savepoint_release().await?;
+
+    // restores the savepoint established before both, which destroys the deferred one
+    tx3.rollback().await?;
+
+    sqlx::query(insert).bind(3_i32).execute(&mut tx2).await?;
+    tx2.commit_with_savepoint_release().await?;
+
+    let mut savepoint = tx1.savepoint("fourth").await?;
+    sqlx::query(insert)
+        .bind(4_i32)
+        .execute(&mut *savepoint)
+        .await?;
+    savepoint.release().await?;
+
+    tx1.commit().await?;
+    tx.commit().await?;
+
+    let ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM _sqlx_savepoints_4209 ORDER BY id")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(ids, vec![3, 4]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_begins_transactions_with_options() -> anyhow::Result<()> {
+    use sqlx::{IsolationLevel, TransactionOptions};
+
+    let mut conn = new::<MySql>().await?;
+
+    // unlike temporary tables, regular tables cannot be written in a read-only transaction
+    conn.execute("DROP TABLE IF EXISTS tx_options_5063").await?;
+    conn.execute("CREATE TABLE tx_options_5063 (value INT)")
+        .await?;
+
+    let options = TransactionOptions::new()
+        .isolation_level(IsolationLevel::RepeatableRead)
+        .read_only(true);
+
+    let mut tx = conn.begin_with_options(options).await?;
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tx_options_5063")
+        .fetch_one(&mut tx)
+        .await?;
+    assert_eq!(count, 0);
+
+    let err = sqlx::query("INSERT INTO tx_options_5063 VALUES (1)")
+        .execute(&mut tx)
+        .await
+        .expect_err("expected the insert to fail");
+
+    assert_eq!(
+        err.into_database_error().unwrap().code().as_deref(),
+        Some("25006")
+    );
+
+    tx.rollback().await?;
+
+    // the options only applied to that transaction
+    let mut tx = conn.begin().await?;
+
+    sqlx::query("INSERT INTO tx_options_5063 VALUES (1)")
+        .execute(&mut tx)
+        .await?;
+
+    tx.commit().await?;
+
+    conn.execute("DROP TABLE tx_options_5063").await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_authenticates_without_tls() -> anyhow::Result<()> {
+    use sqlx::mysql::{MySqlConnectOptions, MySqlSslMode};
+
+    setup_if_needed();
+
+    let options: MySqlConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    let options = options.ssl_mode(MySqlSslMode::Disabled);
+
+    // with `caching_sha2_password`, the first connection sends the password encrypted with the
+    // public key of the server, and the following ones are authenticated from its cache
+    for _ in 0..3 {
+        let mut conn = MySqlConnection::connect_with(&options).await?;
+
+        assert_eq!(conn.tls_info(), None);
+
+        // no packet of the authentication is left over for the first queries
+        let user: String = sqlx::query_scalar("SELECT CURRENT_USER()")
+            .fetch_one(&mut conn)
+            .await?;
+        assert!(user.contains('@'), "{:?}", user);
+
+        let val: i64 = sqlx::query_scalar("SELECT 1 + 1")
+            .fetch_one(&mut conn)
+            .await?;
+        assert_eq!(val, 2);
+
+        conn.close().await?;
+    }
+
+    Ok(())
+}
+
+// temporary tables can not have foreign keys, and check constraints are not enforced before
+// MySQL 8.0.16
+sqlx_test::test_error_kinds!(
+    MySql,
+    r#"
+DROP TABLE IF EXISTS error_kind_children, error_kind_parents;
+CREATE TABLE error_kind_parents (id INT PRIMARY KEY) ENGINE = InnoDB;
+CREATE TABLE error_kind_children (
+    id INT PRIMARY KEY,
+    parent_id INT,
+    name TEXT NOT NULL,
+    FOREIGN KEY (parent_id) REFERENCES error_kind_parents (id)
+) ENGINE = InnoDB;
+    "#,
+    [("SELECT * FROM error_kind_missing", Other)],
+    "DROP TABLE error_kind_children, error_kind_parents"
+);
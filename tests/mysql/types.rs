@@ -9,16 +9,28 @@ use sqlx_test::{new, test_type};
 
 test_type!(bool(MySql, "false" == false, "true" == true));
 
-test_type!(u8(MySql, "CAST(253 AS UNSIGNED)" == 253_u8));
+test_type!(u8(
+    MySql,
+    "CAST(253 AS UNSIGNED)" == 253_u8,
+    "CAST(255 AS UNSIGNED)" == u8::MAX,
+));
 test_type!(i8(MySql, "5" == 5_i8, "0" == 0_i8));
 
 test_type!(u16(MySql, "CAST(21415 AS UNSIGNED)" == 21415_u16));
 test_type!(i16(MySql, "21415" == 21415_i16));
 
-test_type!(u32(MySql, "CAST(2141512 AS UNSIGNED)" == 2141512_u32));
+test_type!(u32(
+    MySql,
+    "CAST(2141512 AS UNSIGNED)" == 2141512_u32,
+    "CAST(4294967295 AS UNSIGNED)" == u32::MAX,
+));
 test_type!(i32(MySql, "2141512" == 2141512_i32));
 
-test_type!(u64(MySql, "CAST(2141512 AS UNSIGNED)" == 2141512_u64));
+test_type!(u64(
+    MySql,
+    "CAST(2141512 AS UNSIGNED)" == 2141512_u64,
+    "CAST(18446744073709551615 AS UNSIGNED)" == u64::MAX,
+));
 test_type!(i64(MySql, "2141512" == 2141512_i64));
 
 test_type!(f64(
@@ -46,6 +58,25 @@ test_type!(bytes<Vec<u8>>(MySql,
         == vec![0_u8, 0, 0, 0, 0x52]
 ));
 
+test_type!(byte_array<[u8; 16]>(MySql,
+    "X'000102030405060708090A0B0C0D0E0F'"
+        == [0_u8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+));
+
+#[sqlx_macros::test]
+async fn it_rejects_byte_arrays_of_the_wrong_length() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let row = conn
+        .fetch_one("SELECT X'000102030405060708090A0B0C0D0E0F'")
+        .await?;
+
+    assert_eq!(row.try_get::<[u8; 16], _>(0)?[15], 15);
+    assert!(row.try_get::<[u8; 8], _>(0).is_err());
+
+    Ok(())
+}
+
 #[cfg(feature = "uuid")]
 test_type!(uuid<sqlx::types::Uuid>(MySql,
     "x'b731678f636f4135bc6f19440c13bd19'"
@@ -139,6 +170,23 @@ mod chrono {
 
         Ok(())
     }
+
+    #[sqlx_macros::test]
+    async fn test_bind_chrono_fixed_offset() -> anyhow::Result<()> {
+        let mut conn = sqlx_test::new::<MySql>().await?;
+
+        let datetime = DateTime::<FixedOffset>::parse_from_rfc3339("2019-01-02T05:10:20+05:30")?;
+
+        let utc: DateTime<Utc> = sqlx::query_scalar("SELECT CAST(? AS DATETIME)")
+            .bind(datetime)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(utc, datetime);
+        assert_eq!(utc.to_rfc3339(), "2019-01-01T23:40:20+00:00");
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "time")]
@@ -227,8 +275,34 @@ test_type!(bigdecimal<sqlx::types::BigDecimal>(
     "CAST(0.01234 AS DECIMAL(6, 5))" == "0.01234".parse::<sqlx::types::BigDecimal>().unwrap(),
     "CAST(12.34 AS DECIMAL(4, 2))" == "12.34".parse::<sqlx::types::BigDecimal>().unwrap(),
     "CAST(12345.6789 AS DECIMAL(9, 4))" == "12345.6789".parse::<sqlx::types::BigDecimal>().unwrap(),
+    "CAST(12345678901234567890.1234567890 AS DECIMAL(30, 10))" == "12345678901234567890.1234567890".parse::<sqlx::types::BigDecimal>().unwrap(),
 ));
 
+#[cfg(feature = "bigdecimal")]
+#[sqlx_macros::test]
+async fn it_decodes_bigdecimal_without_loss_of_precision() -> anyhow::Result<()> {
+    use sqlx::types::BigDecimal;
+
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE amounts (amount DECIMAL(30, 10) NOT NULL)")
+        .await?;
+    conn.execute("INSERT INTO amounts (amount) VALUES (12345678901234567890.1234567890)")
+        .await?;
+
+    // text protocol
+    let amount: BigDecimal = conn.fetch_one("SELECT amount FROM amounts").await?.try_get(0)?;
+    assert_eq!(amount.to_string(), "12345678901234567890.1234567890");
+
+    // binary protocol
+    let amount: BigDecimal = sqlx::query_scalar("SELECT amount FROM amounts")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(amount.to_string(), "12345678901234567890.1234567890");
+
+    Ok(())
+}
+
 test_type!(bigdecimal_as_f64<f64>(
     MySql,
     "CAST(25.25 as DECIMAL(9, 4))" == 25.25,
@@ -243,6 +317,7 @@ test_type!(decimal<sqlx::types::Decimal>(MySql,
     "CAST(0.01234 AS DECIMAL(6, 5))" == sqlx::types::Decimal::from_str("0.01234").unwrap(),
     "CAST(12.34 AS DECIMAL(4, 2))" == sqlx::types::Decimal::from_str("12.34").unwrap(),
     "CAST(12345.6789 AS DECIMAL(9, 4))" == sqlx::types::Decimal::from_str("12345.6789").unwrap(),
+    "CAST(1234567890123456.789012345678 AS DECIMAL(28, 12))" == sqlx::types::Decimal::from_str("1234567890123456.789012345678").unwrap(),
 ));
 
 #[cfg(feature = "json")]
@@ -287,6 +362,58 @@ mod json_tests {
         MySql,
         "\'{\"json_column\":[1,2]}\'" == Json(Customer { json_column: Json(vec![1, 2]) })
     ));
+
+    #[sqlx_macros::test]
+    async fn it_stores_json_in_a_json_column() -> anyhow::Result<()> {
+        let mut conn = new::<MySql>().await?;
+
+        conn.execute(
+            r#"
+CREATE TEMPORARY TABLE with_json (
+    id INT PRIMARY KEY AUTO_INCREMENT,
+    friend JSON NOT NULL
+);
+            "#,
+        )
+        .await?;
+
+        let friend = Friend {
+            name: "Joe".to_string(),
+            age: 33,
+        };
+
+        sqlx::query("INSERT INTO with_json (friend) VALUES (?)")
+            .bind(Json(&friend))
+            .execute(&mut conn)
+            .await?;
+
+        let (stored, json_type): (Json<Friend>, String) =
+            sqlx::query_as("SELECT friend, JSON_TYPE(friend) FROM with_json")
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert_eq!(stored.0, friend);
+
+        // the document was stored as an object, not as a JSON string
+        assert_eq!(json_type, "OBJECT");
+
+        let (extracted, name): (Json<Friend>, Json<String>) = sqlx::query_as(
+            "SELECT JSON_EXTRACT(friend, '$'), JSON_EXTRACT(friend, '$.name') FROM with_json",
+        )
+        .fetch_one(&mut conn)
+        .await?;
+
+        assert_eq!(extracted.0, friend);
+        assert_eq!(name.0, "Joe");
+
+        Ok(())
+    }
+
+    sqlx_test::test_json_nulls!(
+        MySql,
+        "JSON",
+        "INSERT INTO json_nulls (id, friend) VALUES (?, ?)"
+    );
 }
 
 #[sqlx_macros::test]
@@ -330,3 +457,143 @@ CREATE TEMPORARY TABLE with_bits (
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn test_unsigned_integers() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE with_unsigned (
+    id INT PRIMARY KEY AUTO_INCREMENT,
+    value_8 TINYINT UNSIGNED NOT NULL,
+    value_32 INT(10) UNSIGNED ZEROFILL NOT NULL,
+    value_64 BIGINT UNSIGNED NOT NULL
+);
+    "#,
+    )
+    .await?;
+
+    sqlx::query("INSERT INTO with_unsigned (value_8, value_32, value_64) VALUES (?, ?, ?)")
+        .bind(u8::MAX)
+        .bind(u32::MAX)
+        .bind(u64::MAX)
+        .execute(&mut conn)
+        .await?;
+
+    // BINARY
+    let row = sqlx::query("SELECT value_8, value_32, value_64 FROM with_unsigned")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.try_get::<u8, _>(0)?, u8::MAX);
+    assert_eq!(row.try_get::<u32, _>(1)?, u32::MAX);
+    assert_eq!(row.try_get::<u64, _>(2)?, u64::MAX);
+
+    // values that do not fit are not truncated
+    assert!(row.try_get_unchecked::<i8, _>(0).is_err());
+    assert!(row.try_get_unchecked::<i32, _>(1).is_err());
+    assert!(row.try_get_unchecked::<i64, _>(2).is_err());
+    assert_eq!(row.try_get_unchecked::<i16, _>(0)?, 255);
+    assert_eq!(row.try_get_unchecked::<i64, _>(1)?, 4294967295);
+
+    // TEXT
+    let row = conn
+        .fetch_one("SELECT value_8, value_32, value_64 FROM with_unsigned")
+        .await?;
+
+    assert_eq!(row.try_get::<u8, _>(0)?, u8::MAX);
+    assert_eq!(row.try_get::<u32, _>(1)?, u32::MAX);
+    assert_eq!(row.try_get::<u64, _>(2)?, u64::MAX);
+
+    assert!(row.try_get_unchecked::<i8, _>(0).is_err());
+    assert!(row.try_get_unchecked::<i32, _>(1).is_err());
+    assert!(row.try_get_unchecked::<i64, _>(2).is_err());
+
+    // negative values do not fit into unsigned integers either
+    let row = sqlx::query("SELECT CAST(-1 AS SIGNED)")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(row.try_get_unchecked::<u64, _>(0).is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_enums_and_sets() -> anyhow::Result<()> {
+    use sqlx::mysql::types::MySqlSet;
+    use std::collections::{BTreeSet, HashSet};
+
+    #[derive(Debug, PartialEq, sqlx::Type)]
+    #[sqlx(rename_all = "lowercase")]
+    enum Size {
+        Small,
+        Medium,
+        Large,
+    }
+
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE with_enums_and_sets (
+    id INT PRIMARY KEY AUTO_INCREMENT,
+    size ENUM('small', 'medium', 'large') NOT NULL,
+    toppings SET('cheese', 'ham', 'pineapple') NOT NULL
+);
+    "#,
+    )
+    .await?;
+
+    sqlx::query("INSERT INTO with_enums_and_sets (size, toppings) VALUES (?, ?), (?, ?)")
+        .bind("medium")
+        .bind("pineapple,cheese")
+        .bind("small")
+        .bind("")
+        .execute(&mut conn)
+        .await?;
+
+    // BINARY
+    let rows: Vec<(Size, String, MySqlSet<Vec<String>>)> =
+        sqlx::query_as("SELECT size, size, toppings FROM with_enums_and_sets ORDER BY id")
+            .fetch_all(&mut conn)
+            .await?;
+
+    assert_eq!(
+        rows,
+        [
+            (
+                Size::Medium,
+                "medium".to_owned(),
+                MySqlSet(vec!["cheese".to_owned(), "pineapple".to_owned()])
+            ),
+            (Size::Small, "small".to_owned(), MySqlSet(vec![])),
+        ]
+    );
+
+    // TEXT
+    let row = conn
+        .fetch_one("SELECT size, toppings FROM with_enums_and_sets ORDER BY id LIMIT 1")
+        .await?;
+    let size: Size = row.try_get(0)?;
+    let MySqlSet(toppings): MySqlSet<HashSet<String>> = row.try_get(1)?;
+
+    assert_eq!(size, Size::Medium);
+    assert_eq!(
+        toppings,
+        HashSet::from(["cheese".to_owned(), "pineapple".to_owned()])
+    );
+
+    let MySqlSet(toppings): MySqlSet<BTreeSet<String>> = row.try_get(1)?;
+    assert_eq!(toppings.len(), 2);
+
+    // a SET is not decoded from an arbitrary string
+    let res = sqlx::query_scalar::<_, MySqlSet<Vec<String>>>("SELECT 'cheese,ham'")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use futures::TryStreamExt;
 use sqlx_core::postgres::types::PgRange;
-use sqlx::{Connection, Executor, FromRow, Postgres};
+use sqlx::{Connection, Executor, FromRow, Postgres, Row};
 use sqlx_test::{new, test_type};
 use std::fmt::Debug;
 use std::ops::Bound;
@@ -33,6 +33,14 @@ enum Weak {
     Three = 4,
 }
 
+// The integer type of a "weak" enum can also be given by #[sqlx(repr)]
+#[derive(PartialEq, Copy, Clone, Debug, sqlx::Type)]
+#[sqlx(repr = "i16")]
+enum Status {
+    A = 1,
+    B = 2,
+}
+
 // "Strong" enums can map to TEXT (25)
 #[derive(PartialEq, Debug, sqlx::Type)]
 #[sqlx(type_name = "text")]
@@ -114,6 +122,16 @@ enum Mood {
     Sad,
 }
 
+// Enums get an array type as well, if asked for
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(type_name = "status", pg_array)]
+#[sqlx(rename_all = "lowercase")]
+enum TicketStatus {
+    New,
+    Open,
+    Closed,
+}
+
 // Records must map to a custom type
 // Note that all types are types in Postgres
 #[derive(PartialEq, Debug, sqlx::Type)]
@@ -124,6 +142,31 @@ struct InventoryItem {
     price: Option<i64>,
 }
 
+// Records get an array type as well, if asked for
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(type_name = "float_point", pg_array)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+// The array type of a type in a schema is named in it, i.e. `geo._point_2d`
+#[allow(dead_code)]
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(type_name = "geo.point_2d", pg_array)]
+struct GeoPoint {
+    x: f64,
+    y: f64,
+}
+
+// Fields of a record may be NULL
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(type_name = "address")]
+struct Address {
+    street: String,
+    unit: Option<i32>,
+}
+
 // Custom range type
 #[derive(sqlx::Type, Debug, PartialEq)]
 #[sqlx(type_name = "float_range")]
@@ -145,6 +188,32 @@ test_type!(weak_enum<Weak>(Postgres,
     "4::int4" == Weak::Three
 ));
 
+test_type!(sqlx_repr_enum<Status>(Postgres,
+    "1::smallint" == Status::A,
+    "2::smallint" == Status::B
+));
+
+#[sqlx_macros::test]
+async fn test_sqlx_repr_enum_rejects_unknown_discriminants() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let status: Status = sqlx::query_scalar("SELECT 2::smallint")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(status, Status::B);
+
+    let res = sqlx::query_scalar::<_, Status>("SELECT 3::smallint")
+        .fetch_one(&mut conn)
+        .await;
+    assert!(
+        matches!(res, Err(sqlx::Error::ColumnDecode { .. })),
+        "{:?}",
+        res
+    );
+
+    Ok(())
+}
+
 test_type!(strong_enum<Strong>(Postgres,
     "'one'::text" == Strong::One,
     "'two'::text" == Strong::Two,
@@ -155,6 +224,17 @@ test_type!(floatrange<FloatRange>(Postgres,
     "'[1.234, 5.678]'::float_range" == FloatRange(PgRange::from((Bound::Included(1.234), Bound::Included(5.678)))),
 ));
 
+test_type!(enum_vec<Vec<TicketStatus>>(Postgres,
+    "ARRAY['new', 'closed']::status[]" == vec![TicketStatus::New, TicketStatus::Closed],
+    "ARRAY[]::status[]" == Vec::<TicketStatus>::new(),
+));
+
+test_type!(enum_vec_with_nulls<Vec<Option<TicketStatus>>>(Postgres,
+    "ARRAY['open', NULL, 'new']::status[]"
+        == vec![Some(TicketStatus::Open), None, Some(TicketStatus::New)],
+    "ARRAY[NULL]::status[]" == vec![None::<TicketStatus>],
+));
+
 #[sqlx_macros::test]
 async fn test_enum_type() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -374,6 +454,95 @@ SELECT $1 = ROW('fuzzy dice', 42, 199)::inventory_item, $1
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_record_type_with_null_field() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    for value in [
+        Address {
+            street: "1 Infinite Loop".to_owned(),
+            unit: None,
+        },
+        Address {
+            street: "1 Infinite Loop".to_owned(),
+            unit: Some(4),
+        },
+    ] {
+        let rec: (bool, Address) = sqlx::query_as(
+            "
+SELECT $1 IS NOT DISTINCT FROM ROW($2, $3)::address, $1
+            ",
+        )
+        .bind(&value)
+        .bind(&value.street)
+        .bind(value.unit)
+        .fetch_one(&mut conn)
+        .await?;
+
+        assert!(rec.0);
+        assert_eq!(rec.1, value);
+    }
+
+    // records in the text format, as returned by simple queries
+    let row = conn
+        .fetch_one("SELECT ROW('1 Infinite Loop', NULL)::address")
+        .await?;
+
+    let value: Address = row.try_get(0)?;
+
+    assert_eq!(
+        value,
+        Address {
+            street: "1 Infinite Loop".to_owned(),
+            unit: None,
+        }
+    );
+
+    // a NULL field cannot be decoded into a non-optional one
+    let res: Result<(Address,), _> = sqlx::query_as("SELECT ROW(NULL, 4)::address")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_record_array_type() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let points = vec![Point { x: 1.5, y: -2.0 }, Point { x: 0.0, y: 1e10 }];
+
+    let rec: (bool, Vec<Point>) = sqlx::query_as(
+        "
+SELECT $1 = ARRAY[ROW(1.5, -2.0), ROW(0.0, 1e10)]::float_point[], $1
+        ",
+    )
+    .bind(&points)
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(rec.0);
+    assert_eq!(rec.1, points);
+
+    let empty: Vec<Point> = sqlx::query_scalar("SELECT $1")
+        .bind(Vec::<Point>::new())
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(empty.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_array_type_name_in_schema() {
+    use sqlx::postgres::PgHasArrayType;
+
+    assert_eq!(GeoPoint::array_type_info().to_string(), "geo._point_2d");
+}
+
 #[cfg(feature = "macros")]
 #[sqlx_macros::test]
 async fn test_from_row() -> anyhow::Result<()> {
@@ -513,6 +682,35 @@ async fn test_from_row_with_rename_all() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "macros")]
+#[sqlx_macros::test]
+async fn test_from_row_with_rename_all_snake_case() -> anyhow::Result<()> {
+    #[allow(non_snake_case)]
+    #[derive(Debug, sqlx::FromRow)]
+    #[sqlx(rename_all = "snake_case")]
+    struct Account {
+        userId: i32,
+        userName: String,
+        // an explicit rename is used as-is
+        #[sqlx(rename = "Surname")]
+        userSurname: String,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let account: Account = sqlx::query_as(
+        r#"SELECT * from (VALUES (1, 'foo', 'bar')) accounts(user_id, user_name, "Surname")"#,
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(1, account.userId);
+    assert_eq!("foo", account.userName);
+    assert_eq!("bar", account.userSurname);
+
+    Ok(())
+}
+
 #[cfg(feature = "macros")]
 #[sqlx_macros::test]
 async fn test_from_row_tuple() -> anyhow::Result<()> {
@@ -614,3 +812,50 @@ async fn test_flatten() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg(feature = "macros")]
+#[sqlx_macros::test]
+async fn test_flatten_with_prefix() -> anyhow::Result<()> {
+    #[derive(Debug, sqlx::FromRow)]
+    struct Country {
+        id: i32,
+        name: String,
+    }
+
+    #[derive(Debug, sqlx::FromRow)]
+    struct Customer {
+        id: i32,
+        name: String,
+        #[sqlx(flatten, prefix = "country_")]
+        country: Country,
+    }
+
+    #[derive(Debug, sqlx::FromRow)]
+    struct Order {
+        id: i32,
+        #[sqlx(flatten, prefix = "customer_")]
+        customer: Customer,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let order: Order = sqlx::query_as(
+        r#"SELECT 1 AS id, 2 AS customer_id, 'foo' AS customer_name, 3 AS customer_country_id, 'bar' AS customer_country_name"#,
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(1, order.id);
+    assert_eq!(2, order.customer.id);
+    assert_eq!("foo", order.customer.name);
+    assert_eq!(3, order.customer.country.id);
+    assert_eq!("bar", order.customer.country.name);
+
+    let res: Result<Order, _> = sqlx::query_as("SELECT 1 AS id, 2 AS customer_id")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::ColumnNotFound(name)) if name == "customer_name"));
+
+    Ok(())
+}
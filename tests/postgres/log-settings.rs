@@ -0,0 +1,147 @@
+// This lives in its own test binary as it installs the global logger,
+// which `sqlx_test` would otherwise set to `env_logger`.
+
+use log::{Level, LevelFilter};
+use sqlx::postgres::Postgres;
+use sqlx::{Connection, Executor};
+use sqlx_test::{capture_logs, new, transaction_statements_since, LogRecords};
+use std::time::Duration;
+
+fn find_log(records: &LogRecords, sql: &str) -> Option<(Level, String)> {
+    records
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|(_, message)| message.starts_with(sql))
+        .cloned()
+}
+
+fn level_of(records: &LogRecords, sql: &str) -> Option<Level> {
+    find_log(records, sql).map(|(level, _)| level)
+}
+
+#[sqlx_macros::test]
+async fn it_changes_log_settings_without_reconnecting() -> anyhow::Result<()> {
+    let records = capture_logs();
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("SELECT 1 AS default_level").await?;
+
+    assert_eq!(
+        level_of(&records, "SELECT 1 AS default_level"),
+        Some(Level::Info)
+    );
+
+    let mut settings = conn.log_settings().clone();
+    settings.log_statements(LevelFilter::Trace);
+    conn.set_log_settings(settings);
+
+    conn.execute("SELECT 2 AS raised_level").await?;
+
+    assert_eq!(
+        level_of(&records, "SELECT 2 AS raised_level"),
+        Some(Level::Trace)
+    );
+
+    // every statement takes at least zero seconds, so this one is always slow
+    let mut settings = conn.log_settings().clone();
+    settings.log_slow_statements(LevelFilter::Error, Duration::ZERO);
+    conn.set_log_settings(settings);
+
+    conn.execute("SELECT 3 AS slow_level").await?;
+
+    assert_eq!(
+        level_of(&records, "SELECT 3 AS slow_level"),
+        Some(Level::Error)
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_logs_parameters_with_redaction() -> anyhow::Result<()> {
+    let records = capture_logs();
+
+    let mut conn = new::<Postgres>().await?;
+
+    sqlx::query("SELECT $1::text AS not_logged")
+        .bind("alice")
+        .execute(&mut conn)
+        .await?;
+
+    let (_, message) = find_log(&records, "SELECT $1::text AS not_logged").unwrap();
+    assert!(!message.contains("alice"), "{}", message);
+
+    let mut settings = conn.log_settings().clone();
+    settings.log_parameters(true);
+    settings.redact_parameters(|index| index == 1);
+    conn.set_log_settings(settings);
+
+    sqlx::query("SELECT $1::text AS username, $2::text AS password")
+        .bind("alice")
+        .bind("hunter2")
+        .execute(&mut conn)
+        .await?;
+
+    let (_, message) = find_log(&records, "SELECT $1::text AS username,").unwrap();
+    assert!(
+        message.contains(r#"parameters: ["alice", <redacted>]"#),
+        "{}",
+        message
+    );
+    assert!(!message.contains("hunter2"), "{}", message);
+
+    sqlx::query("SELECT $1::bytea AS blob, $2::int4 AS number, $3::text AS missing")
+        .bind(vec![0xab_u8; 1024])
+        .bind(42_i32)
+        .bind(None::<String>)
+        .execute(&mut conn)
+        .await?;
+
+    let (_, message) = find_log(&records, "SELECT $1::bytea AS blob,").unwrap();
+    assert!(
+        message.contains(&format!(
+            "parameters: [<1024 bytes: {}…>, <redacted>, NULL]",
+            "ab".repeat(16)
+        )),
+        "{}",
+        message
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_releases_nested_savepoints_in_a_single_statement() -> anyhow::Result<()> {
+    let records = capture_logs();
+
+    let mut conn = new::<Postgres>().await?;
+
+    let mut tx = conn.begin().await?;
+    let mut tx1 = tx.begin().await?;
+    let mut tx2 = tx1.begin().await?;
+    let mut tx3 = tx2.begin().await?;
+    let mut tx4 = tx3.begin().await?;
+    let mut tx5 = tx4.begin().await?;
+
+    tx5.execute("SELECT 1 AS nested_savepoints").await?;
+
+    let start = records.lock().unwrap().len();
+
+    tx5.commit_with_savepoint_release().await?;
+    tx4.commit_with_savepoint_release().await?;
+    tx3.commit_with_savepoint_release().await?;
+    tx2.commit_with_savepoint_release().await?;
+
+    // releasing the outermost savepoint releases the four committed inside of it
+    tx1.commit().await?;
+    tx.commit().await?;
+
+    assert_eq!(
+        transaction_statements_since(&records, start),
+        ["RELEASE SAVEPOINT _sqlx_savepoint_1", "COMMIT"]
+    );
+
+    Ok(())
+}
@@ -1,8 +1,8 @@
 use futures::{StreamExt, TryStreamExt};
 use sqlx::postgres::types::Oid;
 use sqlx::postgres::{
-    PgAdvisoryLock, PgConnectOptions, PgConnection, PgDatabaseError, PgErrorPosition, PgListener,
-    PgPoolOptions, PgRow, PgSeverity, Postgres,
+    PgAdvisoryLock, PgChannelBinding, PgConnectOptions, PgConnection, PgDatabaseError,
+    PgErrorPosition, PgListener, PgPoolOptions, PgRow, PgSeverity, PgSslMode, Postgres,
 };
 use sqlx::{Column, Connection, Executor, Row, Statement, TypeInfo};
 use sqlx_test::{new, pool, setup_if_needed};
@@ -24,6 +24,293 @@ async fn it_connects() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_reports_the_server_version() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let expected: String = sqlx::query_scalar("SHOW server_version_num")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let version = conn.server_version_num().expect("server version");
+
+    assert!(version >= 90000, "{}", version);
+    assert_eq!(version, expected.parse::<u32>()?);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_binds_typed_nulls_to_pick_an_overload() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE FUNCTION pg_temp.overloaded_5121(x INT4) RETURNS TEXT AS $$ SELECT 'int4' $$ LANGUAGE SQL;
+CREATE FUNCTION pg_temp.overloaded_5121(x TEXT) RETURNS TEXT AS $$ SELECT 'text' $$ LANGUAGE SQL;
+    "#,
+    )
+    .await?;
+
+    let picked: String = sqlx::query_scalar("SELECT pg_temp.overloaded_5121($1)")
+        .bind(None::<i32>)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(picked, "int4");
+
+    // the statement above is cached with an `INT4` parameter, so this one is spelled differently
+    let picked: String = sqlx::query_scalar("SELECT pg_temp.overloaded_5121($1) AS picked")
+        .bind(None::<String>)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(picked, "text");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_pipelines_queries() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE pipeline_5601 (seq SERIAL, id INT4 PRIMARY KEY)")
+        .await?;
+
+    let insert = "INSERT INTO pipeline_5601 (id) VALUES ($1)";
+
+    let results = conn
+        .pipeline((0..100).map(|i| sqlx::query(insert).bind(i)))
+        .await?;
+
+    assert_eq!(results.len(), 100);
+
+    for result in results {
+        assert_eq!(result?.rows_affected(), 1);
+    }
+
+    // the queries are executed in the order they were given
+    let ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM pipeline_5601 ORDER BY seq")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(ids, (0..100).collect::<Vec<_>>());
+
+    // failed queries do not prevent the others from being executed
+    let results = conn
+        .pipeline(vec![
+            sqlx::query(insert).bind(100),
+            sqlx::query(insert).bind(0),
+            sqlx::query("INSERT INTO pipeline_5601_missing (id) VALUES ($1)").bind(1),
+            sqlx::query(insert).bind(101),
+        ])
+        .await?;
+
+    let codes: Vec<_> = results
+        .iter()
+        .map(|result| match result {
+            Ok(_) => None,
+            Err(err) => Some(
+                err.as_database_error()
+                    .and_then(|err| err.code())
+                    .unwrap()
+                    .into_owned(),
+            ),
+        })
+        .collect();
+
+    // 23505 is `unique_violation` and 42P01 is `undefined_table`
+    assert_eq!(
+        codes,
+        [
+            None,
+            Some("23505".to_owned()),
+            Some("42P01".to_owned()),
+            None
+        ]
+    );
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM pipeline_5601")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 102);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_pipelines_queries_in_one_round_trip() -> anyhow::Result<()> {
+    use std::io::{Read, Write};
+    use std::net::{Shutdown, TcpListener, TcpStream};
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    // forward the connection through a proxy which counts the times the client sends anything
+    // after the server has answered, i.e. the number of round trips
+    let url: url::Url = env::var("DATABASE_URL")?.parse()?;
+    let server = TcpStream::connect((
+        url.host_str().unwrap_or("localhost"),
+        url.port().unwrap_or(5432),
+    ))?;
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let port = listener.local_addr()?.port();
+
+    fn forward(mut from: TcpStream, mut to: TcpStream, on_read: impl Fn() + Send + 'static) {
+        std::thread::spawn(move || {
+            let mut buf = [0; 8192];
+
+            loop {
+                let n = match from.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => n,
+                };
+
+                on_read();
+
+                if to.write_all(&buf[..n]).is_err() {
+                    break;
+                }
+            }
+
+            let _ = to.shutdown(Shutdown::Write);
+        });
+    }
+
+    let round_trips = Arc::new(AtomicUsize::new(0));
+    let answered = Arc::new(AtomicBool::new(false));
+
+    let accept = {
+        let round_trips = Arc::clone(&round_trips);
+        let answered = Arc::clone(&answered);
+
+        std::thread::spawn(move || -> std::io::Result<()> {
+            let (client, _) = listener.accept()?;
+
+            forward(server.try_clone()?, client.try_clone()?, {
+                let answered = Arc::clone(&answered);
+                move || answered.store(true, Ordering::SeqCst)
+            });
+
+            forward(client, server, move || {
+                if answered.swap(false, Ordering::SeqCst) {
+                    round_trips.fetch_add(1, Ordering::SeqCst);
+                }
+            });
+
+            Ok(())
+        })
+    };
+
+    let options: PgConnectOptions = url.as_str().parse()?;
+    let mut conn = PgConnection::connect_with(&options.host("127.0.0.1").port(port)).await?;
+
+    accept.join().unwrap()?;
+
+    conn.execute("CREATE TEMPORARY TABLE pipeline_round_trips (id INT4)")
+        .await?;
+
+    let insert = "INSERT INTO pipeline_round_trips (id) VALUES ($1)";
+
+    // prepare and cache the statement first, so that neither count includes it
+    sqlx::query(insert).bind(0).execute(&mut conn).await?;
+
+    let before = round_trips.load(Ordering::SeqCst);
+
+    for i in 0..10 {
+        sqlx::query(insert).bind(i).execute(&mut conn).await?;
+    }
+
+    assert_eq!(round_trips.load(Ordering::SeqCst) - before, 10);
+
+    let before = round_trips.load(Ordering::SeqCst);
+
+    conn.pipeline((0..10).map(|i| sqlx::query(insert).bind(i)))
+        .await?;
+
+    assert_eq!(round_trips.load(Ordering::SeqCst) - before, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_checks_the_number_of_bound_parameters() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let res = sqlx::query("SELECT $1::int4 + $2::int4")
+        .bind(1_i32)
+        .execute(&mut conn)
+        .await;
+
+    match res {
+        Err(sqlx::Error::Protocol(message)) => {
+            assert_eq!(message, "the query has 2 parameters but 1 was bound")
+        }
+        res => panic!("expected a protocol error, got {:?}", res),
+    }
+
+    // a placeholder used twice is bound once
+    let sum: i32 = sqlx::query_scalar("SELECT $1::int4 + $1")
+        .bind(2_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(sum, 4);
+
+    // in a pipeline, the other queries are still executed
+    let results = conn
+        .pipeline(vec![
+            sqlx::query("SELECT $1::int4 + $2::int4").bind(1_i32),
+            sqlx::query("SELECT $1::int4").bind(1_i32),
+        ])
+        .await?;
+
+    assert!(matches!(results[0], Err(sqlx::Error::Protocol(_))));
+    assert!(results[1].is_ok());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_decodes_registered_extension_types() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // not compatible with `String` until registered
+    let res = sqlx::query_scalar::<_, String>("SELECT 'Hello'::citext")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(
+        matches!(res, Err(sqlx::Error::ColumnDecode { .. })),
+        "{:?}",
+        res
+    );
+
+    conn.register_type::<String>("citext").await?;
+
+    // the statement above is cached with the type it was first described with
+    let value: String = sqlx::query_scalar("SELECT 'Hello'::citext AS value")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, "Hello");
+
+    let equal: bool = sqlx::query_scalar("SELECT 'HELLO'::citext = 'hello'::citext")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(equal);
+
+    let res = conn.register_type::<String>("not_a_type_5701").await;
+
+    assert!(
+        matches!(res, Err(sqlx::Error::TypeNotFound { ref type_name }) if type_name == "not_a_type_5701"),
+        "{:?}",
+        res
+    );
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_select_void() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -374,6 +661,90 @@ async fn it_can_query_scalar() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_can_query_as_a_wide_tuple() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    #[rustfmt::skip]
+    type Row20 = (
+        i32, i32, i32, i32, i32, i32, i32, i32, i32, i32,
+        i32, i32, i32, i32, i32, i32, i32, i32, i32, String,
+    );
+
+    #[rustfmt::skip]
+    type Row32 = (
+        i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64,
+        i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64,
+    );
+
+    let row: Row20 = sqlx::query_as(
+        "SELECT 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 'twenty'",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(row.0, 1);
+    assert_eq!(row.15, 16);
+    assert_eq!(row.18, 19);
+    assert_eq!(row.19, "twenty");
+
+    // the widest tuple that can be decoded from a row
+    let sql = format!(
+        "SELECT {}",
+        (1..=32)
+            .map(|i| format!("{}::int8", i))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    let row: Row32 = sqlx::query_as(&sql).fetch_one(&mut conn).await?;
+
+    assert_eq!(row.0, 1);
+    assert_eq!(row.16, 17);
+    assert_eq!(row.31, 32);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_returns_decode_errors_from_fetch_optional() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // a row that fails to decode is an error, not a missing row
+    let res = sqlx::query_scalar::<_, i32>("SELECT 'not a number'::text")
+        .fetch_optional(&mut conn)
+        .await;
+
+    assert!(
+        matches!(res, Err(sqlx::Error::ColumnDecode { .. })),
+        "{:?}",
+        res
+    );
+
+    let res = sqlx::query_as::<_, (i32,)>("SELECT 'not a number'::text")
+        .fetch_optional(&mut conn)
+        .await;
+
+    assert!(
+        matches!(res, Err(sqlx::Error::ColumnDecode { .. })),
+        "{:?}",
+        res
+    );
+
+    // ...while a query without rows is `None`
+    let scalar: Option<i32> = sqlx::query_scalar("SELECT 1 WHERE false")
+        .fetch_optional(&mut conn)
+        .await?;
+    assert_eq!(scalar, None);
+
+    let row: Option<(i32,)> = sqlx::query_as("SELECT 1 WHERE false")
+        .fetch_optional(&mut conn)
+        .await?;
+    assert_eq!(row, None);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 /// This is separate from `it_can_query_scalar` because while implementing it I ran into a
 /// bug which that prevented `Vec<i32>` from compiling but allowed Vec<Option<i32>>.
@@ -510,74 +881,265 @@ async fn it_can_work_with_nested_transactions() -> anyhow::Result<()> {
     Ok(())
 }
 
+async fn insert_in_savepoint<'a, A>(conn: A, id: i32) -> anyhow::Result<()>
+where
+    A: sqlx::Acquire<'a, Database = Postgres>,
+{
+    let mut tx = conn.begin().await?;
+
+    sqlx::query("INSERT INTO _sqlx_savepoint_refs (id) VALUES ($1)")
+        .bind(id)
+        .execute(&mut tx)
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+async fn begin_and_commit<'a, DB, A>(conn: A) -> sqlx::Result<()>
+where
+    DB: sqlx::Database,
+    A: sqlx::Acquire<'a, Database = DB>,
+{
+    conn.begin().await?.commit().await
+}
+
 #[sqlx_macros::test]
-async fn it_can_drop_multiple_transactions() -> anyhow::Result<()> {
+async fn it_acquires_savepoints_from_a_transaction_reference() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
 
-    conn.execute("CREATE TABLE IF NOT EXISTS _sqlx_users_3952 (id INTEGER PRIMARY KEY)")
+    // a temporary table is private to this connection, so other tests can't see or touch it
+    conn.execute("CREATE TEMPORARY TABLE _sqlx_savepoint_refs (id INTEGER PRIMARY KEY)")
         .await?;
 
-    conn.execute("TRUNCATE _sqlx_users_3952").await?;
+    let mut tx = conn.begin().await?;
 
-    // begin .. (drop)
+    insert_in_savepoint(&mut tx, 1).await?;
+    insert_in_savepoint(&mut tx, 2).await?;
+    begin_and_commit(&mut tx).await?;
 
-    // run 2 times to see what happens if we drop transactions repeatedly
-    for _ in 0..2 {
-        {
-            let mut tx = conn.begin().await?;
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM _sqlx_savepoint_refs")
+        .fetch_one(&mut tx)
+        .await?;
 
-            // do actually something before dropping
-            let _user =
-                sqlx::query("INSERT INTO _sqlx_users_3952 (id) VALUES ($1) RETURNING id")
-                    .bind(20_i32)
-                    .fetch_one(&mut tx)
-                    .await?;
-        }
+    assert_eq!(count, 2);
 
-        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM _sqlx_users_3952")
-            .fetch_one(&mut conn)
-            .await?;
+    // committing the savepoints left the outer transaction open
+    tx.rollback().await?;
 
-        assert_eq!(count, 0);
-    }
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM _sqlx_savepoint_refs")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 0);
 
     Ok(())
 }
 
-// run with `cargo test --features postgres -- --ignored --nocapture pool_smoke_test`
-#[ignore]
+sqlx_test::test_named_savepoints!(
+    Postgres,
+    "_sqlx_savepoints",
+    "CREATE TEMPORARY TABLE _sqlx_savepoints (id INTEGER PRIMARY KEY)"
+);
+
 #[sqlx_macros::test]
-async fn pool_smoke_test() -> anyhow::Result<()> {
-    use futures::{future, task::Poll, Future};
+async fn it_keeps_the_case_of_savepoint_names() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    let mut tx = conn.begin().await?;
 
-    eprintln!("starting pool");
+    tx.savepoint("MixedCase").await?;
 
-    let pool = PgPoolOptions::new()
-        .acquire_timeout(Duration::from_secs(5))
-        .min_connections(1)
-        .max_connections(1)
-        .connect(&dotenvy::var("DATABASE_URL")?)
-        .await?;
+    // unquoted, the name would have been folded to lower case
+    tx.execute(r#"RELEASE SAVEPOINT "MixedCase""#).await?;
 
-    // spin up more tasks than connections available, and ensure we don't deadlock
-    for i in 0..200 {
-        let pool = pool.clone();
-        sqlx_rt::spawn(async move {
-            for j in 0.. {
-                if let Err(e) = sqlx::query("select 1 + 1").execute(&pool).await {
-                    // normal error at termination of the test
-                    if matches!(e, sqlx::Error::PoolClosed) {
-                        eprintln!("pool task {} exiting normally after {} iterations", i, j);
-                    } else {
-                        eprintln!("pool task {} dying due to {} after {} iterations", i, e, j);
-                    }
-                    break;
-                }
+    tx.rollback().await?;
 
-                // shouldn't be necessary if the pool is fair
-                // sqlx_rt::yield_now().await;
-            }
-        });
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_releases_the_savepoints_of_nested_transactions() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TABLE IF NOT EXISTS _sqlx_savepoints_4209 (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    conn.execute("TRUNCATE _sqlx_savepoints_4209").await?;
+
+    let insert = "INSERT INTO _sqlx_savepoints_4209 (id) VALUES ($1)";
+
+    let mut tx = conn.begin().await?;
+    let mut tx1 = tx.begin().await?;
+    let mut tx2 = tx1.begin().await?;
+    let mut tx3 = tx2.begin().await?;
+    let mut tx4 = tx3.begin().await?;
+
+    sqlx::query(insert).bind(1_i32).execute(&mut tx4).await?;
+
+    // the savepoints are released along with the next one, or by the end of the transaction
+    tx4.commit_with_savepoint_release().await?;
+
+    let mut tx4 = tx3.begin().await?;
+    sqlx::query(insert).bind(2_i32).execute(&mut tx4).await?;
+    tx4.commit_with_savepoint_release().await?;
+
+    // restores the savepoint established before both, which destroys the deferred one
+    tx3.rollback().await?;
+
+    sqlx::query(insert).bind(3_i32).execute(&mut tx2).await?;
+    tx2.commit_with_savepoint_release().await?;
+
+    let mut savepoint = tx1.savepoint("fourth").await?;
+    sqlx::query(insert)
+        .bind(4_i32)
+        .execute(&mut *savepoint)
+        .await?;
+    savepoint.release().await?;
+
+    tx1.commit().await?;
+    tx.commit().await?;
+
+    let ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM _sqlx_savepoints_4209 ORDER BY id")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(ids, vec![3, 4]);
+
+    // a savepoint can not be released in a failed transaction, which the commit reports
+    let mut tx = conn.begin().await?;
+    let mut tx1 = tx.begin().await?;
+
+    assert!(tx1.execute("SELECT 1 / 0").await.is_err());
+    assert!(tx1.commit_with_savepoint_release().await.is_err());
+
+    // the savepoint was then rolled back
+    let one: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut tx).await?;
+    assert_eq!(one, 1);
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_drop_multiple_transactions() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TABLE IF NOT EXISTS _sqlx_users_3952 (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    conn.execute("TRUNCATE _sqlx_users_3952").await?;
+
+    // begin .. (drop)
+
+    // run 2 times to see what happens if we drop transactions repeatedly
+    for _ in 0..2 {
+        {
+            let mut tx = conn.begin().await?;
+
+            // do actually something before dropping
+            let _user =
+                sqlx::query("INSERT INTO _sqlx_users_3952 (id) VALUES ($1) RETURNING id")
+                    .bind(20_i32)
+                    .fetch_one(&mut tx)
+                    .await?;
+        }
+
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM _sqlx_users_3952")
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(count, 0);
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_runs_a_closure_in_a_pool_transaction() -> anyhow::Result<()> {
+    let pool = pool::<Postgres>().await?;
+
+    pool.execute("CREATE TABLE IF NOT EXISTS _sqlx_users_4561 (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    pool.execute("TRUNCATE _sqlx_users_4561").await?;
+
+    // Ok(..) commits
+
+    let id: i32 = pool
+        .begin_with(|tx| {
+            Box::pin(async move {
+                sqlx::query_scalar("INSERT INTO _sqlx_users_4561 (id) VALUES ($1) RETURNING id")
+                    .bind(10_i32)
+                    .fetch_one(&mut *tx)
+                    .await
+            })
+        })
+        .await?;
+
+    assert_eq!(10_i32, id);
+
+    // Err(..) rolls back and is returned as-is
+
+    let res: Result<(), sqlx::Error> = pool
+        .begin_with(|tx| {
+            Box::pin(async move {
+                sqlx::query("INSERT INTO _sqlx_users_4561 (id) VALUES ($1)")
+                    .bind(20_i32)
+                    .execute(&mut *tx)
+                    .await?;
+
+                Err(sqlx::Error::RowNotFound)
+            })
+        })
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::RowNotFound)));
+
+    let ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM _sqlx_users_4561 ORDER BY id")
+        .fetch_all(&pool)
+        .await?;
+
+    assert_eq!(ids, vec![10]);
+
+    Ok(())
+}
+
+// run with `cargo test --features postgres -- --ignored --nocapture pool_smoke_test`
+#[ignore]
+#[sqlx_macros::test]
+async fn pool_smoke_test() -> anyhow::Result<()> {
+    use futures::{future, task::Poll, Future};
+
+    eprintln!("starting pool");
+
+    let pool = PgPoolOptions::new()
+        .acquire_timeout(Duration::from_secs(5))
+        .min_connections(1)
+        .max_connections(1)
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    // spin up more tasks than connections available, and ensure we don't deadlock
+    for i in 0..200 {
+        let pool = pool.clone();
+        sqlx_rt::spawn(async move {
+            for j in 0.. {
+                if let Err(e) = sqlx::query("select 1 + 1").execute(&pool).await {
+                    // normal error at termination of the test
+                    if matches!(e, sqlx::Error::PoolClosed) {
+                        eprintln!("pool task {} exiting normally after {} iterations", i, j);
+                    } else {
+                        eprintln!("pool task {} dying due to {} after {} iterations", i, e, j);
+                    }
+                    break;
+                }
+
+                // shouldn't be necessary if the pool is fair
+                // sqlx_rt::yield_now().await;
+            }
+        });
     }
 
     // spawn a bunch of tasks that attempt to acquire but give up to ensure correct handling
@@ -618,6 +1180,159 @@ async fn pool_smoke_test() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_reconnects_after_the_server_closes_an_idle_connection() -> anyhow::Result<()> {
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+        .fetch_one(&pool)
+        .await?;
+
+    // kill the pooled connection from the server side while it sits in the idle queue
+    let mut conn = new::<Postgres>().await?;
+    let terminated: bool = sqlx::query_scalar("SELECT pg_terminate_backend($1)")
+        .bind(pid)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(terminated);
+
+    // give the backend a moment to actually exit
+    sqlx_rt::sleep(Duration::from_millis(100)).await;
+
+    let mut conn = pool.acquire().await?;
+    let value: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    let new_pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 1);
+    assert_ne!(new_pid, pid);
+    assert_eq!(pool.size(), 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_keeps_the_connect_error_of_a_lazy_pool() -> anyhow::Result<()> {
+    // nothing listens on port 1, so every attempt is refused until `acquire_timeout`
+    let options = PgConnectOptions::new().host("127.0.0.1").port(1);
+
+    let pool = PgPoolOptions::new()
+        .acquire_timeout(Duration::from_secs(1))
+        .connect_lazy_with(options);
+
+    let err = pool.acquire().await.unwrap_err();
+
+    assert!(!matches!(err, sqlx::Error::PoolTimedOut), "{:?}", err);
+
+    let mut source: Option<&(dyn std::error::Error + 'static)> = Some(&err);
+    let mut refused = false;
+
+    while let Some(error) = source {
+        if let Some(error) = error.downcast_ref::<std::io::Error>() {
+            refused |= error.kind() == std::io::ErrorKind::ConnectionRefused;
+        }
+
+        source = error.source();
+    }
+
+    assert!(refused, "{:?}", err);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_preloads_statements_on_new_connections() -> anyhow::Result<()> {
+    use sqlx::Either;
+
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .preload_statements(["SELECT $1::int4 + 1", "SELECT $1"])
+        .connect(&env::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+
+    // both statements were prepared and cached before the connection was handed out
+    assert_eq!(2, conn.cached_statements_size());
+
+    let value: i32 = sqlx::query_scalar("SELECT $1::int4 + 1")
+        .bind(41_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 42);
+    assert_eq!(2, conn.cached_statements_size());
+
+    // the parameter of an uncast placeholder was inferred when preloading, and the cached
+    // statement keeps that type even when it is prepared again with another one
+    let statement = conn
+        .prepare_with("SELECT $1", &[sqlx::postgres::PgTypeInfo::with_name("INT4")])
+        .await?;
+
+    let parameters = match statement.parameters() {
+        Some(Either::Left(parameters)) => parameters,
+        other => panic!("expected the types of the parameters, got {:?}", other),
+    };
+
+    assert_eq!(parameters[0].name(), "TEXT");
+    assert_eq!(2, conn.cached_statements_size());
+
+    // a statement that cannot be prepared fails the pool straight away instead of
+    // retrying until `acquire_timeout`
+    let url = env::var("DATABASE_URL")?;
+    let res = PgPoolOptions::new()
+        .acquire_timeout(Duration::from_secs(30))
+        .preload_statements(["SELECT * FROM table_that_does_not_exist"])
+        .connect(&url);
+
+    let err = sqlx_rt::timeout(Duration::from_secs(10), res)
+        .await?
+        .expect_err("the statement should fail to prepare");
+
+    let err = err.into_database_error().expect("a database error");
+    assert_eq!(err.code().as_deref(), Some("42P01"));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_returns_the_raw_bytes_of_a_value() -> anyhow::Result<()> {
+    use sqlx::postgres::PgValueFormat;
+    use sqlx::ValueRef;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // prepared queries receive values in the binary format
+    let row = sqlx::query("SELECT 1234567::int4, NULL::int4")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let raw = row.try_get_raw(0)?;
+
+    assert!(!raw.is_null());
+    assert_eq!(raw.format(), PgValueFormat::Binary);
+    assert_eq!(
+        i32::from_be_bytes(raw.as_bytes().unwrap().try_into()?),
+        1234567
+    );
+
+    assert!(row.try_get_raw(1)?.is_null());
+
+    // simple queries receive them in the text format
+    let row = conn.fetch_one("SELECT 1234567::int4").await?;
+    let raw = row.try_get_raw(0)?;
+
+    assert_eq!(raw.format(), PgValueFormat::Text);
+    assert_eq!(raw.as_str().unwrap(), "1234567");
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn test_invalid_query() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -736,72 +1451,377 @@ async fn it_caches_statements() -> anyhow::Result<()> {
 }
 
 #[sqlx_macros::test]
-async fn it_closes_statement_from_cache_issue_470() -> anyhow::Result<()> {
+async fn it_connects_with_tcp_options() -> anyhow::Result<()> {
     sqlx_test::setup_if_needed();
 
-    let mut options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
 
-    // a capacity of 1 means that before each statement (after the first)
-    // we will close the previous statement
-    options = options.statement_cache_capacity(1);
+    // these are ignored if the URL points to a Unix domain socket
+    let options = options
+        .tcp_keepalive(Some(Duration::from_secs(60)))
+        .tcp_nodelay(true);
 
     let mut conn = PgConnection::connect_with(&options).await?;
 
-    for i in 0..5 {
-        let row = sqlx::query(&*format!("SELECT {}::int4 AS val", i))
-            .fetch_one(&mut conn)
-            .await?;
-
-        let val: i32 = row.get("val");
-
-        assert_eq!(i, val);
-    }
-
-    assert_eq!(1, conn.cached_statements_size());
+    conn.ping().await?;
+    conn.close().await?;
 
     Ok(())
 }
 
 #[sqlx_macros::test]
-async fn it_sets_application_name() -> anyhow::Result<()> {
+async fn it_executes_queries_without_prepared_statements() -> anyhow::Result<()> {
     sqlx_test::setup_if_needed();
 
-    let mut options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
-    options = options.application_name("some-name");
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    let mut conn = PgConnection::connect_with(&options.prepared_statements(false)).await?;
 
-    let mut conn = PgConnection::connect_with(&options).await?;
+    let bytes = vec![0_u8, b'\'', b'\\', 0xff];
 
-    let row = sqlx::query("select current_setting('application_name') as app_name")
+    let row = sqlx::query("SELECT $1::int4 AS id, $2::bytea AS bytes, $3::text AS text")
+        .bind(5_i32)
+        .bind(&bytes)
+        .bind("it's a \\ string")
         .fetch_one(&mut conn)
         .await?;
 
-    let val: String = row.get("app_name");
+    assert_eq!(row.columns()[1].name(), "bytes");
+    assert_eq!(row.try_get::<i32, _>("id")?, 5);
+    assert_eq!(row.try_get::<Vec<u8>, _>("bytes")?, bytes);
+    assert_eq!(row.try_get::<String, _>("text")?, "it's a \\ string");
 
-    assert_eq!("some-name", &val);
+    conn.execute("CREATE TEMPORARY TABLE blobs (id int4 NOT NULL, data bytea NOT NULL)")
+        .await?;
 
-    Ok(())
-}
+    for id in 1..=3 {
+        let done = sqlx::query("INSERT INTO blobs (id, data) VALUES ($1, $2)")
+            .bind(id)
+            .bind(&bytes)
+            .execute(&mut conn)
+            .await?;
 
-#[sqlx_macros::test]
-async fn it_can_handle_parameter_status_message_issue_484() -> anyhow::Result<()> {
-    new::<Postgres>().await?.execute("SET NAMES 'UTF8'").await?;
-    Ok(())
-}
+        assert_eq!(done.rows_affected(), 1);
+    }
 
-#[sqlx_macros::test]
-async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
-    let mut conn = new::<Postgres>().await?;
-    let mut tx = conn.begin().await?;
+    let ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM blobs WHERE data = $1 ORDER BY id")
+        .bind(&bytes)
+        .fetch_all(&mut conn)
+        .await?;
 
-    let tweet_id: i64 = sqlx::query_scalar(
-        "INSERT INTO tweet ( text ) VALUES ( 'Hello, World' ) RETURNING id",
-    )
-    .fetch_one(&mut tx)
-    .await?;
+    assert_eq!(ids, [1, 2, 3]);
 
-    let statement = tx.prepare("SELECT * FROM tweet WHERE id = $1").await?;
+    // a failed query leaves the connection usable
+    assert!(sqlx::query("SELECT $1::int4 / 0")
+        .bind(1_i32)
+        .fetch_one(&mut conn)
+        .await
+        .is_err());
 
-    assert_eq!(statement.column(0).name(), "id");
+    let value: i32 = sqlx::query_scalar("SELECT $1::int4 + 1")
+        .bind(1_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 2);
+
+    #[derive(sqlx::Type, Debug, PartialEq)]
+    #[sqlx(type_name = "status", rename_all = "lowercase", pg_array)]
+    enum Status {
+        New,
+        Open,
+        Closed,
+    }
+
+    // the type of the column is not known to the connection before the query is described
+    let statuses: Vec<Status> = sqlx::query_scalar("SELECT ARRAY['open', $1]::status[]")
+        .bind("closed")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(statuses, [Status::Open, Status::Closed]);
+
+    let prepared: i64 = sqlx::query_scalar("SELECT count(*) FROM pg_prepared_statements")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(prepared, 0);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_closes_statement_from_cache_issue_470() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let mut options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+
+    // a capacity of 1 means that before each statement (after the first)
+    // we will close the previous statement
+    options = options.statement_cache_capacity(1);
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    for i in 0..5 {
+        let row = sqlx::query(&*format!("SELECT {}::int4 AS val", i))
+            .fetch_one(&mut conn)
+            .await?;
+
+        let val: i32 = row.get("val");
+
+        assert_eq!(i, val);
+    }
+
+    assert_eq!(1, conn.cached_statements_size());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_saturates_statement_cache_at_capacity() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let mut options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    options = options.statement_cache_capacity(3);
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    for i in 0..10 {
+        let val: i32 = sqlx::query_scalar(&*format!("SELECT {}::int4", i))
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(i, val);
+        assert_eq!((i as usize + 1).min(3), conn.cached_statements_size());
+    }
+
+    conn.clear_cached_statements().await?;
+    assert_eq!(0, conn.cached_statements_size());
+
+    // the connection is still usable after the statements were closed
+    let val: i32 = sqlx::query_scalar("SELECT 1::int4")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(1, val);
+    assert_eq!(1, conn.cached_statements_size());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_times_out_statements() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let mut options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    options = options.statement_timeout(Duration::from_secs(1));
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    let res = conn.execute("SELECT pg_sleep(10)").await;
+    let err = res.unwrap_err().into_database_error().unwrap();
+
+    assert_eq!(err.code().as_deref(), Some("57014"));
+
+    // the connection is safe for reuse
+    let val: i32 = sqlx::query_scalar("SELECT 1::int4")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(val, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_runs_pool_lifecycle_hooks_on_the_driver_connection() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let pool = PgPoolOptions::new()
+        .max_connections(3)
+        .after_connect(|conn, _meta| {
+            Box::pin(async move {
+                conn.execute("SET search_path TO pg_catalog, public").await?;
+                conn.execute("SET sqlx.hook_test = 'connected'").await?;
+
+                Ok(())
+            })
+        })
+        .before_acquire(|conn, _meta| {
+            Box::pin(async move {
+                // discard connections that were marked as unusable
+                let state: String = sqlx::query_scalar("SELECT current_setting('sqlx.hook_test')")
+                    .fetch_one(conn)
+                    .await?;
+
+                Ok(state != "poisoned")
+            })
+        })
+        .after_release(|_conn, _meta| Box::pin(async move { Ok(true) }))
+        .connect(&env::var("DATABASE_URL")?)
+        .await?;
+
+    // hold several connections at once so that more than one is opened
+    let mut conns = Vec::new();
+    for _ in 0..3 {
+        conns.push(pool.acquire().await?);
+    }
+
+    for conn in &mut conns {
+        let search_path: String = sqlx::query_scalar("SHOW search_path")
+            .fetch_one(&mut **conn)
+            .await?;
+        let state: String = sqlx::query_scalar("SELECT current_setting('sqlx.hook_test')")
+            .fetch_one(&mut **conn)
+            .await?;
+
+        assert_eq!(search_path, "pg_catalog, public");
+        assert_eq!(state, "connected");
+    }
+
+    // poison every connection; `before_acquire` should transparently replace them
+    for conn in &mut conns {
+        conn.execute("SET sqlx.hook_test = 'poisoned'").await?;
+    }
+
+    drop(conns);
+
+    for _ in 0..3 {
+        let mut conn = pool.acquire().await?;
+        let state: String = sqlx::query_scalar("SELECT current_setting('sqlx.hook_test')")
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(state, "connected");
+    }
+
+    pool.close().await;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_binds_slices_to_any() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE names (id INT4 NOT NULL, name TEXT NOT NULL);
+INSERT INTO names (id, name) VALUES (1, 'a'), (2, 'b'), (3, 'c');
+        "#,
+    )
+    .await?;
+
+    let ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM names WHERE name = ANY($1) ORDER BY id")
+        .bind(&["a", "b"][..])
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(ids, vec![1, 2]);
+
+    let names = vec!["b".to_owned(), "c".to_owned()];
+    let ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM names WHERE name = ANY($1) ORDER BY id")
+        .bind(&names)
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(ids, vec![2, 3]);
+
+    // an empty slice is still a valid (empty) array
+    let empty: &[String] = &[];
+    let ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM names WHERE name = ANY($1)")
+        .bind(empty)
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert!(ids.is_empty());
+
+    #[cfg(feature = "uuid")]
+    {
+        use sqlx::types::Uuid;
+
+        let uuids = [Uuid::from_u128(1), Uuid::from_u128(2)];
+        let matched: bool = sqlx::query_scalar("SELECT $1 = ANY($2)")
+            .bind(uuids[1])
+            .bind(&uuids[..])
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert!(matched);
+    }
+
+    #[cfg(feature = "chrono")]
+    {
+        use sqlx::types::chrono::NaiveDate;
+
+        let dates = vec![
+            NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
+        ];
+        let matched: bool = sqlx::query_scalar("SELECT '2021-01-01'::date = ANY($1)")
+            .bind(&dates)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert!(matched);
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    {
+        use sqlx::types::BigDecimal;
+        use std::str::FromStr;
+
+        let values = vec![BigDecimal::from_str("1.5")?, BigDecimal::from_str("2.25")?];
+        let matched: bool = sqlx::query_scalar("SELECT 2.25::numeric = ANY($1)")
+            .bind(&values)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert!(matched);
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_sets_application_name() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let mut options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    options = options.application_name("some-name");
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    let row = sqlx::query("select current_setting('application_name') as app_name")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let val: String = row.get("app_name");
+
+    assert_eq!("some-name", &val);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_handle_parameter_status_message_issue_484() -> anyhow::Result<()> {
+    new::<Postgres>().await?.execute("SET NAMES 'UTF8'").await?;
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    let mut tx = conn.begin().await?;
+
+    let tweet_id: i64 = sqlx::query_scalar(
+        "INSERT INTO tweet ( text ) VALUES ( 'Hello, World' ) RETURNING id",
+    )
+    .fetch_one(&mut tx)
+    .await?;
+
+    let statement = tx.prepare("SELECT * FROM tweet WHERE id = $1").await?;
+
+    assert_eq!(statement.column(0).name(), "id");
     assert_eq!(statement.column(1).name(), "created_at");
     assert_eq!(statement.column(2).name(), "text");
     assert_eq!(statement.column(3).name(), "owner_id");
@@ -819,6 +1839,33 @@ async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_describes_the_parameters_of_a_statement() -> anyhow::Result<()> {
+    use sqlx::Either;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let statement = conn.prepare("SELECT $1::int AS a, $2::text AS b").await?;
+
+    let parameters = match statement.parameters() {
+        Some(Either::Left(parameters)) => parameters,
+        other => panic!("expected the types of the parameters, got {:?}", other),
+    };
+
+    let names: Vec<&str> = parameters.iter().map(|ty| ty.name()).collect();
+    assert_eq!(names, ["INT4", "TEXT"]);
+
+    let columns: Vec<(&str, &str)> = statement
+        .columns()
+        .iter()
+        .map(|col| (col.name(), col.type_info().name()))
+        .collect();
+
+    assert_eq!(columns, [("a", "INT4"), ("b", "TEXT")]);
+
+    Ok(())
+}
+
 // repro is more reliable with the basic scheduler used by `#[tokio::test]`
 #[cfg(feature = "_rt-tokio")]
 #[tokio::test]
@@ -1014,6 +2061,31 @@ async fn test_listener_cleanup() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_listener_receives_payload() -> anyhow::Result<()> {
+    setup_if_needed();
+
+    let mut notify_conn = new::<Postgres>().await?;
+    let notify_pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+        .fetch_one(&mut notify_conn)
+        .await?;
+
+    let mut listener = PgListener::connect(&env::var("DATABASE_URL")?).await?;
+    listener.listen("payload_channel").await?;
+
+    notify_conn.execute("NOTIFY payload_channel, 'hi'").await?;
+
+    let notification = listener.recv().await?;
+
+    assert_eq!(notification.channel(), "payload_channel");
+    assert_eq!(notification.payload(), "hi");
+    assert_eq!(notification.process_id(), notify_pid as u32);
+
+    listener.unlisten("payload_channel").await?;
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn test_pg_listener_allows_pool_to_close() -> anyhow::Result<()> {
     let pool = pool::<Postgres>().await?;
@@ -1444,6 +2516,49 @@ async fn it_can_copy_in() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_can_copy_in_with_sink() -> anyhow::Result<()> {
+    use bytes::Bytes;
+    use futures::SinkExt;
+
+    let mut conn = new::<Postgres>().await?;
+    conn.execute(
+        r#"
+        CREATE TEMPORARY TABLE users (id INTEGER NOT NULL);
+    "#,
+    )
+    .await?;
+
+    let mut copy = conn
+        .copy_in_raw(
+            r#"
+        COPY users (id) FROM STDIN WITH (FORMAT CSV);
+    "#,
+        )
+        .await?;
+
+    // 1,000 chunks of 1,000 rows each, produced lazily
+    let mut chunks = futures::stream::iter(0..1_000).map(|chunk| {
+        let rows: String = (0..1_000)
+            .map(|row| format!("{}\n", chunk * 1_000 + row))
+            .collect();
+
+        Ok::<_, sqlx::Error>(Bytes::from(rows))
+    });
+
+    copy.send_all(&mut chunks).await?;
+    let rows = copy.finish().await?;
+    assert_eq!(rows, 1_000_000);
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 1_000_000);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_abort_copy_in() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -1741,3 +2856,831 @@ async fn test_postgres_bytea_hex_deserialization_errors() -> anyhow::Result<()>
     }
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_fetches_rows_in_batches() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let values: Vec<i32> = sqlx::query_scalar::<_, i32>("SELECT generate_series(1, $1)")
+        .bind(10_000_i32)
+        .fetch_with_size(&mut conn, 1000)
+        .try_collect()
+        .await?;
+
+    assert_eq!(values, (1..=10_000).collect::<Vec<_>>());
+
+    // the server only computes the rows of a batch once it is requested, which another
+    // connection can see from the sequence the rows are numbered by
+    conn.execute("DROP SEQUENCE IF EXISTS _sqlx_batches_1401; CREATE SEQUENCE _sqlx_batches_1401")
+        .await?;
+
+    let mut observer = new::<Postgres>().await?;
+    let last_value = "SELECT last_value FROM _sqlx_batches_1401";
+
+    {
+        let mut s = sqlx::query_scalar::<_, i64>(
+            "SELECT nextval('_sqlx_batches_1401') FROM generate_series(1, 100)",
+        )
+        .fetch_with_size(&mut conn, 10);
+
+        assert_eq!(s.try_next().await?, Some(1));
+
+        let n: i64 = sqlx::query_scalar(last_value)
+            .fetch_one(&mut observer)
+            .await?;
+        assert_eq!(n, 10);
+
+        for i in 2..=11 {
+            assert_eq!(s.try_next().await?, Some(i));
+        }
+
+        let n: i64 = sqlx::query_scalar(last_value)
+            .fetch_one(&mut observer)
+            .await?;
+        assert_eq!(n, 20);
+    }
+
+    conn.execute("DROP SEQUENCE _sqlx_batches_1401").await?;
+
+    // a batch size that does not divide the number of rows
+    let rows: Vec<(i32, String)> =
+        sqlx::query_as("SELECT n, n::text FROM generate_series(1, $1) AS n")
+            .bind(25_i32)
+            .fetch_with_size(&mut conn, 7)
+            .try_collect()
+            .await?;
+
+    assert_eq!(rows.len(), 25);
+    assert_eq!(rows[24], (25, "25".to_owned()));
+
+    // the connection can be used again after dropping a partially read stream
+    {
+        let mut s = sqlx::query("SELECT generate_series(1, 10000)").fetch_with_size(&mut conn, 100);
+
+        for i in 1..=150 {
+            let row = s.try_next().await?.unwrap();
+            assert_eq!(row.get::<i32, _>(0), i);
+        }
+    }
+
+    let value: i32 = sqlx::query_scalar("SELECT 1 + 1")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 2);
+
+    // errors raised after the first batch are still reported
+    let res: Result<Vec<i32>, _> =
+        sqlx::query_scalar::<_, i32>("SELECT 10 / (10 - n) FROM generate_series(1, 20) AS n")
+            .persistent(true)
+            .fetch_with_size(&mut conn, 5)
+            .try_collect()
+            .await;
+
+    assert!(res.is_err());
+
+    let value: i32 = sqlx::query_scalar("SELECT 3").fetch_one(&mut conn).await?;
+
+    assert_eq!(value, 3);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_returns_ids_for_unnest_inserts_in_order() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE unnest_insert (
+    id BIGSERIAL PRIMARY KEY,
+    position INT NOT NULL,
+    label TEXT NOT NULL
+);
+        "#,
+    )
+    .await?;
+
+    // interleave the positions so the table's own order can't line things up by accident
+    let positions: Vec<i32> = (0..1000).map(|i| (i * 7919) % 1000).collect();
+    let labels: Vec<String> = positions.iter().map(|p| format!("row {}", p)).collect();
+
+    let inserted: Vec<(i64, i32, String)> = sqlx::query_as(
+        r#"
+WITH input AS (
+    SELECT * FROM UNNEST($1::int4[], $2::text[]) WITH ORDINALITY AS t(position, label, n)
+), inserted AS (
+    INSERT INTO unnest_insert (position, label)
+    SELECT position, label FROM input
+    RETURNING id, position, label
+)
+-- `RETURNING` does not report the rows in any particular order, so join them back to the
+-- position of their input by a unique key
+SELECT inserted.id, inserted.position, inserted.label
+FROM inserted JOIN input USING (position)
+ORDER BY input.n
+        "#,
+    )
+    .bind(&positions)
+    .bind(&labels)
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(inserted.len(), 1000);
+
+    for (i, (_, position, label)) in inserted.iter().enumerate() {
+        assert_eq!(*position, positions[i]);
+        assert_eq!(*label, labels[i]);
+    }
+
+    // each returned id is that of the row created from the input at the same position
+    let stored: Vec<(i64, i32)> =
+        sqlx::query_as("SELECT id, position FROM unnest_insert ORDER BY id")
+            .fetch_all(&mut conn)
+            .await?;
+
+    let mut returned: Vec<(i64, i32)> = inserted.into_iter().map(|(id, p, _)| (id, p)).collect();
+    returned.sort_unstable();
+
+    assert_eq!(stored, returned);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_tells_inserts_from_updates_in_upserts() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE upsert (
+    id INT PRIMARY KEY,
+    label TEXT NOT NULL
+);
+        "#,
+    )
+    .await?;
+
+    let upsert = |label: &'static str| {
+        sqlx::query_as::<_, (bool, String)>(
+            r#"
+INSERT INTO upsert (id, label)
+VALUES (1, $1)
+ON CONFLICT (id) DO UPDATE SET label = EXCLUDED.label
+RETURNING (xmax = 0) AS inserted, label
+            "#,
+        )
+        .bind(label)
+    };
+
+    assert_eq!(
+        upsert("first").fetch_one(&mut conn).await?,
+        (true, "first".to_owned())
+    );
+    assert_eq!(
+        upsert("second").fetch_one(&mut conn).await?,
+        (false, "second".to_owned())
+    );
+
+    let insert_new = |id: i32| {
+        sqlx::query_scalar::<_, bool>(
+            r#"
+INSERT INTO upsert (id, label)
+VALUES ($1, 'new')
+ON CONFLICT (id) DO NOTHING
+RETURNING (xmax = 0) AS inserted
+            "#,
+        )
+        .bind(id)
+    };
+
+    // a conflicting row is neither inserted nor returned
+    assert_eq!(insert_new(1).fetch_optional(&mut conn).await?, None);
+    assert_eq!(insert_new(2).fetch_optional(&mut conn).await?, Some(true));
+
+    let result = conn.execute(insert_new(2)).await?;
+    assert_eq!(result.rows_affected(), 0);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_cancels_a_running_query_from_another_task() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let cancel = conn.cancel_handle();
+    let (tx, rx) = futures::channel::oneshot::channel();
+
+    sqlx_rt::spawn(async move {
+        // give the query time to start
+        sqlx_rt::sleep(Duration::from_millis(500)).await;
+
+        let _ = tx.send(cancel.cancel().await);
+    });
+
+    let start = std::time::Instant::now();
+    let res = conn.execute("SELECT pg_sleep(30)").await;
+
+    rx.await??;
+
+    let err = res.expect_err("the query should have been cancelled");
+    let err = err
+        .as_database_error()
+        .expect("expected a database error")
+        .downcast_ref::<PgDatabaseError>();
+
+    // query_canceled
+    assert_eq!(err.code(), "57014");
+    assert!(start.elapsed() < Duration::from_secs(10));
+
+    // the connection is still usable afterwards
+    let value: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(value, 1);
+
+    Ok(())
+}
+
+#[cfg(feature = "tracing")]
+#[sqlx_macros::test]
+async fn it_emits_tracing_spans_for_queries() -> anyhow::Result<()> {
+    use sqlx::ConnectOptions;
+    use std::sync::Mutex;
+    use tracing_::field::{Field, Visit};
+    use tracing_::span::{Attributes, Id, Record};
+    use tracing_::{Event, Level, Metadata, Subscriber};
+
+    type Fields = Vec<(String, String)>;
+
+    #[derive(Default)]
+    struct Recorded {
+        spans: Mutex<Vec<(String, Fields)>>,
+        events: Mutex<Vec<(Level, Option<u64>)>>,
+        entered: Mutex<Vec<u64>>,
+    }
+
+    struct Recorder(Arc<Recorded>);
+
+    struct Visitor<'a>(&'a mut Fields);
+
+    impl Visit for Visitor<'_> {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.push((field.name().to_owned(), value.to_owned()));
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .push((field.name().to_owned(), format!("{:?}", value)));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+            let mut fields = Fields::new();
+            attrs.record(&mut Visitor(&mut fields));
+
+            let mut spans = self.0.spans.lock().unwrap();
+            spans.push((attrs.metadata().name().to_owned(), fields));
+
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            let mut spans = self.0.spans.lock().unwrap();
+            values.record(&mut Visitor(&mut spans[span.into_u64() as usize - 1].1));
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let parent = event.parent().map(Id::into_u64);
+            let level = *event.metadata().level();
+
+            self.0.events.lock().unwrap().push((level, parent));
+        }
+
+        fn enter(&self, span: &Id) {
+            self.0.entered.lock().unwrap().push(span.into_u64());
+        }
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    fn field<'a>(fields: &'a Fields, name: &str) -> Option<&'a str> {
+        fields
+            .iter()
+            .rev()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| &**value)
+    }
+
+    let recorded = Arc::new(Recorded::default());
+    let _guard = tracing_::subscriber::set_default(Recorder(Arc::clone(&recorded)));
+
+    let mut options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    options.log_slow_statements(log::LevelFilter::Warn, Duration::from_millis(100));
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    // `fetch_one` would stop before the server reports the number of rows affected
+    let _: Vec<(String,)> = sqlx::query_as("SELECT $1::text")
+        .bind("super secret")
+        .fetch_all(&mut conn)
+        .await?;
+
+    {
+        let spans = recorded.spans.lock().unwrap();
+        let (index, (name, fields)) = spans
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, (_, fields))| field(fields, "db.statement") == Some("SELECT $1::text"))
+            .expect("no span was recorded for the statement");
+
+        // the results of the statement are received within its span
+        let id = index as u64 + 1;
+        assert!(recorded.entered.lock().unwrap().contains(&id));
+
+        assert_eq!(name, "sqlx.query");
+        assert_eq!(field(fields, "db.system"), Some("postgresql"));
+        assert_eq!(field(fields, "db.params"), Some("[redacted]"));
+        assert_eq!(field(fields, "db.rows_affected"), Some("1"));
+        assert_eq!(field(fields, "db.rows_returned"), Some("1"));
+        assert!(field(fields, "elapsed_ms").is_some());
+    }
+
+    // statements over the slow threshold are reported at WARN
+    conn.execute("SELECT pg_sleep(0.2)").await?;
+
+    let slow_span = recorded
+        .spans
+        .lock()
+        .unwrap()
+        .iter()
+        .rposition(|(_, fields)| field(fields, "db.statement") == Some("SELECT pg_sleep(0.2)"))
+        .expect("no span was recorded for the slow statement") as u64
+        + 1;
+
+    assert!(recorded
+        .events
+        .lock()
+        .unwrap()
+        .contains(&(Level::WARN, Some(slow_span))));
+
+    conn.close().await?;
+
+    // parameter values are only included when asked for
+    options.trace_params(true);
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    let _: (i32,) = sqlx::query_as("SELECT $1::int4 + 1")
+        .bind(41_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    {
+        let spans = recorded.spans.lock().unwrap();
+        let (_, fields) = spans
+            .iter()
+            .rev()
+            .find(|(_, fields)| field(fields, "db.statement") == Some("SELECT $1::int4 + 1"))
+            .expect("no span was recorded for the statement");
+
+        let params = field(fields, "db.params").unwrap();
+
+        assert!(params.contains("Int4"), "{}", params);
+        assert!(params.contains("[0, 0, 0, 41]"), "{}", params);
+    }
+
+    conn.close().await?;
+
+    // the encoded arguments can't be masked by index, so all of them are redacted
+    options.redact_parameters(|index| index == 1);
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    let _: (String,) = sqlx::query_as("SELECT $1::text || $2::text")
+        .bind("alice")
+        .bind("hunter2")
+        .fetch_one(&mut conn)
+        .await?;
+
+    {
+        let spans = recorded.spans.lock().unwrap();
+        let (_, fields) = spans
+            .iter()
+            .rev()
+            .find(|(_, fields)| {
+                field(fields, "db.statement") == Some("SELECT $1::text || $2::text")
+            })
+            .expect("no span was recorded for the statement");
+
+        assert_eq!(field(fields, "db.params"), Some("[redacted]"));
+    }
+
+    conn.close().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fetches_scalars() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let n = sqlx::query_scalar::<_, i64>("SELECT 42::int8")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(n, 42);
+
+    let sum: i32 = sqlx::query_scalar("SELECT $1 + $2")
+        .bind(20_i32)
+        .bind(22_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(sum, 42);
+
+    // a query without any columns reports an error instead of panicking
+    let err = sqlx::query_scalar::<_, i32>("SELECT")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            err,
+            sqlx::Error::ColumnIndexOutOfBounds { index: 0, len: 0 }
+        ),
+        "{:?}",
+        err
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_passes_notices_to_the_handler() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let notices = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    let options = options.on_notice({
+        let notices = Arc::clone(&notices);
+
+        move |notice: PgDatabaseError| {
+            notices.lock().unwrap().push((
+                notice.severity(),
+                notice.message().to_owned(),
+                notice.detail().map(ToOwned::to_owned),
+            ));
+        }
+    });
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    conn.execute("SET client_min_messages TO notice").await?;
+    conn.execute(
+        "DO $$ BEGIN RAISE NOTICE 'hi'; RAISE WARNING 'careful' USING DETAIL = 'some detail'; END $$",
+    )
+    .await?;
+
+    assert_eq!(
+        *notices.lock().unwrap(),
+        [
+            (PgSeverity::Notice, "hi".to_owned(), None),
+            (
+                PgSeverity::Warning,
+                "careful".to_owned(),
+                Some("some detail".to_owned())
+            ),
+        ]
+    );
+
+    conn.close().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_executes_a_bulk_insert_from_query_builder() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE bulk_5062 (id INT4 PRIMARY KEY, name TEXT NOT NULL)")
+        .await?;
+
+    let mut builder = sqlx::QueryBuilder::<Postgres>::new("INSERT INTO bulk_5062 (id, name) ");
+
+    builder.push_values(0..500, |mut b, i| {
+        // a value that would break the query if it were pushed as SQL
+        b.push_bind(i)
+            .push_bind(format!("'); DROP TABLE bulk_5062; --{}", i));
+    });
+
+    let done = builder.build().execute(&mut conn).await?;
+
+    assert_eq!(done.rows_affected(), 500);
+
+    // a dynamic `IN (..)` list, numbered after the placeholders already pushed
+    let mut builder =
+        sqlx::QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM bulk_5062 WHERE id >= ");
+    builder.push_bind(100).push(" AND id IN (");
+
+    let mut ids = builder.separated(", ");
+    for id in [5, 150, 250, 499, 500] {
+        ids.push_bind(id);
+    }
+    ids.push_unseparated(")");
+
+    assert!(builder
+        .sql()
+        .ends_with("id >= $1 AND id IN ($2, $3, $4, $5, $6)"));
+
+    let count: i64 = builder.build_query_scalar().fetch_one(&mut conn).await?;
+    assert_eq!(count, 3);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_begins_transactions_with_options() -> anyhow::Result<()> {
+    use sqlx::{IsolationLevel, TransactionOptions};
+
+    let pool = pool::<Postgres>().await?;
+
+    pool.execute("DROP TABLE IF EXISTS tx_options_5063; CREATE TABLE tx_options_5063 (value INT4)")
+        .await?;
+
+    let serializable = TransactionOptions::new().isolation_level(IsolationLevel::Serializable);
+
+    let mut tx1 = pool.begin_with_options(serializable).await?;
+    let mut tx2 = pool.begin_with_options(serializable).await?;
+
+    let level: String = sqlx::query_scalar("SHOW transaction_isolation")
+        .fetch_one(&mut tx1)
+        .await?;
+    assert_eq!(level, "serializable");
+
+    // each transaction inserts the number of rows it saw, which could not happen if they
+    // were executed one after the other
+    for tx in [&mut tx1, &mut tx2] {
+        sqlx::query("INSERT INTO tx_options_5063 SELECT COUNT(*) FROM tx_options_5063")
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    tx1.commit().await?;
+
+    let err = tx2
+        .commit()
+        .await
+        .expect_err("expected a serialization failure");
+
+    assert_eq!(
+        err.into_database_error().unwrap().code().as_deref(),
+        Some("40001")
+    );
+
+    // a read-only transaction refuses to write
+    let mut tx = pool
+        .begin_with_options(TransactionOptions::new().read_only(true))
+        .await?;
+
+    let err = sqlx::query("INSERT INTO tx_options_5063 VALUES (1)")
+        .execute(&mut tx)
+        .await
+        .expect_err("expected the insert to fail");
+
+    assert_eq!(
+        err.into_database_error().unwrap().code().as_deref(),
+        Some("25006")
+    );
+
+    tx.rollback().await?;
+
+    // the options cannot be applied to a savepoint
+    let mut conn = pool.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let err = tx
+        .begin_with_options(serializable)
+        .await
+        .expect_err("expected the options to be rejected");
+
+    assert!(matches!(err, sqlx::Error::Savepoint(_)), "{:?}", err);
+
+    tx.rollback().await?;
+
+    pool.execute("DROP TABLE tx_options_5063").await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_authenticates_with_channel_binding() -> anyhow::Result<()> {
+    setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    let tls = options.clone().ssl_mode(PgSslMode::Require);
+
+    // channel binding is only possible over TLS
+    let mut conn = match PgConnection::connect_with(&tls).await {
+        Ok(conn) => conn,
+        Err(sqlx::Error::Tls(_)) => return Ok(()),
+        Err(error) => return Err(error.into()),
+    };
+
+    let encrypted: bool =
+        sqlx::query_scalar("SELECT ssl FROM pg_stat_ssl WHERE pid = pg_backend_pid()")
+            .fetch_one(&mut conn)
+            .await?;
+
+    // SCRAM-SHA-256-PLUS is only offered by Postgres 11+
+    if !encrypted || matches!(conn.server_version_num(), Some(version) if version < 110000) {
+        return Ok(());
+    }
+
+    // connecting with channel binding required only succeeds if the server authenticated
+    // with SCRAM-SHA-256-PLUS
+    let mut conn =
+        PgConnection::connect_with(&tls.clone().channel_binding(PgChannelBinding::Require)).await?;
+
+    let value: i32 = sqlx::query_scalar("SELECT 1 + 1")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(value, 2);
+
+    // without channel binding, the server can still authenticate the client
+    let conn = PgConnection::connect_with(&tls.channel_binding(PgChannelBinding::Disable)).await?;
+    conn.close().await?;
+
+    // ...but it must not when it is required and the connection is not encrypted
+    let error = PgConnection::connect_with(
+        &options
+            .ssl_mode(PgSslMode::Disable)
+            .channel_binding(PgChannelBinding::Require),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, sqlx::Error::Tls(_)), "{:?}", error);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_shrinks_buffers_after_release() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .after_release(|conn, _meta| {
+            Box::pin(async move {
+                conn.shrink_buffers();
+
+                Ok(true)
+            })
+        })
+        .connect(&env::var("DATABASE_URL")?)
+        .await?;
+
+    // grow the buffers of the connection by sending and receiving a large value
+    let large = "x".repeat(1 << 20);
+
+    let echoed: String = sqlx::query_scalar("SELECT $1::text")
+        .bind(&large)
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(echoed, large);
+
+    // the connection keeps working once its buffers were shrunk
+    let mut conn = pool.acquire().await?;
+
+    let value: i32 = sqlx::query_scalar("SELECT 1 + 1")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(value, 2);
+
+    let echoed: String = sqlx::query_scalar("SELECT $1::text")
+        .bind(&large)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(echoed.len(), large.len());
+
+    conn.shrink_buffers();
+
+    let value: i32 = sqlx::query_scalar("SELECT 1 + 1")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(value, 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_executes_queries_on_a_pool_in_an_arc() -> anyhow::Result<()> {
+    let pool = Arc::new(pool::<Postgres>().await?);
+
+    let mut handles = vec![];
+
+    for i in 0..2_i64 {
+        let pool = Arc::clone(&pool);
+
+        handles.push(sqlx_rt::spawn(async move {
+            let value: i64 = sqlx::query_scalar("SELECT $1::int8 + 1")
+                .bind(i)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+
+            assert_eq!(value, i + 1);
+
+            // the stream may borrow the `Arc` rather than the pool it holds
+            let values: Vec<i32> = sqlx::query_scalar::<_, i32>("SELECT generate_series(1, 10)")
+                .fetch(&pool)
+                .try_collect()
+                .await
+                .unwrap();
+
+            assert_eq!(values, (1..=10).collect::<Vec<_>>());
+        }));
+    }
+
+    for handle in handles {
+        #[cfg(any(feature = "_rt-tokio", feature = "_rt-actix"))]
+        handle.await.unwrap();
+
+        #[cfg(feature = "_rt-async-std")]
+        handle.await;
+    }
+
+    let row = sqlx::query("SELECT 1").fetch_optional(&pool).await?;
+    assert!(row.is_some());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reports_the_tls_session() -> anyhow::Result<()> {
+    setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+
+    let conn = PgConnection::connect_with(&options.clone().ssl_mode(PgSslMode::Disable)).await?;
+    assert_eq!(conn.tls_info(), None);
+    conn.close().await?;
+
+    let mut conn = match PgConnection::connect_with(&options.ssl_mode(PgSslMode::Require)).await {
+        Ok(conn) => conn,
+        Err(sqlx::Error::Tls(_)) => return Ok(()),
+        Err(error) => return Err(error.into()),
+    };
+
+    let version: Option<String> =
+        sqlx::query_scalar("SELECT version FROM pg_stat_ssl WHERE pid = pg_backend_pid()")
+            .fetch_one(&mut conn)
+            .await?;
+
+    let info = conn.tls_info().expect("the connection should be encrypted");
+    assert!(info.peer_certificate_der.is_some());
+
+    // only rustls reports the parameters of the session
+    if cfg!(any(
+        feature = "runtime-actix-rustls",
+        feature = "runtime-tokio-rustls",
+        feature = "runtime-async-std-rustls"
+    )) {
+        // e.g. `TLSv1.3` for `TLSv1_3`
+        assert_eq!(info.protocol_version.map(|v| v.replace('_', ".")), version);
+        assert!(info.cipher_suite.is_some());
+    }
+
+    Ok(())
+}
+
+sqlx_test::test_error_kinds!(
+    Postgres,
+    r#"
+CREATE TEMPORARY TABLE error_kind_parents (id int4 PRIMARY KEY);
+CREATE TEMPORARY TABLE error_kind_children (
+    id int4 PRIMARY KEY,
+    parent_id int4 REFERENCES error_kind_parents (id),
+    name text NOT NULL,
+    age int4 CHECK (age >= 0)
+);
+    "#,
+    [
+        (
+            "INSERT INTO error_kind_children (id, name, age) VALUES (1, 'a', -1)",
+            CheckViolation
+        ),
+        ("SELECT 1 / 0", Other),
+    ]
+);
@@ -1,6 +1,12 @@
 -- https://www.postgresql.org/docs/current/ltree.html
 CREATE EXTENSION IF NOT EXISTS ltree;
 
+-- https://www.postgresql.org/docs/current/hstore.html
+CREATE EXTENSION IF NOT EXISTS hstore;
+
+-- https://www.postgresql.org/docs/current/citext.html
+CREATE EXTENSION IF NOT EXISTS citext;
+
 -- https://www.postgresql.org/docs/current/sql-createtype.html
 CREATE TYPE status AS ENUM ('new', 'open', 'closed');
 
@@ -12,6 +18,18 @@ CREATE TYPE inventory_item AS
     price       BIGINT
 );
 
+CREATE TYPE float_point AS
+(
+    x FLOAT8,
+    y FLOAT8
+);
+
+CREATE TYPE address AS
+(
+    street TEXT,
+    unit   INT
+);
+
 -- https://github.com/prisma/database-schema-examples/tree/master/postgres/basic-twitter#basic-twitter
 CREATE TABLE tweet
 (
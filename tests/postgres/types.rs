@@ -2,9 +2,15 @@ extern crate time_ as time;
 
 use std::ops::Bound;
 
-use sqlx::postgres::types::{Oid, PgInterval, PgMoney, PgRange};
+use sqlx::postgres::types::{
+    Oid, PgBox, PgCircle, PgInterval, PgLine, PgMoney, PgPoint, PgRange, PgTsLexeme, PgTsPosition,
+    PgTsVector, PgTsWeight,
+};
 use sqlx::postgres::Postgres;
-use sqlx_test::{test_decode_type, test_prepared_type, test_type};
+use sqlx_test::{
+    test_decode_type, test_prepared_decode_type, test_prepared_type, test_type,
+    test_unprepared_type,
+};
 use std::str::FromStr;
 
 test_type!(null<Option<i16>>(Postgres,
@@ -57,6 +63,35 @@ test_type!(byte_array<[u8; 4]>(Postgres,
     "E'\\\\xDEADBEEF'::bytea" == [0xDE_u8, 0xAD, 0xBE, 0xEF],
 ));
 
+#[sqlx_macros::test]
+async fn it_rejects_byte_arrays_of_the_wrong_length() -> anyhow::Result<()> {
+    use sqlx::{Executor, Row};
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let bytes: [u8; 16] = sqlx::query_scalar("SELECT $1::bytea")
+        .bind([7_u8; 16])
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(bytes, [7_u8; 16]);
+
+    let res = sqlx::query_scalar::<_, [u8; 8]>("SELECT $1::bytea")
+        .bind([7_u8; 16])
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    // simple queries decode from the text format
+    let row = conn.fetch_one("SELECT '\\x0001'::bytea").await?;
+
+    assert_eq!(row.try_get::<[u8; 2], _>(0)?, [0, 1]);
+    assert!(row.try_get::<[u8; 8], _>(0).is_err());
+
+    Ok(())
+}
+
 test_type!(str<&str>(Postgres,
     "'this is foo'" == "this is foo",
     "''" == "",
@@ -80,6 +115,12 @@ test_type!(string_vec<Vec<String>>(Postgres,
         == vec!["Hello, World", "", "Goodbye"]
 ));
 
+// the string "NULL" is quoted in the text format, unlike NULL itself
+test_type!(string_vec_with_nulls<Vec<Option<String>>>(Postgres,
+    "array['NULL', NULL, 'null']::text[]"
+        == vec![Some("NULL".to_owned()), None, Some("null".to_owned())],
+));
+
 test_type!(string_array<[String; 3]>(Postgres,
     "array['one','two','three']::text[]" == ["one","two","three"],
 ));
@@ -88,10 +129,94 @@ test_type!(i8(
     Postgres,
     "0::\"char\"" == 0_i8,
     "120::\"char\"" == 120_i8,
+    "(-56)::\"char\"" == -56_i8,
+    "'\\'::\"char\"" == b'\\' as i8,
 ));
 
+#[sqlx_macros::test]
+async fn it_decodes_relkind_as_i8() -> anyhow::Result<()> {
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let sql = "SELECT relkind FROM pg_class WHERE relname = 'pg_class'";
+
+    let relkind: i8 = sqlx::query_scalar(sql).fetch_one(&mut conn).await?;
+    assert_eq!(relkind, b'r' as i8);
+
+    // the text encoding, as used by simple queries
+    let row = sqlx::Executor::fetch_one(&mut conn, sql).await?;
+    assert_eq!(sqlx::Row::try_get::<i8, _>(&row, 0)?, b'r' as i8);
+
+    // `char(1)` is `bpchar`, a string rather than a single byte
+    let res: Result<i8, _> = sqlx::query_scalar("SELECT 'r'::char(1)")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(
+        matches!(res, Err(sqlx::Error::ColumnDecode { .. })),
+        "{:?}",
+        res
+    );
+
+    Ok(())
+}
+
 test_type!(Oid(Postgres, "325235::oid" == Oid(325235),));
 
+test_type!(u32(
+    Postgres,
+    "325235::oid" == 325235_u32,
+    "0::oid" == 0_u32
+));
+
+// reference types are sent as an OID in the binary format, but as a name in the text format
+test_prepared_type!(regclass<u32>(Postgres,
+    "'pg_class'::regclass" == 1259_u32,
+    "'int4'::regtype" == 23_u32,
+));
+
+test_unprepared_type!(regclass_name<String>(Postgres,
+    "'pg_class'::regclass" == "pg_class",
+    "'information_schema.tables'::regclass" == "information_schema.tables",
+    "'int4'::regtype" == "integer",
+));
+
+#[sqlx_macros::test]
+async fn it_decodes_regclass_from_catalog_queries() -> anyhow::Result<()> {
+    use sqlx::{Executor, Row};
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let sql = "SELECT 'pg_class'::regclass, 'information_schema.tables'::regclass";
+
+    // binary
+    let (pg_class, tables): (u32, Oid) = sqlx::query_as(sql).fetch_one(&mut conn).await?;
+
+    let expected: (Oid, Oid) = sqlx::query_as(
+        "SELECT oid, (SELECT oid FROM pg_class WHERE relname = 'tables' AND relnamespace = \
+         'information_schema'::regnamespace) FROM pg_class WHERE relname = 'pg_class'",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!((Oid(pg_class), tables), expected);
+
+    // the name is only sent in the text format
+    let res = sqlx::query_scalar::<_, String>(sql)
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    // text, where the name is qualified if its schema is not in the search path
+    let row = conn.fetch_one(sql).await?;
+
+    assert_eq!(row.try_get::<String, _>(0)?, "pg_class");
+    assert_eq!(row.try_get::<String, _>(1)?, "information_schema.tables");
+    assert!(row.try_get::<u32, _>(0).is_err());
+
+    Ok(())
+}
+
 test_type!(i16(
     Postgres,
     "-2144::smallint" == -2144_i16,
@@ -175,6 +300,59 @@ test_type!(uuid_vec<Vec<sqlx::types::Uuid>>(Postgres,
         ]
 ));
 
+test_type!(url_text<sqlx::types::Text<url::Url>>(Postgres,
+    "'https://example.com/path?q=1'::text"
+        == sqlx::types::Text(url::Url::parse("https://example.com/path?q=1").unwrap()),
+));
+
+#[cfg(feature = "uuid")]
+test_type!(uuid_text<sqlx::types::Text<sqlx::types::Uuid>>(Postgres,
+    "'b731678f-636f-4135-bc6f-19440c13bd19'::text"
+        == sqlx::types::Text(sqlx::types::Uuid::parse_str("b731678f-636f-4135-bc6f-19440c13bd19").unwrap()),
+));
+
+#[cfg(feature = "uuid")]
+test_decode_type!(uuid_text_forms<sqlx::types::Text<sqlx::types::Uuid>>(Postgres,
+    "'B731678F-636F-4135-BC6F-19440C13BD19'::varchar"
+        == sqlx::types::Text(sqlx::types::Uuid::parse_str("b731678f-636f-4135-bc6f-19440c13bd19").unwrap()),
+    "'{b731678f-636f-4135-bc6f-19440c13bd19}'::text"
+        == sqlx::types::Text(sqlx::types::Uuid::parse_str("b731678f-636f-4135-bc6f-19440c13bd19").unwrap()),
+));
+
+#[cfg(feature = "uuid")]
+#[sqlx_macros::test]
+async fn it_stores_a_uuid_in_a_text_column() -> anyhow::Result<()> {
+    use sqlx::types::{Text, Uuid};
+    use sqlx::Executor;
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE legacy_users (id varchar(36) NOT NULL)")
+        .await?;
+
+    let id = Uuid::parse_str("b731678f-636f-4135-bc6f-19440c13bd19")?;
+
+    sqlx::query("INSERT INTO legacy_users (id) VALUES ($1)")
+        .bind(Text(id))
+        .execute(&mut conn)
+        .await?;
+
+    let (stored, decoded): (String, Text<Uuid>) = sqlx::query_as("SELECT id, id FROM legacy_users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(stored, "b731678f-636f-4135-bc6f-19440c13bd19");
+    assert_eq!(decoded.0, id);
+
+    let result = sqlx::query_scalar::<_, Text<Uuid>>("SELECT 'not a uuid'::text")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(result, Err(sqlx::Error::ColumnDecode { .. })));
+
+    Ok(())
+}
+
 #[cfg(feature = "ipnetwork")]
 test_type!(ipnetwork<sqlx::types::ipnetwork::IpNetwork>(Postgres,
     "'127.0.0.1'::inet"
@@ -203,6 +381,51 @@ test_type!(ipnetwork<sqlx::types::ipnetwork::IpNetwork>(Postgres,
             .unwrap(),
 ));
 
+#[cfg(feature = "ipnetwork")]
+test_type!(ipnetwork_cidr<sqlx::types::ipnetwork::IpNetwork>(Postgres,
+    "'192.168.1.0/24'::cidr"
+        == "192.168.1.0/24"
+            .parse::<sqlx::types::ipnetwork::IpNetwork>()
+            .unwrap(),
+    // a host address in a `cidr` column is a network with the full prefix
+    "'192.168.1.1'::cidr"
+        == "192.168.1.1/32"
+            .parse::<sqlx::types::ipnetwork::IpNetwork>()
+            .unwrap(),
+    "'2001:4f8:3:ba::'::cidr"
+        == "2001:4f8:3:ba::/128"
+            .parse::<sqlx::types::ipnetwork::IpNetwork>()
+            .unwrap(),
+));
+
+#[cfg(feature = "ipnetwork")]
+test_type!(ipaddr<std::net::IpAddr>(Postgres,
+    "'127.0.0.1'::inet" == "127.0.0.1".parse::<std::net::IpAddr>().unwrap(),
+    "'::1'::inet" == "::1".parse::<std::net::IpAddr>().unwrap(),
+    "'192.168.1.1/32'::cidr" == "192.168.1.1".parse::<std::net::IpAddr>().unwrap(),
+));
+
+#[cfg(feature = "ipnetwork")]
+#[sqlx_macros::test]
+async fn it_refuses_to_decode_a_network_as_an_ip_address() -> anyhow::Result<()> {
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let network: sqlx::types::ipnetwork::IpNetwork =
+        sqlx::query_scalar("SELECT '192.168.1.0/24'::cidr")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(network.prefix(), 24);
+
+    let res = sqlx::query_scalar::<_, std::net::IpAddr>("SELECT '192.168.1.0/24'::cidr")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
 #[cfg(feature = "mac_address")]
 test_type!(mac_address<sqlx::types::mac_address::MacAddress>(Postgres,
     "'00:01:02:03:04:05'::macaddr"
@@ -224,6 +447,8 @@ test_type!(bitvec<sqlx::types::BitVec>(
         bit_vec.push(false);
         bit_vec
     },
+    // A VARBIT value spanning two bytes, missing three bits from the second
+    "B'1011001110101'" == "1011001110101".chars().map(|c| c == '1').collect::<sqlx::types::BitVec>(),
     // A BIT value
     "B'01101'::bit(5)" == {
         let mut bit_vec = sqlx::types::BitVec::with_capacity(5);
@@ -236,6 +461,15 @@ test_type!(bitvec<sqlx::types::BitVec>(
     },
 ));
 
+#[cfg(feature = "bit-vec")]
+test_type!(bitvec_vec<Vec<sqlx::types::BitVec>>(
+    Postgres,
+    "'{101,1011001110101}'::varbit[]" == vec![
+        "101".chars().map(|c| c == '1').collect::<sqlx::types::BitVec>(),
+        "1011001110101".chars().map(|c| c == '1').collect::<sqlx::types::BitVec>(),
+    ],
+));
+
 #[cfg(feature = "ipnetwork")]
 test_type!(ipnetwork_vec<Vec<sqlx::types::ipnetwork::IpNetwork>>(Postgres,
     "'{127.0.0.1,8.8.8.8/24}'::inet[]"
@@ -258,7 +492,7 @@ test_type!(mac_address_vec<Vec<sqlx::types::mac_address::MacAddress>>(Postgres,
 mod chrono {
     use super::*;
     use sqlx::types::chrono::{
-        DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc,
+        DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc,
     };
 
     type PgTimeTz = sqlx::postgres::types::PgTimeTz<NaiveTime, FixedOffset>;
@@ -276,6 +510,22 @@ mod chrono {
         "'2019-01-02 05:10:20'::timestamp" == NaiveDate::from_ymd_opt(2019, 1, 2).unwrap().and_hms_opt(5, 10, 20).unwrap()
     ));
 
+    test_type!(chrono_tsrange<PgRange<NaiveDateTime>>(Postgres,
+        "'[2019-01-02 05:10:20,2019-01-03 00:00:00)'::tsrange"
+            == PgRange::from(
+                NaiveDate::from_ymd_opt(2019, 1, 2).unwrap().and_hms_opt(5, 10, 20).unwrap()
+                    ..NaiveDate::from_ymd_opt(2019, 1, 3).unwrap().and_hms_opt(0, 0, 0).unwrap()
+            ),
+        "'(,2019-01-03 00:00:00]'::tsrange"
+            == PgRange::from(
+                ..=NaiveDate::from_ymd_opt(2019, 1, 3).unwrap().and_hms_opt(0, 0, 0).unwrap()
+            ),
+        "'[2019-01-02 05:10:20,)'::tsrange"
+            == PgRange::from(
+                NaiveDate::from_ymd_opt(2019, 1, 2).unwrap().and_hms_opt(5, 10, 20).unwrap()..
+            )
+    ));
+
     test_type!(chrono_date_time_vec<Vec<NaiveDateTime>>(Postgres,
         "array['2019-01-02 05:10:20']::timestamp[]"
             == vec![NaiveDate::from_ymd_opt(2019, 1, 2).unwrap().and_hms_opt(5, 10, 20).unwrap()]
@@ -304,18 +554,81 @@ mod chrono {
             ]
     ));
 
+    test_prepared_type!(chrono_duration<Duration>(Postgres,
+        "INTERVAL '1.5 seconds'" == Duration::milliseconds(1500),
+        "INTERVAL '-1 hours'" == Duration::hours(-1),
+        "INTERVAL '2 days -01:00:00'" == Duration::hours(47),
+    ));
+
+    #[sqlx_macros::test]
+    async fn chrono_duration_rejects_months() -> anyhow::Result<()> {
+        let mut conn = sqlx_test::new::<Postgres>().await?;
+
+        let res: Result<Duration, _> = sqlx::query_scalar("SELECT INTERVAL '1 mon 2 days'")
+            .fetch_one(&mut conn)
+            .await;
+
+        assert!(
+            matches!(res, Err(sqlx::Error::ColumnDecode { .. })),
+            "{:?}",
+            res
+        );
+
+        Ok(())
+    }
+
     test_type!(chrono_time_tz<PgTimeTz>(Postgres,
         "TIMETZ '05:10:20.115100+00'" == PgTimeTz { time: NaiveTime::from_hms_micro_opt(5, 10, 20, 115100).unwrap(), offset: FixedOffset::east_opt(0).unwrap() },
         "TIMETZ '05:10:20.115100+06:30'" == PgTimeTz { time: NaiveTime::from_hms_micro_opt(5, 10, 20, 115100).unwrap(), offset: FixedOffset::east_opt(60 * 60 * 6 + 1800).unwrap() },
         "TIMETZ '05:10:20.115100-05'" == PgTimeTz { time: NaiveTime::from_hms_micro_opt(5, 10, 20, 115100).unwrap(), offset: FixedOffset::west_opt(60 * 60 * 5).unwrap() },
-        "TIMETZ '05:10:20+02'" == PgTimeTz { time: NaiveTime::from_hms_opt(5, 10, 20).unwrap(), offset: FixedOffset::east_opt(60 * 60 * 2 ).unwrap()}
+        "TIMETZ '05:10:20+02'" == PgTimeTz { time: NaiveTime::from_hms_opt(5, 10, 20).unwrap(), offset: FixedOffset::east_opt(60 * 60 * 2 ).unwrap()},
+        "TIMETZ '12:34:56+05:30'" == PgTimeTz { time: NaiveTime::from_hms_opt(12, 34, 56).unwrap(), offset: FixedOffset::east_opt(60 * 60 * 5 + 1800).unwrap() },
+        "TIMETZ '00:00:00-03:30'" == PgTimeTz { time: NaiveTime::from_hms_opt(0, 0, 0).unwrap(), offset: FixedOffset::west_opt(60 * 60 * 3 + 1800).unwrap() }
     ));
+
+    #[sqlx_macros::test]
+    async fn chrono_time_tz_rejects_the_end_of_the_day() -> anyhow::Result<()> {
+        let mut conn = sqlx_test::new::<Postgres>().await?;
+
+        // Postgres allows `24:00:00`, which must not be read as midnight
+        let res: Result<PgTimeTz, _> = sqlx::query_scalar("SELECT TIMETZ '24:00:00+00'")
+            .fetch_one(&mut conn)
+            .await;
+
+        assert!(
+            matches!(res, Err(sqlx::Error::ColumnDecode { .. })),
+            "{:?}",
+            res
+        );
+
+        Ok(())
+    }
+
+    #[sqlx_macros::test]
+    async fn chrono_binds_a_datetime_with_a_fixed_offset() -> anyhow::Result<()> {
+        let mut conn = sqlx_test::new::<Postgres>().await?;
+
+        let datetime = DateTime::<FixedOffset>::parse_from_rfc3339("2019-01-02T05:10:20+05:30")?;
+
+        let (utc, equal): (DateTime<Utc>, bool) =
+            sqlx::query_as("SELECT $1, $1 = TIMESTAMPTZ '2019-01-01 23:40:20+00'")
+                .bind(datetime)
+                .fetch_one(&mut conn)
+                .await?;
+
+        // decoding is always in UTC, whatever the offset of the bound value
+        assert_eq!(utc, datetime);
+        assert_eq!(utc.to_rfc3339(), "2019-01-01T23:40:20+00:00");
+        assert!(equal);
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "time")]
 mod time_tests {
     use super::*;
-    use sqlx::types::time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+    use sqlx::types::time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
     use time::macros::{date, time};
 
     type PgTimeTz = sqlx::postgres::types::PgTimeTz<Time, UtcOffset>;
@@ -345,7 +658,15 @@ mod time_tests {
                 .assume_utc()
     ));
 
-    test_prepared_type!(time_time_tz<PgTimeTz>(Postgres,
+    test_prepared_type!(time_duration<Duration>(Postgres,
+        "INTERVAL '1.5 seconds'" == Duration::milliseconds(1500),
+        "INTERVAL '-1 days +01:00:00'" == Duration::hours(-23),
+    ));
+
+    test_type!(time_time_tz<PgTimeTz>(Postgres,
+        "TIMETZ '12:34:56+05:30'" == PgTimeTz { time: time!(12:34:56), offset: UtcOffset::from_hms(5, 30, 0).unwrap() },
+        "TIMETZ '00:00:00-03:30'" == PgTimeTz { time: time!(0:00:00), offset: UtcOffset::from_hms(-3, -30, 0).unwrap() },
+        "TIMETZ '23:59:59.999999+14'" == PgTimeTz { time: time!(23:59:59.999999), offset: UtcOffset::from_hms(14, 0, 0).unwrap() },
         "TIMETZ '05:10:20.115100+00'" == PgTimeTz { time: time!(5:10:20.115100), offset: UtcOffset::from_whole_seconds(0).unwrap() },
         "TIMETZ '05:10:20.115100+00'" == PgTimeTz { time: time!(5:10:20.115100), offset: UtcOffset::from_whole_seconds(0).unwrap() },
         "TIMETZ '05:10:20.115100+06:30'" == PgTimeTz { time: time!(5:10:20.115100), offset: UtcOffset::from_whole_seconds(60 * 60 * 6 + 1800).unwrap() },
@@ -443,6 +764,82 @@ mod json {
 
         Ok(())
     }
+
+    #[sqlx_macros::test]
+    async fn test_jsonb_binary_and_text_decode_agree() -> anyhow::Result<()> {
+        let mut conn = new::<Postgres>().await?;
+
+        let sql = "SELECT jsonb_agg(jsonb_build_object(
+            'id', i,
+            'name', 'user ' || i,
+            'tags', jsonb_build_array(i % 3, 'é😎', i::float8 / 7, NULL, i % 2 = 0)
+        )) FROM generate_series(1, 5000) i";
+
+        // unprepared, text API
+        let row: PgRow = conn.fetch_one(sql).await?;
+        let text: JsonValue = row.try_get(0)?;
+
+        // prepared, binary API
+        let binary: JsonValue = sqlx::query_scalar(sql).fetch_one(&mut conn).await?;
+
+        assert_eq!(binary.as_array().map(Vec::len), Some(5000));
+        assert_eq!(binary, text);
+
+        Ok(())
+    }
+
+    sqlx_test::test_json_nulls!(
+        Postgres,
+        "JSONB",
+        "INSERT INTO json_nulls (id, friend) VALUES ($1, $2)"
+    );
+
+    #[sqlx_macros::test]
+    async fn test_row_get_json() -> anyhow::Result<()> {
+        let mut conn = new::<Postgres>().await?;
+
+        let sql = r#"SELECT '{"name":"Joe","age":33}'::jsonb, '{"name":"Bob","age":22}'::text, NULL::jsonb"#;
+
+        let joe = Friend {
+            name: "Joe".to_string(),
+            age: 33,
+        };
+
+        let bob = Friend {
+            name: "Bob".to_string(),
+            age: 22,
+        };
+
+        // prepared, binary API
+        let row: PgRow = sqlx::query(sql).fetch_one(&mut conn).await?;
+
+        assert_eq!(row.try_get_json::<Friend, _>(0)?, joe);
+        assert_eq!(row.try_get_json::<Friend, _>(1)?, bob);
+        assert_eq!(row.try_get_json_opt::<Friend, _>(0)?, Some(joe));
+
+        // SQL `NULL` is only accepted by `try_get_json_opt`
+        assert_eq!(row.try_get_json_opt::<Friend, _>(2)?, None);
+        assert!(matches!(
+            row.try_get_json::<Friend, _>(2),
+            Err(sqlx::Error::ColumnDecode { .. })
+        ));
+
+        // unprepared, text API
+        let row: PgRow = conn.fetch_one(sql).await?;
+
+        assert_eq!(row.get_json::<Friend, _>(1), bob);
+        assert_eq!(row.try_get_json::<JsonValue, _>(0)?["age"], json!(33));
+
+        // other types are not JSON
+        let row: PgRow = sqlx::query("SELECT 1::int4").fetch_one(&mut conn).await?;
+
+        assert!(matches!(
+            row.try_get_json::<JsonValue, _>(0),
+            Err(sqlx::Error::ColumnDecode { .. })
+        ));
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "bigdecimal")]
@@ -473,6 +870,80 @@ test_type!(bigdecimal<sqlx::types::BigDecimal>(Postgres,
     "12345.6789::numeric" == "12345.6789".parse::<sqlx::types::BigDecimal>().unwrap(),
 ));
 
+#[cfg(feature = "bigdecimal")]
+test_type!(bigdecimal_vec<Vec<Option<sqlx::types::BigDecimal>>>(Postgres,
+    "ARRAY[1.5, NULL, 3.25]::numeric[]" == vec![
+        Some("1.5".parse::<sqlx::types::BigDecimal>().unwrap()),
+        None,
+        Some("3.25".parse::<sqlx::types::BigDecimal>().unwrap()),
+    ],
+    "'{}'::numeric[]" == Vec::<Option<sqlx::types::BigDecimal>>::new(),
+));
+
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal_scale {
+    use super::*;
+    use sqlx::types::BigDecimal;
+    use sqlx_test::new;
+
+    #[sqlx_macros::test]
+    async fn it_preserves_the_scale_of_each_array_element() -> anyhow::Result<()> {
+        let mut conn = new::<Postgres>().await?;
+
+        let values: Vec<Option<BigDecimal>> =
+            sqlx::query_scalar("SELECT ARRAY[1.50, NULL, 3.250, 7]::numeric[]")
+                .fetch_one(&mut conn)
+                .await?;
+
+        let values: Vec<Option<String>> = values
+            .iter()
+            .map(|value| value.as_ref().map(ToString::to_string))
+            .collect();
+
+        assert_eq!(
+            values,
+            vec![
+                Some("1.50".to_owned()),
+                None,
+                Some("3.250".to_owned()),
+                Some("7".to_owned())
+            ]
+        );
+
+        Ok(())
+    }
+}
+
+// MONEY is only decoded from the binary format, as its text format depends on the locale
+#[cfg(feature = "bigdecimal")]
+test_prepared_decode_type!(bigdecimal_money<sqlx::types::BigDecimal>(Postgres,
+    "'$1234.56'::money" == sqlx::types::BigDecimal::from_str("1234.56").unwrap(),
+    "'-1234.56'::numeric::money" == sqlx::types::BigDecimal::from_str("-1234.56").unwrap(),
+    "'92233720368547758.07'::numeric::money"
+        == sqlx::types::BigDecimal::from_str("92233720368547758.07").unwrap(),
+    "'-92233720368547758.08'::numeric::money"
+        == sqlx::types::BigDecimal::from_str("-92233720368547758.08").unwrap(),
+));
+
+#[cfg(feature = "bigdecimal")]
+#[sqlx_macros::test]
+async fn it_round_trips_money_as_bigdecimal() -> anyhow::Result<()> {
+    use sqlx::types::BigDecimal;
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let value = BigDecimal::from_str("1234.56")?;
+
+    let money: BigDecimal = sqlx::query_scalar("SELECT $1::money")
+        .bind(&value)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(money, value);
+
+    Ok(())
+}
+
 #[cfg(feature = "bigdecimal")]
 test_type!(numrange_bigdecimal<PgRange<sqlx::types::BigDecimal>>(Postgres,
     "'(1.3,2.4)'::numrange" == PgRange::from(
@@ -491,6 +962,70 @@ test_type!(decimal<sqlx::types::Decimal>(Postgres,
     "12345.6789::numeric" == sqlx::types::Decimal::from_str("12345.6789").unwrap(),
 ));
 
+#[cfg(feature = "decimal")]
+#[sqlx_macros::test]
+async fn it_encodes_decimals_exactly() -> anyhow::Result<()> {
+    use sqlx::types::Decimal;
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let mut values: Vec<Decimal> = [
+        "0",
+        "0.000",
+        "-1.50",
+        "0.0000000000000000000000000001",
+        "79228162514264337593543950335",
+        "-79228162514264337593543950335",
+        "7.9228162514264337593543950335",
+    ]
+    .iter()
+    .map(|value| Decimal::from_str(value).unwrap())
+    .collect();
+
+    // spread the mantissas over all 96 bits and the scales over all 29
+    values.extend((0..1000_i128).map(|i| {
+        let mantissa = (i * 0x1234_5678_9abc_def1_2345_6789) % (1 << 96);
+        let mantissa = if i % 2 == 0 { mantissa } else { -mantissa };
+
+        Decimal::from_i128_with_scale(mantissa, (i % 29) as u32)
+    }));
+
+    let expected: Vec<String> = values.iter().map(ToString::to_string).collect();
+
+    // encoded in binary, compared with the way Postgres formats them
+    let texts: Vec<String> = sqlx::query_scalar(
+        "SELECT v::text FROM UNNEST($1::numeric[]) WITH ORDINALITY AS t(v, i) ORDER BY i",
+    )
+    .bind(&values)
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(texts, expected);
+
+    // and decoded from binary again
+    let decoded: Vec<Decimal> = sqlx::query_scalar("SELECT $1::numeric[]")
+        .bind(&values)
+        .fetch_one(&mut conn)
+        .await?;
+
+    let decoded: Vec<String> = decoded.iter().map(ToString::to_string).collect();
+
+    assert_eq!(decoded, expected);
+
+    Ok(())
+}
+
+// MONEY is only decoded from the binary format, as its text format depends on the locale
+#[cfg(feature = "decimal")]
+test_prepared_decode_type!(decimal_money<sqlx::types::Decimal>(Postgres,
+    "'$1234.56'::money" == sqlx::types::Decimal::from_str("1234.56").unwrap(),
+    "'-1234.56'::numeric::money" == sqlx::types::Decimal::from_str("-1234.56").unwrap(),
+    "'92233720368547758.07'::numeric::money"
+        == sqlx::types::Decimal::from_str("92233720368547758.07").unwrap(),
+    "'-92233720368547758.08'::numeric::money"
+        == sqlx::types::Decimal::from_str("-92233720368547758.08").unwrap(),
+));
+
 #[cfg(feature = "decimal")]
 test_type!(numrange_decimal<PgRange<sqlx::types::Decimal>>(Postgres,
     "'(1.3,2.4)'::numrange" == PgRange::from(
@@ -522,6 +1057,37 @@ test_type!(int4range<PgRange<i32>>(Postgres,
     "'[1,2]'::int4range" == PgRange::from((INC1, EXC3)),
 ));
 
+test_type!(int8range<PgRange<i64>>(Postgres,
+    "'(,)'::int8range" == PgRange::<i64>::from((Bound::Unbounded, Bound::Unbounded)),
+    "'[1,)'::int8range" == PgRange::from(1_i64..),
+    "'[-5000000000,5000000000)'::int8range" == PgRange::from(-5_000_000_000_i64..5_000_000_000),
+));
+
+// an empty range has no bounds to decode
+test_decode_type!(int4range_empty<PgRange<i32>>(Postgres,
+    "'empty'::int4range" == PgRange::from((UNB, UNB)),
+    "'[1,1)'::int4range" == PgRange::from((UNB, UNB)),
+));
+
+#[sqlx_macros::test]
+async fn it_exposes_the_bounds_of_a_range() -> anyhow::Result<()> {
+    use std::ops::RangeBounds;
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let range: PgRange<i32> = sqlx::query_scalar("SELECT $1::int4range")
+        .bind(PgRange::from(1..10))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(range.start_bound(), Bound::Included(&1));
+    assert_eq!(range.end_bound(), Bound::Excluded(&10));
+    assert!(range.contains(&9));
+    assert!(!range.contains(&10));
+
+    Ok(())
+}
+
 test_prepared_type!(interval<PgInterval>(
     Postgres,
     "INTERVAL '1h'"
@@ -548,6 +1114,26 @@ test_prepared_type!(interval<PgInterval>(
             days: 0,
             microseconds: (3 * 3_600 + 10 * 60 + 20) * 1_000_000 + 116100
         },
+    "INTERVAL '1 mon 2 days 03:04:05'"
+        == PgInterval {
+            months: 1,
+            days: 2,
+            microseconds: (3 * 3_600 + 4 * 60 + 5) * 1_000_000
+        },
+    "INTERVAL '-1 mon +2 days -03:04:05'"
+        == PgInterval {
+            months: -1,
+            days: 2,
+            microseconds: -(3 * 3_600 + 4 * 60 + 5) * 1_000_000
+        },
+));
+
+test_prepared_type!(std_duration<std::time::Duration>(
+    Postgres,
+    "INTERVAL '1.5 seconds'" == std::time::Duration::from_millis(1500),
+    "INTERVAL '0'" == std::time::Duration::ZERO,
+    "INTERVAL '00:00:00.000001'" == std::time::Duration::from_micros(1),
+    "INTERVAL '1 day 02:03:04'" == std::time::Duration::from_secs(86_400 + 2 * 3600 + 3 * 60 + 4),
 ));
 
 test_prepared_type!(money<PgMoney>(Postgres, "123.45::money" == PgMoney(12345)));
@@ -556,6 +1142,127 @@ test_prepared_type!(money_vec<Vec<PgMoney>>(Postgres,
     "array[123.45,420.00,666.66]::money[]" == vec![PgMoney(12345), PgMoney(42000), PgMoney(66666)],
 ));
 
+// `point`, `box` and `circle` have no `=` operator, only `~=` for "same as"
+test_type!(geometric_point<PgPoint>(Postgres,
+    "SELECT ({0} ~= $1)::int4, {0}, $2",
+    "POINT '(1,2)'" == PgPoint::new(1.0, 2.0),
+    "POINT '(-1.5,1e+300)'" == PgPoint::new(-1.5, 1e300),
+));
+
+test_type!(geometric_line<PgLine>(Postgres,
+    "SELECT ({0} = $1)::int4, {0}, $2",
+    "LINE '{1,-1,0}'" == PgLine::new(1.0, -1.0, 0.0),
+    "LINE '[(0,0),(0,1)]'" == PgLine::new(-1.0, 0.0, 0.0),
+));
+
+test_type!(geometric_box<PgBox>(Postgres,
+    "SELECT ({0} ~= $1)::int4, {0}, $2",
+    "BOX '(3,4),(1,2)'" == PgBox::new(PgPoint::new(3.0, 4.0), PgPoint::new(1.0, 2.0)),
+    "BOX '(1,4),(3,2)'" == PgBox::new(PgPoint::new(1.0, 4.0), PgPoint::new(3.0, 2.0)),
+));
+
+// the elements of a `box[]` are separated by `;` rather than `,`
+test_unprepared_type!(geometric_box_vec<Vec<PgBox>>(Postgres,
+    "ARRAY[BOX '(3,4),(1,2)', BOX '(0,0),(-1,-1)']::box[]"
+        == vec![
+            PgBox::new(PgPoint::new(3.0, 4.0), PgPoint::new(1.0, 2.0)),
+            PgBox::new(PgPoint::new(0.0, 0.0), PgPoint::new(-1.0, -1.0)),
+        ],
+));
+
+test_type!(geometric_circle<PgCircle>(Postgres,
+    "SELECT ({0} ~= $1)::int4, {0}, $2",
+    "CIRCLE '<(1,2),3>'" == PgCircle::new(PgPoint::new(1.0, 2.0), 3.0),
+));
+
+#[sqlx_macros::test]
+async fn it_orders_the_corners_of_a_box() -> anyhow::Result<()> {
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let r#box = PgBox {
+        upper_right: PgPoint::new(1.0, 2.0),
+        lower_left: PgPoint::new(3.0, 4.0),
+    };
+
+    let decoded: PgBox = sqlx::query_scalar("SELECT $1::box")
+        .bind(r#box)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(decoded.upper_right, PgPoint::new(3.0, 4.0));
+    assert_eq!(decoded.lower_left, PgPoint::new(1.0, 2.0));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_decodes_polymorphic_arrays() -> anyhow::Result<()> {
+    use sqlx::Executor;
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let aggregated: Vec<i32> = sqlx::query_scalar("SELECT array_agg(x) FROM (VALUES (1),(2)) s(x)")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(aggregated, [1, 2]);
+
+    // the most common values of a column are an `anyarray` of the type of the column
+    conn.execute(
+        "CREATE TEMPORARY TABLE polymorphic (x int4 NOT NULL);
+        INSERT INTO polymorphic (x) VALUES (2), (2), (2), (1), (1);
+        ANALYZE polymorphic;",
+    )
+    .await?;
+
+    let sql = "SELECT most_common_vals FROM pg_stats
+        WHERE schemaname = (SELECT nspname FROM pg_namespace WHERE oid = pg_my_temp_schema())
+        AND tablename = 'polymorphic' AND attname = 'x'";
+
+    let most_common: Vec<i32> = sqlx::query_scalar(sql).fetch_one(&mut conn).await?;
+
+    assert_eq!(most_common, [2, 1]);
+
+    let result = sqlx::query_scalar::<_, Vec<String>>(sql)
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(result, Err(sqlx::Error::ColumnDecode { .. })));
+
+    Ok(())
+}
+
+fn ts_lexeme(text: &str, positions: &[(u16, PgTsWeight)]) -> PgTsLexeme {
+    PgTsLexeme {
+        text: text.to_owned(),
+        positions: positions
+            .iter()
+            .map(|&(position, weight)| PgTsPosition { position, weight })
+            .collect(),
+    }
+}
+
+test_decode_type!(tsvector<PgTsVector>(Postgres,
+    "to_tsvector('english', 'the quick brown fox')" == PgTsVector {
+        lexemes: vec![
+            ts_lexeme("brown", &[(3, PgTsWeight::D)]),
+            ts_lexeme("fox", &[(4, PgTsWeight::D)]),
+            ts_lexeme("quick", &[(2, PgTsWeight::D)]),
+        ],
+    },
+    "setweight(to_tsvector('simple', 'fat cat'), 'A') || 'cat:4B ''it''''s'':1C,3'::tsvector" == PgTsVector {
+        lexemes: vec![
+            ts_lexeme("cat", &[(2, PgTsWeight::A), (6, PgTsWeight::B)]),
+            ts_lexeme("fat", &[(1, PgTsWeight::A)]),
+            ts_lexeme("it's", &[(3, PgTsWeight::C), (5, PgTsWeight::D)]),
+        ],
+    },
+    "'a fat cat'::tsvector" == PgTsVector {
+        lexemes: vec![ts_lexeme("a", &[]), ts_lexeme("cat", &[]), ts_lexeme("fat", &[])],
+    },
+    "''::tsvector" == PgTsVector::default(),
+));
+
 // FIXME: needed to disable `ltree` tests in version that don't have a binary format for it
 // but `PgLTree` should just fall back to text format
 #[cfg(postgres_14)]
@@ -574,3 +1281,59 @@ test_type!(ltree_vec<Vec<sqlx::postgres::types::PgLTree>>(Postgres,
             sqlx::postgres::types::PgLTree::from_iter(["Alpha", "Beta", "Delta", "Gamma"]).unwrap()
         ]
 ));
+
+#[cfg(feature = "hstore")]
+test_type!(hstore<std::collections::HashMap<String, Option<String>>>(Postgres,
+    "''::hstore" == std::collections::HashMap::<String, Option<String>>::new(),
+    r#"'"a"=>"1", "b"=>NULL'::hstore"# == [
+        ("a".to_owned(), Some("1".to_owned())),
+        ("b".to_owned(), None),
+    ].into_iter().collect::<std::collections::HashMap<_, _>>(),
+    r#"'"k=>\"e,y\\"=>"v, \"w\"", "NULL"=>"NULL"'::hstore"# == [
+        (r#"k=>"e,y\"#.to_owned(), Some(r#"v, "w""#.to_owned())),
+        ("NULL".to_owned(), Some("NULL".to_owned())),
+    ].into_iter().collect::<std::collections::HashMap<_, _>>(),
+));
+
+// only run against a server with the `postgis` extension installed, which is not part of the
+// test setup; enable them with `RUSTFLAGS="--cfg postgis"`
+#[cfg(all(feature = "postgis", postgis))]
+test_type!(postgis_point<sqlx::postgres::types::PgGeometry<sqlx::types::geo_types::Point>>(Postgres,
+    "'SRID=4326;POINT(1.5 -2.25)'::geometry"
+        == sqlx::postgres::types::PgGeometry::new(4326, sqlx::types::geo_types::Point::new(1.5, -2.25)),
+    "'SRID=3857;POINT(-13580977.9 4530049.4)'::geometry"
+        == sqlx::postgres::types::PgGeometry::new(3857, sqlx::types::geo_types::Point::new(-13580977.9, 4530049.4)),
+    "'POINT(0 0)'::geometry"
+        == sqlx::postgres::types::PgGeometry::new(0, sqlx::types::geo_types::Point::new(0.0, 0.0)),
+));
+
+#[cfg(all(feature = "postgis", postgis))]
+#[sqlx_macros::test]
+async fn it_encodes_points_with_the_default_srid() -> anyhow::Result<()> {
+    use sqlx::types::geo_types::Point;
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let (srid, point): (i32, Point) = sqlx::query_as("SELECT ST_SRID($1), $1")
+        .bind(Point::new(1.5, -2.25))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(srid, sqlx::postgres::types::SRID_WGS84);
+    assert_eq!(point, Point::new(1.5, -2.25));
+
+    // the SRID is dropped when decoding a bare point
+    let point: Point = sqlx::query_scalar("SELECT 'SRID=3857;POINT(3 4)'::geometry")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(point, Point::new(3.0, 4.0));
+
+    let res = sqlx::query_scalar::<_, Point>("SELECT 'LINESTRING(0 0, 1 1)'::geometry")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
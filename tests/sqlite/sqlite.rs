@@ -79,6 +79,44 @@ async fn it_fetches_and_inflates_row() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_gets_columns_by_name() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let columns: Vec<String> = (0..50).map(|i| format!("n + {} AS c{}", i, i)).collect();
+    let sql = format!(
+        "WITH RECURSIVE t(n) AS (SELECT 0 UNION ALL SELECT n + 1 FROM t WHERE n < 999) \
+         SELECT {} FROM t",
+        columns.join(", ")
+    );
+
+    let rows = sqlx::query(&sql).fetch_all(&mut conn).await?;
+
+    assert_eq!(rows.len(), 1000);
+
+    for (n, row) in rows.iter().enumerate() {
+        for i in 0..50 {
+            assert_eq!(row.get::<i64, _>(&*format!("c{}", i)), (n + i) as i64);
+        }
+    }
+
+    // names are case-sensitive
+    assert!(matches!(
+        rows[0].try_get::<i64, _>("C0"),
+        Err(sqlx::Error::ColumnNotFound(name)) if name == "C0"
+    ));
+
+    // the last of the columns with the same name is used
+    let row = sqlx::query("SELECT 1 AS a, 2 AS a")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.get::<i32, _>("a"), 2);
+    assert_eq!(row.get::<i32, _>(0), 1);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_maths() -> anyhow::Result<()> {
     let mut conn = new::<Sqlite>().await?;
@@ -127,6 +165,30 @@ async fn test_bind_multiple_statements_same_value() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_bind_wrong_number_of_values() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    // unlike in other databases, this is not an error
+    let (value, unbound): (i32, bool) = sqlx::query_as("select ?, ? is null")
+        .bind(25_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 25);
+    assert!(unbound);
+
+    let value: i32 = sqlx::query_scalar("select ?")
+        .bind(25_i32)
+        .bind(26_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 25);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_describe_with_pragma() -> anyhow::Result<()> {
     use sqlx::{Decode, TypeInfo, ValueRef};
@@ -175,6 +237,41 @@ async fn it_binds_positional_parameters_issue_467() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_binds_a_sequence_of_parameters() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let values: Vec<i32> = (1..=50).collect();
+    let placeholders = vec!["(?)"; values.len()].join(", ");
+
+    let sum: i64 = sqlx::query_scalar(&format!(
+        "SELECT SUM(column1) FROM (VALUES {})",
+        placeholders
+    ))
+    .bind_iter(&values)
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(sum, 1275);
+
+    let rows: Vec<(i32,)> = sqlx::query_as(&format!("VALUES {}", placeholders))
+        .bind_all(values.clone())
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(rows.into_iter().map(|(v,)| v).collect::<Vec<_>>(), values);
+
+    // binding nothing leaves the query unchanged
+    let one: i32 = sqlx::query_scalar("SELECT 1")
+        .bind_all(Vec::<i32>::new())
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(one, 1);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_fetches_in_loop() -> anyhow::Result<()> {
     // this is trying to check for any data races
@@ -523,6 +620,36 @@ async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_rejects_transaction_options_for_savepoints() -> anyhow::Result<()> {
+    use sqlx::TransactionOptions;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    let mut tx = conn.begin_with_options(TransactionOptions::new()).await?;
+
+    let err = tx
+        .begin_with_options(TransactionOptions::new())
+        .await
+        .expect_err("expected the options to be rejected");
+
+    assert!(matches!(err, sqlx::Error::Savepoint(_)), "{:?}", err);
+
+    // the transaction is still usable
+    let value: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut tx).await?;
+    assert_eq!(value, 1);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+sqlx_test::test_named_savepoints!(
+    Sqlite,
+    "_sqlx_savepoints",
+    "CREATE TEMPORARY TABLE _sqlx_savepoints (id INTEGER PRIMARY KEY)"
+);
+
 #[sqlx_macros::test]
 async fn it_resets_prepared_statement_after_fetch_one() -> anyhow::Result<()> {
     let mut conn = new::<Sqlite>().await?;
@@ -737,3 +864,317 @@ async fn concurrent_read_and_write() {
         write.await;
     }
 }
+
+#[sqlx_macros::test]
+async fn it_applies_foreign_keys_option() -> anyhow::Result<()> {
+    use std::str::FromStr;
+
+    const SCHEMA: &str = "
+        CREATE TABLE parent (id INTEGER PRIMARY KEY);
+        CREATE TABLE child (id INTEGER PRIMARY KEY, parent_id INTEGER REFERENCES parent (id));
+    ";
+
+    let mut conn = SqliteConnectOptions::from_str(":memory:")?
+        .foreign_keys(true)
+        .connect()
+        .await?;
+
+    conn.execute(SCHEMA).await?;
+
+    let res = conn
+        .execute("INSERT INTO child (id, parent_id) VALUES (1, 42)")
+        .await;
+
+    let err = res.expect_err("expected a foreign key violation");
+    assert!(err.to_string().contains("FOREIGN KEY"), "{}", err);
+
+    let mut conn = SqliteConnectOptions::from_str(":memory:")?
+        .foreign_keys(false)
+        .connect()
+        .await?;
+
+    conn.execute(SCHEMA).await?;
+    conn.execute("INSERT INTO child (id, parent_id) VALUES (1, 42)")
+        .await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_applies_pragma_options_on_connect() -> anyhow::Result<()> {
+    use sqlx::sqlite::{SqliteJournalMode, SqliteSynchronous};
+    use std::time::Duration;
+
+    let dir = tempdir::TempDir::new("sqlite_pragma_test")?;
+
+    let options = SqliteConnectOptions::new()
+        .filename(dir.path().join("database.sqlite3"))
+        .create_if_missing(true)
+        .journal_mode(SqliteJournalMode::Wal)
+        .synchronous(SqliteSynchronous::Normal)
+        .foreign_keys(true)
+        .busy_timeout(Duration::from_millis(1234));
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(2)
+        .connect_with(options)
+        .await?;
+
+    // every connection in the pool is configured before it is handed out
+    let mut a = pool.acquire().await?;
+    let mut b = pool.acquire().await?;
+
+    for conn in [&mut *a, &mut *b] {
+        let journal_mode: String = sqlx::query_scalar("PRAGMA journal_mode")
+            .fetch_one(&mut *conn)
+            .await?;
+        let synchronous: i32 = sqlx::query_scalar("PRAGMA synchronous")
+            .fetch_one(&mut *conn)
+            .await?;
+        let foreign_keys: bool = sqlx::query_scalar("PRAGMA foreign_keys")
+            .fetch_one(&mut *conn)
+            .await?;
+        let busy_timeout: i32 = sqlx::query_scalar("PRAGMA busy_timeout")
+            .fetch_one(&mut *conn)
+            .await?;
+
+        assert_eq!(journal_mode, "wal");
+        assert_eq!(synchronous, 1);
+        assert!(foreign_keys);
+        assert_eq!(busy_timeout, 1234);
+    }
+
+    drop((a, b));
+    pool.close().await;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_serializes_and_deserializes_a_database() -> anyhow::Result<()> {
+    let mut conn = SqliteConnection::connect(":memory:").await?;
+
+    conn.execute(
+        r#"
+CREATE TABLE fixture (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+INSERT INTO fixture (name) VALUES ('alpha'), ('beta');
+        "#,
+    )
+    .await?;
+
+    let data = conn.serialize(None).await?;
+    assert!(!data.is_empty());
+
+    // each restore gets its own copy of the snapshot
+    for _ in 0..2 {
+        let mut conn = SqliteConnection::connect(":memory:").await?;
+        conn.deserialize(None, &data).await?;
+
+        let names: Vec<String> = sqlx::query_scalar("SELECT name FROM fixture ORDER BY id")
+            .fetch_all(&mut conn)
+            .await?;
+        assert_eq!(names, ["alpha", "beta"]);
+
+        conn.execute("INSERT INTO fixture (name) VALUES ('gamma')")
+            .await?;
+    }
+
+    // a named schema is serialized on its own
+    conn.execute("ATTACH DATABASE ':memory:' AS aux").await?;
+    conn.execute(
+        "CREATE TABLE aux.other (value INTEGER NOT NULL); INSERT INTO aux.other VALUES (42)",
+    )
+    .await?;
+
+    let aux = conn.serialize(Some("aux")).await?;
+
+    let mut restored = SqliteConnection::connect(":memory:").await?;
+    restored
+        .execute("ATTACH DATABASE ':memory:' AS aux")
+        .await?;
+    restored.deserialize(Some("aux"), &aux).await?;
+
+    let value: i64 = sqlx::query_scalar("SELECT value FROM aux.other")
+        .fetch_one(&mut restored)
+        .await?;
+    assert_eq!(value, 42);
+
+    let tables: Vec<String> = sqlx::query_scalar("SELECT name FROM aux.sqlite_master")
+        .fetch_all(&mut restored)
+        .await?;
+    assert_eq!(tables, ["other"]);
+
+    assert!(conn.serialize(Some("does_not_exist")).await.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_attaches_databases() -> anyhow::Result<()> {
+    use std::str::FromStr;
+
+    let dir = tempdir::TempDir::new("sqlite_attach_test")?;
+    let other = dir.path().join("other.sqlite3");
+
+    let mut conn = SqliteConnectOptions::new()
+        .filename(dir.path().join("main.sqlite3"))
+        .create_if_missing(true)
+        .connect()
+        .await?;
+
+    conn.attach(&other, "other").await?;
+    conn.execute("CREATE TABLE other.t (x INTEGER NOT NULL); INSERT INTO other.t VALUES (1)")
+        .await?;
+
+    let x: i64 = sqlx::query_scalar("SELECT x FROM other.t")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(x, 1);
+
+    conn.detach("other").await?;
+    assert!(conn.execute("SELECT x FROM other.t").await.is_err());
+
+    // declared attachments are re-applied to every connection, and stay on disk even though
+    // the main database is in memory
+    let options = SqliteConnectOptions::from_str(":memory:")?.attach(&other, "other");
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(2)
+        .connect_with(options)
+        .await?;
+
+    let mut a = pool.acquire().await?;
+    let mut b = pool.acquire().await?;
+
+    a.execute("INSERT INTO other.t VALUES (2)").await?;
+
+    let xs: Vec<i64> = sqlx::query_scalar("SELECT x FROM other.t ORDER BY x")
+        .fetch_all(&mut *b)
+        .await?;
+    assert_eq!(xs, [1, 2]);
+
+    drop((a, b));
+    pool.close().await;
+
+    conn.attach(&other, "other").await?;
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM other.t")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(count, 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_executes_a_bulk_insert_from_query_builder() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE bulk (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+        .await?;
+
+    let mut builder = sqlx::QueryBuilder::<Sqlite>::new("INSERT INTO bulk (id, name) ");
+
+    builder.push_values(0..500, |mut b, i| {
+        b.push_bind(i)
+            .push_bind(format!("'); DROP TABLE bulk; --{}", i));
+    });
+
+    assert!(builder.sql().ends_with("(?, ?), (?, ?)"));
+
+    let done = builder.build().execute(&mut conn).await?;
+
+    assert_eq!(done.rows_affected(), 500);
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM bulk WHERE name LIKE '%DROP%'")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 500);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_returns_the_sql_of_a_query() -> anyhow::Result<()> {
+    let sql = "SELECT ?1 + 1";
+
+    let query = sqlx::query::<Sqlite>(sql).bind(1_i32);
+    assert_eq!(query.sql(), sql);
+
+    let query = query.try_map(|row: SqliteRow| row.try_get::<i32, _>(0));
+    assert_eq!(query.sql(), sql);
+
+    assert_eq!(sqlx::query_as::<Sqlite, (i32,)>(sql).sql(), sql);
+    assert_eq!(sqlx::query_scalar::<Sqlite, i32>(sql).sql(), sql);
+
+    // a query on a prepared statement returns the SQL of the statement
+    let mut conn = new::<Sqlite>().await?;
+    let statement = conn.prepare(sql).await?;
+
+    assert_eq!(statement.query().bind(1_i32).sql(), sql);
+
+    Ok(())
+}
+
+sqlx_test::test_error_kinds!(
+    Sqlite,
+    r#"
+CREATE TEMPORARY TABLE error_kind_parents (id INTEGER PRIMARY KEY);
+CREATE TEMPORARY TABLE error_kind_children (
+    id INTEGER PRIMARY KEY,
+    parent_id INTEGER REFERENCES error_kind_parents (id),
+    name TEXT NOT NULL,
+    age INTEGER CHECK (age >= 0)
+);
+    "#,
+    [
+        (
+            "INSERT INTO error_kind_children (id, name, age) VALUES (1, 'a', -1)",
+            CheckViolation
+        ),
+        ("SELECT * FROM error_kind_missing", Other),
+    ]
+);
+
+#[sqlx_macros::test]
+async fn it_fetches_all_into_a_reused_vec() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let mut buf: Vec<i64> = Vec::with_capacity(64);
+
+    sqlx::query_scalar("SELECT 1 UNION ALL SELECT 2 UNION ALL SELECT 3")
+        .fetch_all_into(&mut conn, &mut buf)
+        .await?;
+
+    assert_eq!(buf, [1, 2, 3]);
+
+    let capacity = buf.capacity();
+
+    // the buffer is cleared, but its allocation is kept even though the result is smaller
+    sqlx::query_scalar("SELECT 4")
+        .fetch_all_into(&mut conn, &mut buf)
+        .await?;
+
+    assert_eq!(buf, [4]);
+    assert_eq!(buf.capacity(), capacity);
+
+    let mut rows = Vec::new();
+
+    sqlx::query("SELECT 5")
+        .fetch_all_into(&mut conn, &mut rows)
+        .await?;
+
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get::<i64, _>(0), 5);
+
+    let mut pairs: Vec<(i64, String)> = vec![(0, String::new())];
+
+    sqlx::query_as("SELECT 6, 'six'")
+        .fetch_all_into(&mut conn, &mut pairs)
+        .await?;
+
+    assert_eq!(pairs, [(6, "six".to_owned())]);
+
+    Ok(())
+}
@@ -36,6 +36,27 @@ test_type!(bytes<Vec<u8>>(Sqlite,
         == vec![0_u8, 0, 0, 0, 0x52]
 ));
 
+test_type!(byte_array<[u8; 16]>(Sqlite,
+    "X'000102030405060708090A0B0C0D0E0F'"
+        == [0_u8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+));
+
+#[sqlx_macros::test]
+async fn it_rejects_byte_arrays_of_the_wrong_length() -> anyhow::Result<()> {
+    use sqlx::Executor;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    let row = conn
+        .fetch_one("SELECT X'000102030405060708090A0B0C0D0E0F'")
+        .await?;
+
+    assert_eq!(row.try_get::<[u8; 16], _>(0)?[15], 15);
+    assert!(row.try_get::<[u8; 8], _>(0).is_err());
+
+    Ok(())
+}
+
 #[cfg(feature = "json")]
 mod json_tests {
     use super::*;
@@ -197,3 +218,9 @@ test_type!(uuid_hyphenated<sqlx::types::uuid::fmt::Hyphenated>(Sqlite,
     "'00000000-0000-0000-0000-000000000000'"
         == sqlx::types::Uuid::parse_str("00000000-0000-0000-0000-000000000000").unwrap().hyphenated()
 ));
+
+#[cfg(feature = "uuid")]
+test_type!(uuid_text<sqlx::types::Text<sqlx::types::Uuid>>(Sqlite,
+    "'b731678f-636f-4135-bc6f-19440c13bd19'"
+        == sqlx::types::Text(sqlx::types::Uuid::parse_str("b731678f-636f-4135-bc6f-19440c13bd19").unwrap()),
+));